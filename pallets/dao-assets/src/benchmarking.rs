@@ -3,8 +3,10 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{account, benchmarks, whitelist_account, whitelisted_caller};
-use frame_support::traits::Get;
+use frame_benchmarking::{
+	account, benchmarks, whitelist_account, whitelisted_caller, BenchmarkError,
+};
+use frame_support::traits::{EnsureOrigin, Get};
 use frame_system::RawOrigin as SystemOrigin;
 
 use crate::Pallet as Assets;
@@ -38,6 +40,17 @@ fn add_sufficients<T: Config>(n: u32) {
 	}
 }
 
+fn add_checkpoints<T: Config>(source: &T::AccountId, dest: &T::AccountId, n: u32) {
+	let asset_id = default_asset_id::<T>();
+	let f = TransferFlags { keep_alive: true, best_effort: false, burn_dust: false };
+	for _ in 0..n {
+		Assets::<T>::snapshot(asset_id.into()).unwrap();
+		Assets::<T>::do_transfer(asset_id.into(), source, dest, 1u32.into(), f).unwrap();
+	}
+	// the transfer to benchmark records a new checkpoint for both accounts
+	Assets::<T>::snapshot(asset_id.into()).unwrap();
+}
+
 fn add_approvals<T: Config>(minter: T::AccountId, n: u32) {
 	let asset_id = default_asset_id::<T>();
 	T::Currency::deposit_creating(&minter, T::ApprovalDeposit::get() * n.into());
@@ -57,6 +70,17 @@ fn add_approvals<T: Config>(minter: T::AccountId, n: u32) {
 	}
 }
 
+/// A schedule locking at least _MinVestedTransfer_, vesting over 10 blocks from genesis.
+fn vesting_schedule<T: Config>() -> VestingScheduleOf<T> {
+	let locked = T::MinVestedTransfer::get().max(100u32.into());
+	VestingSchedule {
+		locked,
+		per_block: locked / 10u32.into(),
+		start: 0u32.into(),
+		cliff: 0u32.into(),
+	}
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -112,6 +136,31 @@ benchmarks! {
 		);
 	}
 
+	mint {
+		let (asset_id, caller) = create_default_asset::<T>();
+		let policy = SupplyPolicy {
+			max_supply: Some(T::Balance::max_value()),
+			inflation: Some(Inflation { period: 1u32.into(), max_per_period: Perbill::from_percent(100) }),
+		};
+		SupplyPolicies::<T>::insert(asset_id.into(), policy);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let amount = T::Balance::from(100u32);
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, asset_id, caller_lookup, amount)
+	verify {
+		assert_last_event::<T>(Event::Issued { asset_id: asset_id.into(), owner: caller, total_supply: amount }.into());
+	}
+
+	burn {
+		let amount = T::Balance::from(100u32);
+		let (asset_id, caller) = create_default_minted_asset::<T>(amount);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, asset_id, caller_lookup, amount)
+	verify {
+		assert_last_event::<T>(Event::Burned { asset_id: asset_id.into(), owner: caller, balance: amount }.into());
+	}
+
 	transfer {
 		let amount = T::Balance::from(100u32);
 		let (asset_id, caller) = create_default_minted_asset::<T>(amount);
@@ -134,6 +183,20 @@ benchmarks! {
 		assert_last_event::<T>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
 	}
 
+	// the cost of a transfer does not depend on the length of the history of the accounts
+	transfer_with_checkpoints {
+		let c in 1 .. 1000;
+		let mint_amount = T::Balance::from(2000u32);
+		let amount = T::Balance::from(100u32);
+		let (asset_id, caller) = create_default_minted_asset::<T>(mint_amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		add_checkpoints::<T>(&caller, &target, c);
+	}: transfer(SystemOrigin::Signed(caller.clone()), asset_id, target_lookup, amount)
+	verify {
+		assert_last_event::<T>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
+	}
+
 	approve_transfer {
 		let (asset_id, caller) = create_default_minted_asset::<T>(100u32.into());
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
@@ -180,5 +243,48 @@ benchmarks! {
 		assert_last_event::<T>(Event::ApprovalCancelled { asset_id: asset_id.into(), owner: caller, delegate }.into());
 	}
 
+	set_supply_policy {
+		let (asset_id, _) = create_default_minted_asset::<T>(100u32.into());
+		let policy = Some(SupplyPolicy {
+			max_supply: Some(T::Balance::max_value()),
+			inflation: Some(Inflation { period: 1u32.into(), max_per_period: Perbill::from_percent(100) }),
+		});
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, asset_id, policy.clone())
+	verify {
+		assert_last_event::<T>(Event::SupplyPolicySet { asset_id: asset_id.into(), policy }.into());
+	}
+
+	vest {
+		let (asset_id, caller) = create_default_minted_asset::<T>(T::Balance::max_value() / 2u32.into());
+		let target: T::AccountId = account("target", 0, SEED);
+		whitelist_account!(target);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let schedule = vesting_schedule::<T>();
+		for _ in 0..T::MaxVestingSchedules::get() {
+			let origin = SystemOrigin::Signed(caller.clone()).into();
+			Assets::<T>::vested_transfer(origin, asset_id, target_lookup.clone(), schedule.clone())?;
+		}
+		frame_system::Pallet::<T>::set_block_number(5u32.into());
+	}: _(SystemOrigin::Signed(target.clone()), asset_id)
+	verify {
+		let locked = schedule.locked_at(5u32.into()) * T::MaxVestingSchedules::get().into();
+		assert_last_event::<T>(Event::VestingUpdated { asset_id: asset_id.into(), who: target, locked }.into());
+	}
+
+	vested_transfer {
+		let (asset_id, caller) = create_default_minted_asset::<T>(T::Balance::max_value() / 2u32.into());
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let schedule = vesting_schedule::<T>();
+		for _ in 1..T::MaxVestingSchedules::get() {
+			let origin = SystemOrigin::Signed(caller.clone()).into();
+			Assets::<T>::vested_transfer(origin, asset_id, target_lookup.clone(), schedule.clone())?;
+		}
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, target_lookup, schedule.clone())
+	verify {
+		assert_last_event::<T>(Event::VestedTransfer { asset_id: asset_id.into(), from: caller, to: target, schedule }.into());
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
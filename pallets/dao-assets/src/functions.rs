@@ -1,9 +1,17 @@
 //! Functions for the Assets pallet.
 
 use super::*;
-use frame_support::{traits::Get, BoundedVec};
-use frame_system::pallet_prelude::BlockNumberFor;
-use sp_std::{borrow::Borrow, fmt::Debug};
+use frame_support::{
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+use sp_std::borrow::Borrow;
+
+/// The maximum number of checkpoints pruned per change of a value.
+const MAX_PRUNED_CHECKPOINTS: u32 = 2;
+
+/// The maximum number of snapshots in use read to find the oldest one when releasing a snapshot.
+const MAX_SCANNED_SNAPSHOTS: u32 = 64;
 
 // The main implementation block for the module.
 impl<T: Config> Pallet<T> {
@@ -53,80 +61,248 @@ impl<T: Config> Pallet<T> {
 		Asset::<T>::get(id).map(|x| x.supply)
 	}
 
-	/// Get the total historical supply of an asset `id` at a certain `block`.
-	/// Result may be None, if the age of the requested block is at or beyond
-	/// the HistoryHorizon and history has been removed.
-	pub fn total_historical_supply(id: T::AssetId, block: BlockNumberFor<T>) -> Option<T::Balance> {
-		Self::search_history(SupplyHistory::<T>::get(id), block)
+	/// Take a snapshot of the supply and balances of asset `id`, returning its id.
+	///
+	/// Taking a snapshot is constant in cost, values are only recorded once they change. The
+	/// snapshot is in use until it is released with `release_snapshot`.
+	pub fn snapshot(id: T::AssetId) -> Result<SnapshotId, DispatchError> {
+		let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+		ensure!(details.status == AssetStatus::Live, Error::<T>::AssetNotLive);
+		let snapshot_id = CurrentSnapshotId::<T>::try_mutate(id, |current| {
+			*current = current.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			Ok::<_, DispatchError>(*current)
+		})?;
+		// values at older snapshots are no longer needed if none is in use
+		if !OpenSnapshots::<T>::contains_prefix(id) {
+			RetainedSnapshotId::<T>::insert(id, snapshot_id);
+		}
+		OpenSnapshots::<T>::insert(id, snapshot_id, 1);
+		Self::deposit_event(Event::SnapshotTaken { asset_id: id, snapshot_id });
+		Ok(snapshot_id)
+	}
+
+	/// The latest snapshot taken of asset `id`, 0 if none has been taken yet.
+	pub fn current_snapshot_id(id: T::AssetId) -> SnapshotId {
+		CurrentSnapshotId::<T>::get(id)
+	}
+
+	/// Keep the values at snapshot `snapshot_id` of asset `id` available for a further user of the
+	/// snapshot, until it is released with `release_snapshot` again.
+	pub fn retain_snapshot(id: T::AssetId, snapshot_id: SnapshotId) -> DispatchResult {
+		let retained = RetainedSnapshotId::<T>::get(id);
+		ensure!(
+			!snapshot_id.is_zero() &&
+				snapshot_id <= CurrentSnapshotId::<T>::get(id) &&
+				snapshot_id >= retained,
+			Error::<T>::SnapshotUnavailable
+		);
+		if !OpenSnapshots::<T>::contains_prefix(id) || snapshot_id < retained {
+			RetainedSnapshotId::<T>::insert(id, snapshot_id);
+		}
+		OpenSnapshots::<T>::mutate(id, snapshot_id, |users| users.saturating_inc());
+		Ok(())
 	}
 
-	/// Get the total historical balance of an asset `id` at a certain `block` for an account `who`.
-	/// Result may be None, if the age of the requested block is at or beyond
-	/// the HistoryHorizon and history has been removed.
-	pub fn total_historical_balance(
+	/// Release snapshot `snapshot_id` of asset `id` once a user no longer needs its values.
+	///
+	/// Values at snapshots older than the oldest snapshot still in use become unavailable, their
+	/// checkpoints are pruned as the values change. Up to `MAX_SCANNED_SNAPSHOTS` snapshots in use
+	/// are read to find the oldest, as long as more are in use no values become unavailable.
+	pub fn release_snapshot(id: T::AssetId, snapshot_id: SnapshotId) {
+		let users = OpenSnapshots::<T>::get(id, snapshot_id);
+		if users > 1 {
+			OpenSnapshots::<T>::insert(id, snapshot_id, users - 1);
+			return
+		}
+		OpenSnapshots::<T>::remove(id, snapshot_id);
+		if OpenSnapshots::<T>::contains_key(id, RetainedSnapshotId::<T>::get(id)) {
+			return
+		}
+
+		let open: Vec<_> = OpenSnapshots::<T>::iter_key_prefix(id)
+			.take(MAX_SCANNED_SNAPSHOTS as usize + 1)
+			.collect();
+		if open.len() > MAX_SCANNED_SNAPSHOTS as usize {
+			return
+		}
+		let oldest = open
+			.into_iter()
+			.min()
+			.unwrap_or_else(|| CurrentSnapshotId::<T>::get(id).saturating_add(1));
+		RetainedSnapshotId::<T>::insert(id, oldest);
+	}
+
+	/// Get the total supply of an asset `id` at snapshot `snapshot_id`.
+	/// Result is None, if no such snapshot has been taken or it has been released.
+	pub fn total_supply_at(id: T::AssetId, snapshot_id: SnapshotId) -> Option<T::Balance> {
+		let range = (PrunedSupplyCheckpoints::<T>::get(id), SupplyCheckpointCount::<T>::get(id));
+		Self::search_history(id, range, snapshot_id, |index| SupplyCheckpoints::<T>::get(id, index))
+			.map(|value| value.unwrap_or_else(|| Self::total_supply(id)))
+	}
+
+	/// Get the total balance of an account `who` of an asset `id` at snapshot `snapshot_id`.
+	/// Result is None, if no such snapshot has been taken or it has been released.
+	pub fn total_balance_at(
 		id: T::AssetId,
 		who: impl Borrow<T::AccountId>,
-		block: BlockNumberFor<T>,
+		snapshot_id: SnapshotId,
 	) -> Option<T::Balance> {
-		Self::search_history(AccountHistory::<T>::get(id, who.borrow()), block)
-	}
-
-	/// Search a history for the value at a specific block.
-	/// Result may be None, if the age of the requested block is at or beyond
-	/// the HistoryHorizon and history has been removed.
-	fn search_history<V: Copy + Zero, B: Get<u32>>(
-		history: Option<BoundedBTreeMap<BlockNumberFor<T>, V, B>>,
-		block: BlockNumberFor<T>,
-	) -> Option<V> {
-		let default = || {
-			let current_block = frame_system::Pallet::<T>::block_number();
-			if current_block - block < T::HistoryHorizon::get().into() {
-				Some(Zero::zero())
-			} else {
-				None
-			}
-		};
-		history.map_or_else(default, |history| {
-			history.range(..=block).next_back().map(|item| *item.1).or_else(default)
+		let who = who.borrow();
+		let range = (
+			PrunedAccountCheckpoints::<T>::get(id, who),
+			AccountCheckpointCount::<T>::get(id, who),
+		);
+		Self::search_history(id, range, snapshot_id, |index| {
+			AccountCheckpoints::<T>::get((id, who, index))
 		})
+		.map(|value| value.unwrap_or_else(|| Self::total_balance(id, who)))
 	}
 
-	pub(super) fn update_supply_history(id: T::AssetId, supply: T::Balance) {
-		// update history
-		let history = Self::update_history(SupplyHistory::<T>::get(id).unwrap_or_default(), supply);
+	/// Search a history for the value at a snapshot. Returns `Some(None)` if the value has not
+	/// changed since the snapshot, i.e. the current value is the one at the snapshot.
+	///
+	/// Performs a binary search, reading `O(log n)` of the checkpoints from the first one left
+	/// after pruning up to the `count` recorded ones.
+	fn search_history(
+		id: T::AssetId,
+		(first, count): (CheckpointIndex, CheckpointIndex),
+		snapshot_id: SnapshotId,
+		checkpoint: impl Fn(CheckpointIndex) -> Option<CheckpointOf<T>>,
+	) -> Option<Option<T::Balance>> {
+		if snapshot_id.is_zero() ||
+			snapshot_id > CurrentSnapshotId::<T>::get(id) ||
+			snapshot_id < RetainedSnapshotId::<T>::get(id)
+		{
+			return None
+		}
+		// find the first checkpoint recorded at or after `snapshot_id`
+		let (mut low, mut high) = (first, count);
+		while low < high {
+			let mid = low + (high - low) / 2;
+			match checkpoint(mid) {
+				Some(Checkpoint { snapshot, .. }) if snapshot < snapshot_id => low = mid + 1,
+				_ => high = mid,
+			}
+		}
+		Some(if low < count { checkpoint(low).map(|Checkpoint { value, .. }| value) } else { None })
+	}
 
-		// record new history
-		SupplyHistory::<T>::insert(id, history);
+	/// Record the total supply before it changes, if it has not changed since the latest snapshot.
+	pub(super) fn update_supply_history(id: T::AssetId, supply: T::Balance) {
+		let previous = (PrunedSupplyCheckpoints::<T>::get(id), SupplyCheckpointCount::<T>::get(id));
+		let (first, count) = Self::update_history(
+			id,
+			previous,
+			supply,
+			|index| SupplyCheckpoints::<T>::get(id, index),
+			|index, checkpoint| SupplyCheckpoints::<T>::insert(id, index, checkpoint),
+			|index| SupplyCheckpoints::<T>::remove(id, index),
+		);
+		if first != previous.0 {
+			PrunedSupplyCheckpoints::<T>::insert(id, first);
+		}
+		if count != previous.1 {
+			SupplyCheckpointCount::<T>::insert(id, count);
+		}
 	}
 
+	/// Record the total balance of `who` before it changes, if it has not changed since the latest
+	/// snapshot.
 	pub(super) fn update_account_history(id: T::AssetId, who: &T::AccountId, balance: T::Balance) {
-		// update history
-		let history =
-			Self::update_history(AccountHistory::<T>::get(id, who).unwrap_or_default(), balance);
-
-		// record new history
-		AccountHistory::<T>::insert(id, who, history);
+		let previous = (
+			PrunedAccountCheckpoints::<T>::get(id, who),
+			AccountCheckpointCount::<T>::get(id, who),
+		);
+		let (first, count) = Self::update_history(
+			id,
+			previous,
+			balance,
+			|index| AccountCheckpoints::<T>::get((id, who, index)),
+			|index, checkpoint| AccountCheckpoints::<T>::insert((id, who, index), checkpoint),
+			|index| AccountCheckpoints::<T>::remove((id, who, index)),
+		);
+		if first != previous.0 {
+			PrunedAccountCheckpoints::<T>::insert(id, who, first);
+		}
+		if count != previous.1 {
+			AccountCheckpointCount::<T>::insert(id, who, count);
+		}
 	}
 
-	fn update_history<V: Copy + Debug + Zero, B: Get<u32>>(
-		mut history: BoundedBTreeMap<BlockNumberFor<T>, V, B>,
-		value: V,
-	) -> BoundedBTreeMap<BlockNumberFor<T>, V, B> {
-		let current_block = frame_system::Pallet::<T>::block_number();
+	/// Append a checkpoint with the `value` before a change, unless no snapshot is in use or the
+	/// value already changed since the latest snapshot. Then prune up to `MAX_PRUNED_CHECKPOINTS`
+	/// checkpoints that are only valid for released snapshots.
+	///
+	/// Returns the index of the first checkpoint left and the number of recorded checkpoints. Reads
+	/// at most three checkpoints, so the cost does not depend on the length of the history.
+	fn update_history(
+		id: T::AssetId,
+		(mut first, mut count): (CheckpointIndex, CheckpointIndex),
+		value: T::Balance,
+		checkpoint: impl Fn(CheckpointIndex) -> Option<CheckpointOf<T>>,
+		insert: impl Fn(CheckpointIndex, CheckpointOf<T>),
+		remove: impl Fn(CheckpointIndex),
+	) -> (CheckpointIndex, CheckpointIndex) {
+		let snapshot = CurrentSnapshotId::<T>::get(id);
+		if snapshot.is_zero() {
+			return (first, count)
+		}
+		let retained = RetainedSnapshotId::<T>::get(id);
+		let is_recorded =
+			count > first && checkpoint(count - 1).map_or(false, |last| last.snapshot == snapshot);
+		if snapshot >= retained && !is_recorded {
+			insert(count, Checkpoint { snapshot, value });
+			count = count.saturating_add(1);
+		}
 
-		// the oldest block for which we need to be able to retrieve history
-		let inner_horizon_block =
-			current_block.saturating_sub((T::HistoryHorizon::get() - 1).into());
+		let mut pruned = 0;
+		while first < count && pruned < MAX_PRUNED_CHECKPOINTS {
+			match checkpoint(first) {
+				Some(oldest) if oldest.snapshot < retained => remove(first),
+				_ => break,
+			}
+			first += 1;
+			pruned += 1;
+		}
+		(first, count)
+	}
 
-		// if there is enough history, find block that has history for inner_horizon_block
-		if let Some(block) = history.range(..=inner_horizon_block).next_back().map(|i| *i.0) {
-			// and remove everything older than that block
-			history = BoundedBTreeMap::try_from(history.into_inner().split_off(&block)).unwrap();
+	/// Remove up to `max_items` checkpoints of asset `id` being destroyed, those of the total
+	/// supply first, then those of the accounts. Returns the number of removed checkpoints.
+	fn clear_history(id: T::AssetId, max_items: u32) -> u32 {
+		let mut removed = 0;
+		let first = PrunedSupplyCheckpoints::<T>::get(id);
+		let mut count = SupplyCheckpointCount::<T>::get(id);
+		while count > first && removed < max_items {
+			count -= 1;
+			SupplyCheckpoints::<T>::remove(id, count);
+			removed += 1;
+		}
+		if count == first {
+			SupplyCheckpointCount::<T>::remove(id);
+			PrunedSupplyCheckpoints::<T>::remove(id);
+		} else {
+			SupplyCheckpointCount::<T>::insert(id, count);
 		}
 
-		// insert new history item
-		history.try_insert(current_block, value).expect("Enough space has been freed");
-		history
+		let accounts: Vec<_> = AccountCheckpointCount::<T>::iter_prefix(id)
+			.take(max_items.saturating_sub(removed) as usize)
+			.collect();
+		for (who, mut count) in accounts {
+			let first = PrunedAccountCheckpoints::<T>::get(id, &who);
+			while count > first && removed < max_items {
+				count -= 1;
+				AccountCheckpoints::<T>::remove((id, &who, count));
+				removed += 1;
+			}
+			if count == first {
+				AccountCheckpointCount::<T>::remove(id, &who);
+				PrunedAccountCheckpoints::<T>::remove(id, &who);
+			} else {
+				AccountCheckpointCount::<T>::insert(id, &who, count);
+			}
+		}
+		removed
 	}
 
 	pub(super) fn new_account(
@@ -145,7 +321,8 @@ impl<T: Config> Pallet<T> {
 	) {
 		let _ = frame_system::Pallet::<T>::dec_providers(who);
 		details.accounts.saturating_dec();
-		AccountHistory::<T>::remove(id, who);
+		Vesting::<T>::remove(id, who);
+		VestingLocks::<T>::remove(id, who);
 	}
 
 	/// Returns `true` when the balance of `account` can be increased by `amount`.
@@ -202,6 +379,14 @@ impl<T: Config> Pallet<T> {
 			None => return Underflow,
 		};
 		if let Some(rest) = account.balance.checked_sub(&amount) {
+			if let Some(frozen) = Self::frozen_balance(id, who) {
+				match frozen.checked_add(&details.min_balance) {
+					Some(required) if rest < required => return Frozen,
+					None => return Overflow,
+					_ => {},
+				}
+			}
+
 			if rest < details.min_balance {
 				if keep_alive {
 					WouldDie
@@ -227,7 +412,12 @@ impl<T: Config> Pallet<T> {
 		ensure!(details.status == AssetStatus::Live, Error::<T>::AssetNotLive);
 
 		let account = Account::<T>::get(id, who).ok_or(Error::<T>::NoAccount)?;
-		Ok(if keep_alive {
+		Ok(if let Some(frozen) = Self::frozen_balance(id, who) {
+			// frozen balance: account cannot be deleted
+			let required =
+				frozen.checked_add(&details.min_balance).ok_or(ArithmeticError::Overflow)?;
+			account.balance.saturating_sub(required)
+		} else if keep_alive {
 			account.balance.saturating_sub(details.min_balance)
 		} else {
 			account.balance
@@ -298,6 +488,44 @@ impl<T: Config> Pallet<T> {
 		Ok((credit, maybe_burn))
 	}
 
+	/// Ensure the `origin` conforms to `ForceOrigin` or to `AdminOrigin` for the asset `id`.
+	pub(super) fn ensure_force_or_admin(
+		origin: T::RuntimeOrigin,
+		id: &T::AssetId,
+	) -> DispatchResult {
+		if let Err(origin) = T::ForceOrigin::try_origin(origin) {
+			T::AdminOrigin::ensure_origin(origin, id)?;
+		}
+		Ok(())
+	}
+
+	/// Check that minting `amount` of asset `id` is allowed by the supply policy of the asset and
+	/// account for it in the current inflation period.
+	pub(super) fn check_supply_policy(id: T::AssetId, amount: T::Balance) -> DispatchResult {
+		let Some(policy) = SupplyPolicies::<T>::get(id) else { return Ok(()) };
+		let supply = Self::total_supply(id);
+
+		if let Some(max_supply) = policy.max_supply {
+			ensure!(supply.saturating_add(amount) <= max_supply, Error::<T>::MaxSupplyExceeded);
+		}
+
+		if let Some(inflation) = policy.inflation {
+			let now = frame_system::Pallet::<T>::block_number();
+			let start = now - now % inflation.period;
+			// the allowance of a new period is based on the supply at its beginning
+			let mut period = InflationPeriods::<T>::get(id)
+				.filter(|period| period.start == start)
+				.unwrap_or_else(|| InflationPeriod {
+					start,
+					allowance: inflation.max_per_period * supply,
+				});
+			period.allowance =
+				period.allowance.checked_sub(&amount).ok_or(Error::<T>::InflationExceeded)?;
+			InflationPeriods::<T>::insert(id, period);
+		}
+		Ok(())
+	}
+
 	/// Increases the asset `id` balance of `beneficiary` by `amount`.
 	///
 	/// This alters the registered supply of the asset and emits an event.
@@ -313,9 +541,8 @@ impl<T: Config> Pallet<T> {
 				T::Balance::max_value() - details.supply >= amount,
 				"checked in prep; qed"
 			);
-			details.supply.saturating_accrue(amount);
-
 			Self::update_supply_history(id, details.supply);
+			details.supply.saturating_accrue(amount);
 
 			Ok(())
 		})?;
@@ -351,9 +578,9 @@ impl<T: Config> Pallet<T> {
 
 			let mut account = Account::<T>::try_get(id, beneficiary)
 				.or_else(|_| Self::new_account(beneficiary, details))?;
+			Self::update_account_history(id, beneficiary, account.balance + account.reserved);
 			account.balance.saturating_accrue(amount);
 			ensure!(account.balance >= details.min_balance, TokenError::BelowMinimum);
-			Self::update_account_history(id, beneficiary, account.balance + account.reserved);
 			Account::<T>::insert(id, beneficiary, account);
 			Ok(())
 		})
@@ -366,7 +593,6 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Will return an error and do nothing or will decrease the amount and return the amount
 	/// reduced by.
-	#[cfg(test)]
 	pub(super) fn do_burn(
 		id: T::AssetId,
 		target: &T::AccountId,
@@ -378,9 +604,8 @@ impl<T: Config> Pallet<T> {
 
 		let actual = Self::decrease_balance(id, target, amount, f, |actual, details| {
 			debug_assert!(details.supply >= actual, "checked in prep; qed");
-			details.supply.saturating_reduce(actual);
-
 			Self::update_supply_history(id, details.supply);
+			details.supply.saturating_reduce(actual);
 
 			Ok(())
 		})?;
@@ -418,14 +643,15 @@ impl<T: Config> Pallet<T> {
 
 			let mut account = Account::<T>::take(id, target).ok_or(Error::<T>::NoAccount)?;
 			debug_assert!(account.balance >= actual, "checked in prep; qed");
+			Self::update_account_history(id, target, account.balance + account.reserved);
 			account.balance.saturating_reduce(actual);
-			if account.balance < details.min_balance {
+			// the reserved balance keeps the account alive
+			if account.balance < details.min_balance && account.reserved.is_zero() {
 				// account already removed by take
 				Self::dead_account(id, target, details);
 				debug_assert!(account.balance.is_zero(), "checked in prep; qed");
 				return Ok(())
 			};
-			Self::update_account_history(id, target, account.balance + account.reserved);
 			Account::<T>::insert(id, target, account);
 			Ok(())
 		})?;
@@ -446,7 +672,8 @@ impl<T: Config> Pallet<T> {
 		let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T>::AssetNotLive);
 
-		let f = DebitFlags { keep_alive: true, best_effort: false };
+		// the reserved balance keeps the account alive, so the whole balance may be reserved
+		let f = DebitFlags { keep_alive: false, best_effort: false };
 
 		let actual = Self::prep_debit(id, target.borrow(), amount, f)?;
 
@@ -493,6 +720,48 @@ impl<T: Config> Pallet<T> {
 		Ok(amount)
 	}
 
+	/// Burns some `amount` of the reserved asset `id` balance of `target`.
+	/// If `amount` is greater than reserved balance, then the whole reserved balance is burned.
+	///
+	/// This alters the registered supply of the asset and emits an event.
+	pub fn do_slash_reserved(
+		id: T::AssetId,
+		target: impl Borrow<T::AccountId>,
+		mut amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		if amount.is_zero() {
+			return Ok(amount)
+		}
+
+		// check asset is live
+		let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+		ensure!(details.status == AssetStatus::Live, Error::<T>::AssetNotLive);
+
+		Account::<T>::try_mutate(id, target.borrow(), |maybe_account| -> DispatchResult {
+			let mut account = maybe_account.take().ok_or(Error::<T>::NoAccount)?;
+
+			// Burn the minimum of amount and reserved balance
+			amount = amount.min(account.reserved);
+			Self::update_account_history(id, target.borrow(), account.balance + account.reserved);
+			account.reserved.saturating_reduce(amount);
+			*maybe_account = Some(account);
+			Ok(())
+		})?;
+
+		Asset::<T>::mutate(id, |maybe_details| {
+			if let Some(details) = maybe_details {
+				Self::update_supply_history(id, details.supply);
+				details.supply.saturating_reduce(amount);
+			}
+		});
+		Self::deposit_event(Event::Burned {
+			asset_id: id,
+			owner: target.borrow().clone(),
+			balance: amount,
+		});
+		Ok(amount)
+	}
+
 	/// Reduces the asset `id` balance of `source` by some `amount` and increases the balance of
 	/// `dest` by (similar) amount.
 	///
@@ -501,7 +770,7 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Will fail if the amount transferred is so small that it cannot create the destination due
 	/// to minimum balance requirements.
-	pub(super) fn do_transfer(
+	pub fn do_transfer(
 		id: T::AssetId,
 		source: &T::AccountId,
 		dest: &T::AccountId,
@@ -532,34 +801,35 @@ impl<T: Config> Pallet<T> {
 				// Debit dust from supply; this will not saturate since it's already checked in
 				// prep.
 				debug_assert!(details.supply >= burn, "checked in prep; qed");
+				Self::update_supply_history(id, details.supply);
 				details.supply = details.supply.saturating_sub(burn);
 			}
 
 			// Debit balance from source; this will not saturate since it's already checked in prep.
 			let mut source_account = Account::<T>::get(id, source).expect("checked in prep; qed");
 			debug_assert!(source_account.balance >= debit, "checked in prep; qed");
+			Self::update_account_history(
+				id,
+				source,
+				source_account.balance + source_account.reserved,
+			);
 			source_account.balance.saturating_reduce(debit);
 
 			let mut account =
 				Account::<T>::try_get(id, dest).or_else(|_| Self::new_account(dest, details))?;
 			// Calculate new balance; this will not saturate since it's already checked in prep.
 			debug_assert!(account.balance.checked_add(&credit).is_some(), "checked in prep; qed");
-			account.balance.saturating_accrue(credit);
 			Self::update_account_history(id, dest, account.balance + account.reserved);
+			account.balance.saturating_accrue(credit);
 			Account::<T>::insert(id, dest, account);
 
 			// Remove source account if it's now dead.
-			if source_account.balance < details.min_balance {
+			if source_account.balance < details.min_balance && source_account.reserved.is_zero() {
 				debug_assert!(source_account.balance.is_zero(), "checked in prep; qed");
 				Self::dead_account(id, source, details);
 				Account::<T>::remove(id, source);
 				return Ok(())
 			}
-			Self::update_account_history(
-				id,
-				source,
-				source_account.balance + source_account.reserved,
-			);
 			Account::<T>::insert(id, source, &source_account);
 			Ok(())
 		})?;
@@ -591,7 +861,7 @@ impl<T: Config> Pallet<T> {
 			id,
 			AssetDetails {
 				owner: owner.clone(),
-				supply: Zero::zero(), // no need to record a supply of zero in the SupplyHistory
+				supply: Zero::zero(), // no need to record a supply of zero in the supply history
 				min_balance,
 				accounts: 0,
 				approvals: 0,
@@ -605,7 +875,7 @@ impl<T: Config> Pallet<T> {
 
 	/// Start the process of destroying an asset, by setting the asset status to `Destroying`, and
 	/// emitting the `DestructionStarted` event.
-	pub(super) fn do_start_destroy(
+	pub fn do_start_destroy(
 		id: T::AssetId,
 		maybe_check_owner: Option<T::AccountId>,
 	) -> DispatchResult {
@@ -614,19 +884,23 @@ impl<T: Config> Pallet<T> {
 			if let Some(check_owner) = maybe_check_owner {
 				ensure!(details.owner == check_owner, Error::<T>::NoPermission);
 			}
+			// the values at snapshots in use must remain available
+			ensure!(!OpenSnapshots::<T>::contains_prefix(id), Error::<T>::SnapshotInUse);
 			details.status = AssetStatus::Destroying;
-			SupplyHistory::<T>::remove(id);
+			CurrentSnapshotId::<T>::remove(id);
+			RetainedSnapshotId::<T>::remove(id);
 
 			Self::deposit_event(Event::DestructionStarted { asset_id: id });
 			Ok(())
 		})
 	}
 
-	/// Destroy accounts associated with a given asset up to the max (T::RemoveItemsLimit).
+	/// Destroy accounts associated with a given asset up to the max (T::RemoveItemsLimit), then
+	/// the checkpoints of its history with what is left of the max.
 	///
 	/// Each call emits the `Event::AccountsDestroyed` event.
-	/// Returns the number of destroyed accounts.
-	pub(super) fn do_destroy_accounts(
+	/// Returns the number of destroyed accounts and checkpoints.
+	pub fn do_destroy_accounts(
 		id: T::AssetId,
 		max_items: u32,
 	) -> Result<u32, DispatchError> {
@@ -646,20 +920,21 @@ impl<T: Config> Pallet<T> {
 			remaining_accounts = details.accounts;
 			Ok(())
 		})?;
+		let removed_checkpoints = Self::clear_history(id, max_items - dead_accounts);
 
 		Self::deposit_event(Event::AccountsDestroyed {
 			asset_id: id,
 			accounts_destroyed: dead_accounts,
 			accounts_remaining: remaining_accounts,
 		});
-		Ok(dead_accounts)
+		Ok(dead_accounts + removed_checkpoints)
 	}
 
 	/// Destroy approvals associated with a given asset up to the max (T::RemoveItemsLimit).
 	///
 	/// Each call emits the `Event::ApprovalsDestroyed` event
 	/// Returns the number of destroyed approvals.
-	pub(super) fn do_destroy_approvals(
+	pub fn do_destroy_approvals(
 		id: T::AssetId,
 		max_items: u32,
 	) -> Result<u32, DispatchError> {
@@ -691,12 +966,14 @@ impl<T: Config> Pallet<T> {
 	/// Complete destroying an asset and unreserve the deposit.
 	///
 	/// On success, the `Event::Destroyed` event is emitted.
-	pub(super) fn do_finish_destroy(id: T::AssetId) -> DispatchResult {
+	pub fn do_finish_destroy(id: T::AssetId) -> DispatchResult {
 		Asset::<T>::try_mutate_exists(id, |maybe_details| -> Result<(), DispatchError> {
 			let mut details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
 			ensure!(details.status == AssetStatus::Destroying, Error::<T>::IncorrectStatus);
 			ensure!(details.accounts == 0, Error::<T>::InUse);
 			ensure!(details.approvals == 0, Error::<T>::InUse);
+			ensure!(SupplyCheckpointCount::<T>::get(id) == 0, Error::<T>::InUse);
+			ensure!(!AccountCheckpointCount::<T>::contains_prefix(id), Error::<T>::InUse);
 
 			let _ = Metadata::<T>::take(id); // erase metadata
 			details.status = AssetStatus::Destroyed;
@@ -826,4 +1103,72 @@ impl<T: Config> Pallet<T> {
 			Ok(())
 		})
 	}
+
+	/// Get the asset `id` balance of `who` that is locked by vesting schedules, if any.
+	pub fn frozen_balance(id: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+		VestingLocks::<T>::get(id, who)
+	}
+
+	/// Unlock the asset `id` balance of `who` that has vested and remove the vesting schedules
+	/// which are fully vested.
+	pub(super) fn do_vest(id: T::AssetId, who: &T::AccountId) -> DispatchResult {
+		let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+		ensure!(details.status == AssetStatus::Live, Error::<T>::AssetNotLive);
+
+		let mut schedules = Vesting::<T>::get(id, who).ok_or(Error::<T>::NotVesting)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		schedules.retain(|schedule| !schedule.locked_at(now).is_zero());
+		let locked = schedules.iter().fold(Zero::zero(), |locked: T::Balance, schedule| {
+			locked.saturating_add(schedule.locked_at(now))
+		});
+
+		if schedules.is_empty() {
+			Vesting::<T>::remove(id, who);
+			VestingLocks::<T>::remove(id, who);
+		} else {
+			Vesting::<T>::insert(id, who, schedules);
+			VestingLocks::<T>::insert(id, who, locked);
+		}
+
+		Self::deposit_event(Event::VestingUpdated { asset_id: id, who: who.clone(), locked });
+		Ok(())
+	}
+
+	/// Transfer `schedule.locked` of asset `id` from `source` to `dest` and lock it there
+	/// according to `schedule`.
+	pub(super) fn do_vested_transfer(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		schedule: VestingScheduleOf<T>,
+	) -> DispatchResult {
+		ensure!(schedule.is_valid(), Error::<T>::InvalidVestingSchedule);
+		ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::VestedTransferTooLow);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			schedule.end().saturating_sub(now) <= T::MaxVestingDuration::get(),
+			Error::<T>::VestingScheduleTooLong
+		);
+
+		let mut schedules = Vesting::<T>::get(id, dest).unwrap_or_default();
+		schedules
+			.try_push(schedule.clone())
+			.map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+		Self::do_transfer(id, source, dest, schedule.locked, f)?;
+
+		Vesting::<T>::insert(id, dest, schedules);
+		VestingLocks::<T>::mutate(id, dest, |locked| {
+			*locked = Some(locked.unwrap_or_default().saturating_add(schedule.locked));
+		});
+
+		Self::deposit_event(Event::VestedTransfer {
+			asset_id: id,
+			from: source.clone(),
+			to: dest.clone(),
+			schedule,
+		});
+		Ok(())
+	}
 }
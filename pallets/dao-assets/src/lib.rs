@@ -20,6 +20,7 @@ pub mod weights;
 
 mod functions;
 mod impl_fungibles;
+pub mod migrations;
 mod types;
 
 pub use types::*;
@@ -32,13 +33,12 @@ use frame_support::{
 		traits::{
 			AtLeast32BitUnsigned, Bounded, CheckedAdd, CheckedSub, Saturating, StaticLookup, Zero,
 		},
-		ArithmeticError, TokenError,
+		ArithmeticError, Perbill, TokenError,
 	},
 	traits::{
 		tokens::{DepositConsequence, WithdrawConsequence},
 		Currency, EnsureOriginWithArg, ReservableCurrency,
 	},
-	BoundedBTreeMap,
 };
 use frame_system::Config as SystemConfig;
 use scale_info::TypeInfo;
@@ -54,7 +54,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -125,6 +125,9 @@ pub mod pallet {
 		/// attributes.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which may administer a specific asset in place of its owner.
+		type AdminOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, Self::AssetId>;
+
 		/// The amount of funds that must be reserved when creating a new approval.
 		#[pallet::constant]
 		type ApprovalDeposit: Get<DepositBalanceOf<Self>>;
@@ -133,11 +136,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type StringLimit: Get<u32>;
 
-		/// The age for which historical data may be removed.
-		/// For example, if this is 100 and the current block is 150,
-		/// then history for blocks 50 and older may be removed.
+		/// The maximum number of vesting schedules of an account per asset.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// The minimum amount transferred with a vesting schedule, so that the schedules of an
+		/// account cannot be filled up cheaply.
+		#[pallet::constant]
+		type MinVestedTransfer: Get<Self::Balance>;
+
+		/// The maximum number of blocks until a vesting schedule is fully vested, counted from
+		/// the vested transfer.
 		#[pallet::constant]
-		type HistoryHorizon: Get<u32>;
+		type MaxVestingDuration: Get<BlockNumberFor<Self>>;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
@@ -188,23 +199,124 @@ pub mod pallet {
 	>;
 
 	#[pallet::storage]
-	/// History for the total supply across all accounts.
-	pub(super) type SupplyHistory<T: Config> = StorageMap<
+	/// The latest snapshot of an asset, zero if no snapshot has been taken.
+	pub(super) type CurrentSnapshotId<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, SnapshotId, ValueQuery>;
+
+	#[pallet::storage]
+	/// The number of checkpoints of the total supply across all accounts.
+	pub(super) type SupplyCheckpointCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, CheckpointIndex, ValueQuery>;
+
+	#[pallet::storage]
+	/// Checkpoints of the total supply across all accounts, ordered by snapshot.
+	pub(super) type SupplyCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Twox64Concat,
+		CheckpointIndex,
+		CheckpointOf<T>,
+	>;
+
+	#[pallet::storage]
+	/// The number of checkpoints of the total balance of each account.
+	pub(super) type AccountCheckpointCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		CheckpointIndex,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Checkpoints of the total balance of each account, ordered by snapshot.
+	pub(super) type AccountCheckpoints<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AssetId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Twox64Concat, CheckpointIndex>,
+		),
+		CheckpointOf<T>,
+	>;
+
+	#[pallet::storage]
+	/// The number of leading checkpoints of the total supply that have been pruned, i.e. the index
+	/// of the first checkpoint left.
+	pub(super) type PrunedSupplyCheckpoints<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, CheckpointIndex, ValueQuery>;
+
+	#[pallet::storage]
+	/// The number of leading checkpoints of the total balance of each account that have been
+	/// pruned, i.e. the index of the first checkpoint left.
+	pub(super) type PrunedAccountCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		CheckpointIndex,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The snapshots of an asset in use, alongside with their number of users.
+	pub(super) type OpenSnapshots<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AssetId,
-		BoundedBTreeMap<BlockNumberFor<T>, AssetBalanceOf<T>, T::HistoryHorizon>,
+		Twox64Concat,
+		SnapshotId,
+		u32,
+		ValueQuery,
 	>;
 
 	#[pallet::storage]
-	/// History for the total balance of each account for all assets.
-	pub(super) type AccountHistory<T: Config> = StorageDoubleMap<
+	/// The oldest snapshot of an asset whose values are still available. Checkpoints only valid
+	/// for older snapshots are pruned.
+	pub(super) type RetainedSnapshotId<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, SnapshotId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Limits to minting an asset after its issuance, minting is not limited without a policy.
+	pub(super) type SupplyPolicies<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, SupplyPolicyOf<T>>;
+
+	#[pallet::storage]
+	/// The current inflation period of an asset with an inflation schedule.
+	pub(super) type InflationPeriods<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		InflationPeriod<AssetBalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	#[pallet::storage]
+	/// The vesting schedules of an account for an asset.
+	pub(super) type Vesting<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+	>;
+
+	#[pallet::storage]
+	/// The part of the free balance of an account that is locked by its vesting schedules.
+	///
+	/// Locked tokens cannot be transferred or reserved, but still count towards the (historical)
+	/// balance of the account.
+	pub(super) type VestingLocks<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AssetId,
 		Blake2_128Concat,
 		T::AccountId,
-		BoundedBTreeMap<BlockNumberFor<T>, AssetBalanceOf<T>, T::HistoryHorizon>,
+		AssetBalanceOf<T>,
 	>;
 
 	#[pallet::genesis_config]
@@ -338,6 +450,19 @@ pub mod pallet {
 		},
 		/// An asset has had its attributes changed by the `Force` origin.
 		AssetStatusChanged { asset_id: T::AssetId },
+		/// The supply policy of an asset has been set.
+		SupplyPolicySet { asset_id: T::AssetId, policy: Option<SupplyPolicyOf<T>> },
+		/// Some assets were transferred subject to a vesting schedule.
+		VestedTransfer {
+			asset_id: T::AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			schedule: VestingScheduleOf<T>,
+		},
+		/// The amount of assets of an account still locked by vesting has been updated.
+		VestingUpdated { asset_id: T::AssetId, who: T::AccountId, locked: T::Balance },
+		/// A snapshot of the supply and balances of an asset has been taken.
+		SnapshotTaken { asset_id: T::AssetId, snapshot_id: SnapshotId },
 	}
 
 	#[pallet::error]
@@ -370,6 +495,26 @@ pub mod pallet {
 		AssetNotLive,
 		/// The asset status is not the expected status.
 		IncorrectStatus,
+		/// Minting would exceed the maximum supply of the asset.
+		MaxSupplyExceeded,
+		/// Minting would exceed the inflation allowed in the current period.
+		InflationExceeded,
+		/// The supply policy is below the current supply or has an empty inflation period.
+		InvalidSupplyPolicy,
+		/// The account has no vesting schedule for the asset.
+		NotVesting,
+		/// The account already has the maximum number of vesting schedules for the asset.
+		TooManyVestingSchedules,
+		/// The vesting schedule locks nothing, never unlocks or has its cliff before its start.
+		InvalidVestingSchedule,
+		/// The vesting schedule locks less than _MinVestedTransfer_.
+		VestedTransferTooLow,
+		/// The vesting schedule vests after more than _MaxVestingDuration_ blocks.
+		VestingScheduleTooLong,
+		/// The snapshot has not been taken or its values are no longer available.
+		SnapshotUnavailable,
+		/// The asset has snapshots in use, which need to be released first.
+		SnapshotInUse,
 	}
 
 	#[pallet::call]
@@ -377,20 +522,24 @@ pub mod pallet {
 		/// Start the process of destroying a fungible asset class.
 		///
 		/// `start_destroy` is the first in a series of extrinsics that should be called, to allow
-		/// destruction of an asset class.
+		/// destruction of an asset class. It fails as long as snapshots of the asset are in use.
 		///
-		/// The origin must conform to `ForceOrigin` or must be `Signed` by the asset's `owner`.
+		/// The origin must conform to `ForceOrigin` or `AdminOrigin` or must be `Signed` by the
+		/// asset's `owner`.
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		///   asset.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::start_destroy())]
 		pub fn start_destroy(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
+			let id: T::AssetId = id.into();
 			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
 				Ok(_) => None,
-				Err(origin) => Some(ensure_signed(origin)?),
+				Err(origin) => match T::AdminOrigin::try_origin(origin, &id) {
+					Ok(_) => None,
+					Err(origin) => Some(ensure_signed(origin)?),
+				},
 			};
-			let id: T::AssetId = id.into();
 			Self::do_start_destroy(id, maybe_check_owner)
 		}
 
@@ -400,7 +549,8 @@ pub mod pallet {
 		/// asset is in a `Destroying` state.
 		///
 		/// Due to weight restrictions, this function may need to be called multiple times to fully
-		/// destroy all accounts. It will destroy `RemoveItemsLimit` accounts at a time.
+		/// destroy all accounts. It will destroy `RemoveItemsLimit` accounts at a time, followed by
+		/// the checkpoints of the history of the asset within the same limit.
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		///   asset.
@@ -445,8 +595,8 @@ pub mod pallet {
 		/// Complete destroying asset and unreserve currency.
 		///
 		/// `finish_destroy` should only be called after `start_destroy` has been called, and the
-		/// asset is in a `Destroying` state. All accounts or approvals should be destroyed before
-		/// hand.
+		/// asset is in a `Destroying` state. All accounts, checkpoints or approvals should be
+		/// destroyed before hand.
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		///   asset.
@@ -460,6 +610,66 @@ pub mod pallet {
 			Self::do_finish_destroy(id)
 		}
 
+		/// Mint assets of a particular class.
+		///
+		/// The origin must conform to `ForceOrigin` or `AdminOrigin`. Minting is limited by the
+		/// supply policy of the asset, if any.
+		///
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `beneficiary`: The account to be credited with the minted assets.
+		/// - `amount`: The amount of the asset to be minted.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// Weight: `O(1)`
+		/// Modes: Pre-existing balance of `beneficiary`; Account pre-existence of `beneficiary`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			beneficiary: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id: T::AssetId = id.into();
+			Self::ensure_force_or_admin(origin, &id)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			Self::check_supply_policy(id, amount)?;
+			Self::do_mint(id, &beneficiary, amount)
+		}
+
+		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
+		///
+		/// The origin must conform to `ForceOrigin` or `AdminOrigin`.
+		///
+		/// Bails with `NoAccount` if the `who` is already dead.
+		///
+		/// - `id`: The identifier of the asset to have some amount burned.
+		/// - `who`: The account to be debited from.
+		/// - `amount`: The maximum amount by which `who`'s balance should be reduced.
+		///
+		/// Emits `Burned` with the actual amount burned. If this takes the balance to below the
+		/// minimum for the asset, then the amount burned is increased to take it to zero.
+		///
+		/// Weight: `O(1)`
+		/// Modes: Post-existence of `who`; Pre & post Zombie-status of `who`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id: T::AssetId = id.into();
+			Self::ensure_force_or_admin(origin, &id)?;
+			let who = T::Lookup::lookup(who)?;
+
+			let f = DebitFlags { keep_alive: false, best_effort: true };
+			Self::do_burn(id, &who, amount, f).map(|_| ())
+		}
+
 		/// Move some assets from the sender account to another.
 		///
 		/// Origin must be Signed.
@@ -632,5 +842,88 @@ pub mod pallet {
 			let id: T::AssetId = id.into();
 			Self::do_transfer_approved(id, &owner, &delegate, &destination, amount)
 		}
+
+		/// Set or clear the supply policy of an asset, limiting the amount that can be minted.
+		///
+		/// The origin must conform to `ForceOrigin` or `AdminOrigin`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `policy`: The maximum supply and inflation schedule, `None` to allow any minting.
+		///
+		/// Emits `SupplyPolicySet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_supply_policy())]
+		pub fn set_supply_policy(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			policy: Option<SupplyPolicyOf<T>>,
+		) -> DispatchResult {
+			let id: T::AssetId = id.into();
+			Self::ensure_force_or_admin(origin, &id)?;
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(details.status == AssetStatus::Live, Error::<T>::AssetNotLive);
+
+			if let Some(policy) = &policy {
+				if let Some(max_supply) = policy.max_supply {
+					ensure!(max_supply >= details.supply, Error::<T>::InvalidSupplyPolicy);
+				}
+				if let Some(inflation) = &policy.inflation {
+					ensure!(!inflation.period.is_zero(), Error::<T>::InvalidSupplyPolicy);
+				}
+			}
+			SupplyPolicies::<T>::set(id, policy.clone());
+			// a new schedule starts with a new period
+			InflationPeriods::<T>::remove(id);
+
+			Self::deposit_event(Event::SupplyPolicySet { asset_id: id, policy });
+			Ok(())
+		}
+
+		/// Unlock the assets of the sender that have vested.
+		///
+		/// Origin must be Signed and the sender must have at least one vesting schedule for the
+		/// asset. Schedules that are fully vested are removed.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `VestingUpdated` with the amount that is still locked.
+		///
+		/// Weight: `O(S)` where `S` is `MaxVestingSchedules`
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::vest())]
+		pub fn vest(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_vest(id.into(), &who)
+		}
+
+		/// Move some assets from the sender account to another and lock them there according to
+		/// a vesting schedule.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `schedule`: The vesting schedule, `schedule.locked` is the amount transferred.
+		///
+		/// The schedule needs to lock at least _MinVestedTransfer_ and to be fully vested within
+		/// _MaxVestingDuration_ blocks.
+		///
+		/// Emits `VestedTransfer` on success.
+		///
+		/// Weight: `O(S)` where `S` is `MaxVestingSchedules`
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			target: AccountIdLookupOf<T>,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(target)?;
+			Self::do_vested_transfer(id.into(), &source, &dest, schedule)
+		}
 	}
 }
@@ -0,0 +1,194 @@
+//! Storage migrations for the dao-assets pallet.
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	marker::PhantomData,
+};
+
+pub mod v2 {
+	use super::*;
+
+	/// The history of the total supply of an asset by block, replaced by `SupplyCheckpoints`.
+	#[storage_alias]
+	pub(crate) type SupplyHistory<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::AssetId,
+		BTreeMap<BlockNumberFor<T>, <T as Config>::Balance>,
+	>;
+
+	/// The history of the total balance of an account by block, replaced by `AccountCheckpoints`.
+	#[storage_alias]
+	pub(crate) type AccountHistory<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::AssetId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BTreeMap<BlockNumberFor<T>, <T as Config>::Balance>,
+	>;
+
+	/// The block of each snapshot taken by `MigrateToV2`, in order of the snapshots.
+	#[storage_alias]
+	type SnapshotBlocks<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::AssetId, Vec<BlockNumberFor<T>>>;
+
+	/// Translates the history of the total supply and of the balances of each asset, kept by
+	/// block, into checkpoints and removes the history.
+	///
+	/// A snapshot is taken for every block of the history, in order of the blocks, so the values
+	/// at each snapshot are those at the end of its block. Use `snapshot_at_block` to find the
+	/// snapshot of a block, e.g. for proposals whose voting power was determined by a block, until
+	/// `ClearSnapshotBlocks` runs.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 1_u64;
+			let mut writes = 1_u64;
+			for (id, supply) in SupplyHistory::<T>::drain() {
+				let accounts: Vec<_> = AccountHistory::<T>::drain_prefix(id).collect();
+				reads += 1 + accounts.len() as u64;
+				writes += 1 + accounts.len() as u64;
+
+				let blocks: Vec<_> = supply
+					.keys()
+					.chain(accounts.iter().flat_map(|(_, history)| history.keys()))
+					.copied()
+					.collect::<BTreeSet<_>>()
+					.into_iter()
+					.collect();
+
+				let checkpoints = Self::checkpoints(&blocks, &supply);
+				SupplyCheckpointCount::<T>::insert(id, checkpoints.len() as CheckpointIndex);
+				writes += 1 + checkpoints.len() as u64;
+				for (index, checkpoint) in checkpoints.into_iter().enumerate() {
+					SupplyCheckpoints::<T>::insert(id, index as CheckpointIndex, checkpoint);
+				}
+				for (who, history) in accounts {
+					let checkpoints = Self::checkpoints(&blocks, &history);
+					AccountCheckpointCount::<T>::insert(
+						id,
+						&who,
+						checkpoints.len() as CheckpointIndex,
+					);
+					writes += 1 + checkpoints.len() as u64;
+					for (index, checkpoint) in checkpoints.into_iter().enumerate() {
+						AccountCheckpoints::<T>::insert(
+							(id, &who, index as CheckpointIndex),
+							checkpoint,
+						);
+					}
+				}
+
+				CurrentSnapshotId::<T>::insert(id, blocks.len() as SnapshotId);
+				SnapshotBlocks::<T>::insert(id, blocks);
+				writes += 2;
+			}
+			// histories of accounts whose asset has no history of its supply
+			let result = AccountHistory::<T>::clear(u32::MAX, None);
+			reads += u64::from(result.loops);
+			writes += u64::from(result.backend);
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let mut values = Vec::new();
+			for (id, history) in SupplyHistory::<T>::iter() {
+				for (block, value) in history {
+					values.push((id, None, block, value));
+				}
+			}
+			for (id, who, history) in AccountHistory::<T>::iter() {
+				for (block, value) in history {
+					values.push((id, Some(who), block, value));
+				}
+			}
+			Ok(values.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version not updated");
+			ensure!(SupplyHistory::<T>::iter().next().is_none(), "supply history left");
+			ensure!(AccountHistory::<T>::iter().next().is_none(), "account history left");
+
+			// every value of the history is available at the snapshot of its block
+			let values: Vec<(
+				<T as Config>::AssetId,
+				Option<<T as frame_system::Config>::AccountId>,
+				BlockNumberFor<T>,
+				<T as Config>::Balance,
+			)> = Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			for (id, who, block, value) in values {
+				let snapshot_id = snapshot_at_block::<T>(id, block).ok_or("snapshot missing")?;
+				let translated = match who {
+					Some(who) => Pallet::<T>::total_balance_at(id, who, snapshot_id),
+					None => Pallet::<T>::total_supply_at(id, snapshot_id),
+				};
+				ensure!(translated == Some(value), "history not translated");
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> MigrateToV2<T> {
+		/// The checkpoints of a value with the given `history`, taking a snapshot at the end of
+		/// each of the `blocks`, which include the blocks of the history.
+		///
+		/// Each checkpoint holds the value before a change of the history and is valid up to the
+		/// snapshot before the change. The value after the last change is the current one.
+		fn checkpoints(
+			blocks: &[BlockNumberFor<T>],
+			history: &BTreeMap<BlockNumberFor<T>, <T as Config>::Balance>,
+		) -> Vec<CheckpointOf<T>> {
+			let mut previous = Zero::zero();
+			let mut checkpoints = Vec::new();
+			for (block, value) in history {
+				// the snapshot of the change is its index within the blocks, counted from 1
+				let snapshot = blocks.partition_point(|other| other < block) as SnapshotId;
+				if !snapshot.is_zero() {
+					checkpoints.push(Checkpoint { snapshot, value: previous });
+				}
+				previous = *value;
+			}
+			checkpoints
+		}
+	}
+
+	/// The snapshot of asset `id` that `MigrateToV2` took for the latest block of the history at
+	/// or before `block`, whose values are the values at the end of `block`. None if there is no
+	/// history up to `block`, or once `ClearSnapshotBlocks` ran.
+	pub fn snapshot_at_block<T: Config>(
+		id: <T as Config>::AssetId,
+		block: BlockNumberFor<T>,
+	) -> Option<SnapshotId> {
+		let blocks = SnapshotBlocks::<T>::get(id)?;
+		let snapshot_id = blocks.partition_point(|other| *other <= block) as SnapshotId;
+		(!snapshot_id.is_zero()).then_some(snapshot_id)
+	}
+
+	/// Removes the blocks of the snapshots taken by `MigrateToV2`, to run after the migrations
+	/// that use `snapshot_at_block`.
+	pub struct ClearSnapshotBlocks<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for ClearSnapshotBlocks<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let result = SnapshotBlocks::<T>::clear(u32::MAX, None);
+			T::DbWeight::get().reads_writes(result.loops.into(), result.backend.into())
+		}
+	}
+}
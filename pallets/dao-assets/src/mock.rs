@@ -5,7 +5,7 @@ use crate as pallet_dao_assets;
 
 use frame_support::{
 	construct_runtime,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, GenesisBuild},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, GenesisBuild, NeverEnsureOrigin},
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -81,9 +81,12 @@ impl Config for Test {
 	type Currency = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type AdminOrigin = AsEnsureOriginWithArg<NeverEnsureOrigin<()>>;
 	type ApprovalDeposit = ConstU64<1>;
 	type StringLimit = ConstU32<50>;
-	type HistoryHorizon = ConstU32<30>;
+	type MaxVestingSchedules = ConstU32<3>;
+	type MinVestedTransfer = ConstU64<10>;
+	type MaxVestingDuration = ConstU64<100>;
 	type WeightInfo = ();
 	type RemoveItemsLimit = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
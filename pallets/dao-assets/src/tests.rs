@@ -7,7 +7,7 @@ use frame_support::{
 	traits::{fungibles::InspectEnumerable, Currency},
 };
 use pallet_balances::Error as BalancesError;
-use sp_runtime::TokenError;
+use sp_runtime::{DispatchError, TokenError};
 
 fn asset_ids() -> Vec<u32> {
 	let mut s: Vec<_> = Assets::asset_ids().collect();
@@ -18,13 +18,13 @@ fn asset_ids() -> Vec<u32> {
 #[test]
 fn basic_minting_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_eq!(Assets::total_historical_supply(0, System::block_number()), Some(0));
+		assert_eq!(Assets::total_supply(0), 0);
 		assert_ok!(Assets::do_force_create(0, 1, 1));
 		assert_ok!(Assets::do_mint(0, &1, 100));
-		assert_eq!(Assets::total_historical_supply(0, System::block_number()), Some(100));
+		assert_eq!(Assets::total_supply(0), 100);
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_ok!(Assets::do_mint(0, &2, 100));
-		assert_eq!(Assets::total_historical_supply(0, System::block_number()), Some(200));
+		assert_eq!(Assets::total_supply(0), 200);
 		assert_eq!(Assets::balance(0, 2), 100);
 		assert_eq!(asset_ids(), vec![0, 999]);
 	});
@@ -415,11 +415,11 @@ fn burning_asset_balance_with_positive_balance_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::do_force_create(0, 1, 1));
 		assert_ok!(Assets::do_mint(0, &1, 100));
-		assert_eq!(Assets::total_historical_supply(0, System::block_number()), Some(100));
+		assert_eq!(Assets::total_supply(0), 100);
 		assert_eq!(Assets::balance(0, 1), 100);
 		let flags = DebitFlags { keep_alive: false, best_effort: true };
 		let _ = Assets::do_burn(0, &1, u64::MAX, flags);
-		assert_eq!(Assets::total_historical_supply(0, System::block_number()), Some(0));
+		assert_eq!(Assets::total_supply(0), 0);
 		assert_eq!(Assets::balance(0, 1), 0);
 	});
 }
@@ -567,6 +567,379 @@ fn reserving_and_unreserving_should_work() {
 		// check undoing reservation worked
 		assert_eq!(Assets::balance(999, 1), 100);
 		assert_eq!(Assets::reserved(999, 1), 0);
+
+		// the whole balance can be reserved
+		assert_ok!(Assets::do_reserve(999, 1, 100));
+		assert_eq!(Assets::balance(999, 1), 0);
+		assert_eq!(Assets::reserved(999, 1), 100);
+		assert_eq!(Assets::total_balance(999, &1), 100);
+		assert_noop!(Assets::do_reserve(999, 1, 1), Error::<Test>::BalanceLow);
+	})
+}
+
+#[test]
+fn slashing_reserved_balance_should_burn() {
+	new_test_ext().execute_with(|| {
+		let supply = Assets::total_supply(999);
+		assert_ok!(Assets::do_reserve(999, 1, 40));
+
+		// burn part of the reservation
+		assert_ok!(Assets::do_slash_reserved(999, 1, 10));
+		assert_eq!(Assets::balance(999, 1), 60);
+		assert_eq!(Assets::reserved(999, 1), 30);
+		assert_eq!(Assets::total_supply(999), supply - 10);
+
+		// burn at most the reserved balance
+		assert_eq!(Assets::do_slash_reserved(999, 1, 1500), Ok(30));
+		assert_eq!(Assets::balance(999, 1), 60);
+		assert_eq!(Assets::reserved(999, 1), 0);
+		assert_eq!(Assets::total_supply(999), supply - 40);
+	})
+}
+
+#[test]
+fn minting_and_burning_should_work() {
+	new_test_ext().execute_with(|| {
+		// only the force or admin origin may mint and burn
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 999, 2, 50), DispatchError::BadOrigin);
+		assert_noop!(Assets::burn(RuntimeOrigin::signed(1), 999, 1, 50), DispatchError::BadOrigin);
+
+		assert_eq!(Assets::snapshot(999), Ok(1));
+		assert_ok!(Assets::mint(RuntimeOrigin::root(), 999, 2, 50));
+		assert_eq!(Assets::balance(999, 2), 50);
+		assert_eq!(Assets::total_supply(999), 150);
+
+		assert_eq!(Assets::snapshot(999), Ok(2));
+		assert_ok!(Assets::burn(RuntimeOrigin::root(), 999, 1, 30));
+		assert_eq!(Assets::balance(999, 1), 70);
+		assert_eq!(Assets::total_supply(999), 120);
+
+		// voting snapshots see the supply and balances at the time they were taken
+		assert_eq!(Assets::total_supply_at(999, 1), Some(100));
+		assert_eq!(Assets::total_supply_at(999, 2), Some(150));
+		assert_eq!(Assets::total_balance_at(999, 1, 2), Some(100));
+		assert_eq!(Assets::total_balance_at(999, 2, 1), Some(0));
+		assert_eq!(Assets::total_balance_at(999, 2, 2), Some(50));
+	})
+}
+
+#[test]
+fn supply_policy_limits_minting() {
+	new_test_ext().execute_with(|| {
+		let policy = SupplyPolicy {
+			max_supply: Some(150),
+			inflation: Some(Inflation { period: 10, max_per_period: Perbill::from_percent(20) }),
+		};
+		assert_noop!(
+			Assets::set_supply_policy(
+				RuntimeOrigin::root(),
+				999,
+				Some(SupplyPolicy { max_supply: Some(99), inflation: None })
+			),
+			Error::<Test>::InvalidSupplyPolicy
+		);
+		assert_ok!(Assets::set_supply_policy(RuntimeOrigin::root(), 999, Some(policy)));
+
+		// at most 20 % of the supply at the beginning of the period can be minted
+		assert_ok!(Assets::mint(RuntimeOrigin::root(), 999, 1, 15));
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::root(), 999, 1, 6),
+			Error::<Test>::InflationExceeded
+		);
+		assert_ok!(Assets::mint(RuntimeOrigin::root(), 999, 1, 5));
+
+		// the next period allows further minting, up to the maximum supply
+		run_to_block(10);
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::root(), 999, 1, 31),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_ok!(Assets::mint(RuntimeOrigin::root(), 999, 1, 24));
+		assert_eq!(Assets::total_supply(999), 144);
+
+		// without a policy minting is not limited
+		assert_ok!(Assets::set_supply_policy(RuntimeOrigin::root(), 999, None));
+		assert_ok!(Assets::mint(RuntimeOrigin::root(), 999, 1, 1000));
+	})
+}
+
+#[test]
+fn vested_transfer_should_lock_balance() {
+	new_test_ext().execute_with(|| {
+		let schedule = VestingSchedule { locked: 50, per_block: 5, start: 1, cliff: 5 };
+		assert_noop!(
+			Assets::vested_transfer(
+				RuntimeOrigin::signed(1),
+				999,
+				2,
+				VestingSchedule { per_block: 0, ..schedule.clone() }
+			),
+			Error::<Test>::InvalidVestingSchedule
+		);
+		// schedules cannot be used to fill up the schedules of an account cheaply
+		assert_noop!(
+			Assets::vested_transfer(
+				RuntimeOrigin::signed(1),
+				999,
+				2,
+				VestingSchedule { locked: 9, ..schedule.clone() }
+			),
+			Error::<Test>::VestedTransferTooLow
+		);
+		assert_noop!(
+			Assets::vested_transfer(
+				RuntimeOrigin::signed(1),
+				999,
+				2,
+				VestingSchedule { cliff: 102, ..schedule.clone() }
+			),
+			Error::<Test>::VestingScheduleTooLong
+		);
+		assert_noop!(
+			Assets::vested_transfer(
+				RuntimeOrigin::signed(1),
+				999,
+				2,
+				VestingSchedule { per_block: 1, start: 60, ..schedule.clone() }
+			),
+			Error::<Test>::VestingScheduleTooLong
+		);
+		assert_noop!(Assets::vest(RuntimeOrigin::signed(2), 999), Error::<Test>::NotVesting);
+		assert_ok!(Assets::vested_transfer(RuntimeOrigin::signed(1), 999, 2, schedule));
+		assert_eq!(Assets::balance(999, 2), 50);
+		assert_eq!(Assets::frozen_balance(999, &2), Some(50));
+
+		// locked tokens count towards the balance used for voting, but cannot be moved
+		assert_eq!(Assets::total_balance(999, &2), 50);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), 999, 3, 1),
+			Error::<Test>::BalanceLow
+		);
+		assert_noop!(Assets::do_reserve(999, 2, 1), Error::<Test>::BalanceLow);
+
+		// nothing is unlocked before the cliff
+		run_to_block(4);
+		assert_ok!(Assets::vest(RuntimeOrigin::signed(2), 999));
+		assert_eq!(Assets::frozen_balance(999, &2), Some(50));
+
+		// afterwards everything vested since the start is unlocked
+		run_to_block(5);
+		assert_ok!(Assets::vest(RuntimeOrigin::signed(2), 999));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::VestingUpdated {
+			asset_id: 999,
+			who: 2,
+			locked: 30,
+		}));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 999, 3, 19));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), 999, 3, 1),
+			Error::<Test>::BalanceLow
+		);
+
+		// fully vested schedules are removed
+		run_to_block(11);
+		assert_ok!(Assets::vest(RuntimeOrigin::signed(2), 999));
+		assert_eq!(Assets::frozen_balance(999, &2), None);
+		assert_noop!(Assets::vest(RuntimeOrigin::signed(2), 999), Error::<Test>::NotVesting);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 999, 3, 31));
+	})
+}
+
+#[test]
+fn vesting_schedules_are_limited() {
+	new_test_ext().execute_with(|| {
+		let schedule = VestingSchedule { locked: 10, per_block: 1, start: 1, cliff: 1 };
+		for _ in 0..3 {
+			assert_ok!(Assets::vested_transfer(RuntimeOrigin::signed(1), 999, 2, schedule.clone()));
+		}
+		assert_noop!(
+			Assets::vested_transfer(RuntimeOrigin::signed(1), 999, 2, schedule),
+			Error::<Test>::TooManyVestingSchedules
+		);
+		assert_eq!(Assets::frozen_balance(999, &2), Some(30));
+	})
+}
+
+#[test]
+fn history_is_recorded_once_per_snapshot() {
+	new_test_ext().execute_with(|| {
+		// without a snapshot no history is recorded
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 1));
+		assert_eq!(AccountCheckpointCount::<Test>::get(999, 1), 0);
+
+		assert_eq!(Assets::snapshot(999), Ok(1));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::SnapshotTaken {
+			asset_id: 999,
+			snapshot_id: 1,
+		}));
+		assert_noop!(Assets::snapshot(0), Error::<Test>::Unknown);
+
+		// only the value before the first change after a snapshot is recorded
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 1));
+		assert_eq!(AccountCheckpointCount::<Test>::get(999, 1), 1);
+		assert_eq!(
+			AccountCheckpoints::<Test>::get((999, 1, 0)),
+			Some(Checkpoint { snapshot: 1, value: 99 })
+		);
+		assert_eq!(Assets::total_balance_at(999, 1, 1), Some(99));
+		assert_eq!(Assets::total_balance(999, 1), 97);
+
+		// snapshots that have not been taken cannot be queried
+		assert_eq!(Assets::total_balance_at(999, 1, 0), None);
+		assert_eq!(Assets::total_balance_at(999, 1, 2), None);
+		assert_eq!(Assets::total_supply_at(999, 2), None);
+
+		// a value that has not changed since a snapshot is the current one
+		assert_eq!(Assets::snapshot(999), Ok(2));
+		assert_eq!(Assets::total_balance_at(999, 1, 2), Some(97));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 1));
+		assert_eq!(AccountCheckpointCount::<Test>::get(999, 1), 2);
+		assert_eq!(Assets::total_balance_at(999, 1, 1), Some(99));
+		assert_eq!(Assets::total_balance_at(999, 1, 2), Some(97));
+
+		// several snapshots without a change share a checkpoint
+		assert_eq!(Assets::snapshot(999), Ok(3));
+		assert_eq!(Assets::snapshot(999), Ok(4));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 3, 1));
+		assert_eq!(AccountCheckpointCount::<Test>::get(999, 1), 3);
+		assert_eq!(Assets::total_balance_at(999, 1, 3), Some(96));
+		assert_eq!(Assets::total_balance_at(999, 1, 4), Some(96));
+		assert_eq!(Assets::total_balance_at(999, 3, 4), Some(0));
+		assert_eq!(Assets::total_balance(999, 1), 95);
+	})
+}
+
+#[test]
+fn history_of_dead_accounts_is_kept() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 10));
+		assert_eq!(Assets::snapshot(999), Ok(1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 999, 3, 10));
+		assert_eq!(Assets::maybe_balance(999, 2), None);
+		assert_eq!(Assets::snapshot(999), Ok(2));
+		assert_eq!(Assets::total_balance_at(999, 2, 1), Some(10));
+		assert_eq!(Assets::total_balance_at(999, 2, 2), Some(0));
+
+		// a reserved balance keeps the account alive
+		assert_ok!(Assets::do_reserve(999, 3, 5));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), 999, 2, 5));
+		assert_eq!(Assets::reserved(999, 3), 5);
+		assert_eq!(Assets::total_balance(999, 3), 5);
+		assert_eq!(Assets::total_balance_at(999, 3, 2), Some(10));
+	})
+}
+
+#[test]
+fn history_is_destroyed_in_batches() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 10));
+		// every snapshot records a checkpoint of both accounts and of the total supply
+		for snapshot_id in 1..=3 {
+			assert_eq!(Assets::snapshot(999), Ok(snapshot_id));
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 10));
+			assert_ok!(Assets::do_mint(999, &1, 1));
+		}
+		assert_eq!(SupplyCheckpointCount::<Test>::get(999), 3);
+		assert_eq!(AccountCheckpointCount::<Test>::get(999, 1), 3);
+		assert_eq!(AccountCheckpointCount::<Test>::get(999, 2), 3);
+
+		// the snapshots in use need to be released first
+		assert_noop!(Assets::do_start_destroy(999, None), Error::<Test>::SnapshotInUse);
+		for snapshot_id in 1..=3 {
+			Assets::release_snapshot(999, snapshot_id);
+		}
+
+		// the accounts are destroyed first, then the checkpoints within the same limit of 5
+		assert_ok!(Assets::do_start_destroy(999, None));
+		assert_eq!(Assets::do_destroy_accounts(999, 5), Ok(5));
+		assert_eq!(SupplyCheckpointCount::<Test>::get(999), 0);
+		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(1), 999), Error::<Test>::InUse);
+		assert_eq!(Assets::do_destroy_accounts(999, 5), Ok(5));
+		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(1), 999), Error::<Test>::InUse);
+		assert_eq!(Assets::do_destroy_accounts(999, 5), Ok(1));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 999));
+
+		assert_eq!(SupplyCheckpoints::<Test>::iter_prefix(999).count(), 0);
+		assert_eq!(AccountCheckpointCount::<Test>::iter_prefix(999).count(), 0);
+		assert_eq!(AccountCheckpoints::<Test>::iter_prefix((999,)).count(), 0);
+	})
+}
+
+#[test]
+fn checkpoints_of_released_snapshots_are_pruned() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::snapshot(999), Ok(1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 1));
+		assert_eq!(Assets::snapshot(999), Ok(2));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 1));
+		assert_eq!(AccountCheckpointCount::<Test>::get(999, 1), 2);
+
+		// values stay available as long as an older snapshot is in use
+		Assets::release_snapshot(999, 2);
+		assert_eq!(Assets::total_balance_at(999, 1, 2), Some(99));
+		Assets::release_snapshot(999, 1);
+		assert_eq!(Assets::total_balance_at(999, 1, 1), None);
+		assert_eq!(Assets::total_balance_at(999, 1, 2), None);
+
+		// without a snapshot in use, changes prune checkpoints instead of recording them
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 1));
+		assert_eq!(PrunedAccountCheckpoints::<Test>::get(999, 1), 2);
+		assert_eq!(AccountCheckpointCount::<Test>::get(999, 1), 2);
+		assert_eq!(AccountCheckpoints::<Test>::iter_prefix((999, 1)).count(), 0);
+
+		// a snapshot in use by several users is retained until all of them released it
+		assert_eq!(Assets::snapshot(999), Ok(3));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 1));
+		assert_eq!(AccountCheckpointCount::<Test>::get(999, 1), 3);
+		assert_ok!(Assets::retain_snapshot(999, 3));
+		Assets::release_snapshot(999, 3);
+		assert_eq!(Assets::total_balance_at(999, 1, 3), Some(98));
+		Assets::release_snapshot(999, 3);
+		assert_eq!(Assets::total_balance_at(999, 1, 3), None);
+		assert_noop!(Assets::retain_snapshot(999, 3), Error::<Test>::SnapshotUnavailable);
+		assert_noop!(Assets::retain_snapshot(999, 4), Error::<Test>::SnapshotUnavailable);
+	})
+}
+
+#[test]
+fn migration_translates_history_into_checkpoints() {
+	use crate::migrations::v2::{self, AccountHistory, SupplyHistory};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use sp_std::collections::btree_map::BTreeMap;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 40));
+		StorageVersion::new(1).put::<Assets>();
+		SupplyHistory::<Test>::insert(999, BTreeMap::from([(0, 100)]));
+		AccountHistory::<Test>::insert(999, 1, BTreeMap::from([(0, 100), (3, 60)]));
+		AccountHistory::<Test>::insert(999, 2, BTreeMap::from([(3, 40)]));
+		// history of an asset without a history of its supply is removed as well
+		AccountHistory::<Test>::insert(5, 1, BTreeMap::from([(2, 7)]));
+
+		v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(Assets::on_chain_storage_version(), 2);
+		assert_eq!(SupplyHistory::<Test>::iter().count(), 0);
+		assert_eq!(AccountHistory::<Test>::iter().count(), 0);
+
+		// a snapshot has been taken for each block of the history
+		assert_eq!(v2::snapshot_at_block::<Test>(999, 0), Some(1));
+		assert_eq!(v2::snapshot_at_block::<Test>(999, 2), Some(1));
+		assert_eq!(v2::snapshot_at_block::<Test>(999, 4), Some(2));
+		assert_eq!(v2::snapshot_at_block::<Test>(5, 4), None);
+		assert_eq!(Assets::total_supply_at(999, 1), Some(100));
+		assert_eq!(Assets::total_balance_at(999, 1, 1), Some(100));
+		assert_eq!(Assets::total_balance_at(999, 1, 2), Some(60));
+		assert_eq!(Assets::total_balance_at(999, 2, 1), Some(0));
+		assert_eq!(Assets::total_balance_at(999, 2, 2), Some(40));
+
+		// changes after the migration are recorded for the latest snapshot
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 999, 1, 10));
+		assert_eq!(Assets::total_balance_at(999, 2, 2), Some(40));
+		assert_eq!(Assets::snapshot(999), Ok(3));
+
+		v2::ClearSnapshotBlocks::<Test>::on_runtime_upgrade();
+		assert_eq!(v2::snapshot_at_block::<Test>(999, 4), None);
 	})
 }
 
@@ -585,7 +958,7 @@ fn run_to_block(n: u64) {
 }
 
 #[test]
-fn query_historic_blocks_should_work() {
+fn query_snapshots_should_work() {
 	new_test_ext().execute_with(|| {
 		let asset_id = 95;
 		let account_id = 32;
@@ -595,228 +968,106 @@ fn query_historic_blocks_should_work() {
 		let transfer1 = 98;
 		let transfer2 = 629984;
 		let burn_amount = 127;
-		let block0 = System::block_number();
 
-		let history = |block, supply, account, account2| {
-			assert_eq!(
-				Assets::total_historical_supply(asset_id, block),
-				Some(supply),
-				"wrong supply"
-			);
+		let history = |snapshot, supply, account, account2| {
+			assert_eq!(Assets::total_supply_at(asset_id, snapshot), Some(supply), "wrong supply");
 			assert_eq!(
-				Assets::total_historical_balance(asset_id, &account_id, block),
+				Assets::total_balance_at(asset_id, &account_id, snapshot),
 				Some(account),
 				"wrong balance for first account"
 			);
 			assert_eq!(
-				Assets::total_historical_balance(asset_id, &account_id2, block),
+				Assets::total_balance_at(asset_id, &account_id2, snapshot),
 				Some(account2),
 				"wrong balance for second account"
 			);
 		};
-		let history_block0 = || {
-			history(block0, 0, 0, 0);
-		};
-		history_block0();
 
 		// create asset
 		assert_ok!(Assets::do_force_create(asset_id, account_id, 1));
-		history_block0();
-
-		// advance to block1
-		println!("advancing to block1");
-		let block1 = block0 + 3;
-		let check1 = block0 + 1;
-		run_to_block(block1);
-		history_block0();
-		history(block1, 0, 0, 0);
+		assert_eq!(Assets::total_supply_at(asset_id, 1), None);
+		let snapshot0 = Assets::snapshot(asset_id).unwrap();
+		let history_snapshot0 = || {
+			history(snapshot0, 0, 0, 0);
+		};
+		history_snapshot0();
 
 		// mint into first account
-		println!("mint into first account");
 		assert_ok!(Assets::do_mint(asset_id, &account_id, amount));
-
-		// history is up to date
-		let history_block1 = || {
-			history(block1, amount, amount, 0);
+		history_snapshot0();
+		let snapshot1 = Assets::snapshot(asset_id).unwrap();
+		let history_snapshot1 = || {
+			history(snapshot1, amount, amount, 0);
 		};
-		history_block1();
-
-		// history is preserved
-		let history_check1 = || {
-			history(check1, 0, 0, 0);
-		};
-		history_check1();
-		history_block0();
-
-		// advance to block2
-		println!("advancing to block2");
-		let block2 = block1 + 5;
-		let check2 = block1 + 3;
-		run_to_block(block2);
-		history_block0();
-		history_check1();
-		history_block1();
-		history(block2, amount, amount, 0);
+		history_snapshot1();
 
 		// mint into second account
-		println!("mint into second account");
 		assert_ok!(Assets::do_mint(asset_id, &account_id2, amount2));
-
-		// history is up to date
-		let history_block2 = || {
-			history(block2, amount + amount2, amount, amount2);
-		};
-		history_block2();
-
-		// history is preserved
-		let history_check2 = || {
-			history(check2, amount, amount, 0);
+		history_snapshot1();
+		history_snapshot0();
+		let snapshot2 = Assets::snapshot(asset_id).unwrap();
+		let history_snapshot2 = || {
+			history(snapshot2, amount + amount2, amount, amount2);
 		};
-		history_check2();
-		history_block1();
-		history_check1();
-		history_block0();
-
-		// advance to block3
-		println!("advancing to block3");
-		let block3 = block2 + 8;
-		let check3 = block2 + 2;
-		run_to_block(block3);
-		history_block0();
-		history_check1();
-		history_block1();
-		history_check2();
-		history_block2();
-		history(block3, amount + amount2, amount, amount2);
+		history_snapshot2();
 
 		// transfer from first account to second account
-		println!("transfer from first account to second account");
 		assert_ok!(Assets::transfer(
 			RuntimeOrigin::signed(account_id),
 			asset_id,
 			account_id2,
 			transfer1
 		));
-
-		// history is up to date
-		let history_block3 = || {
-			history(block3, amount + amount2, amount - transfer1, amount2 + transfer1);
-		};
-		history_block3();
-
-		// history is preserved
-		let history_check3 = || {
-			history(check3, amount + amount2, amount, amount2);
+		history_snapshot2();
+		history_snapshot1();
+		history_snapshot0();
+		let snapshot3 = Assets::snapshot(asset_id).unwrap();
+		let history_snapshot3 = || {
+			history(snapshot3, amount + amount2, amount - transfer1, amount2 + transfer1);
 		};
-		history_check3();
-		history_block2();
-		history_check2();
-		history_block1();
-		history_check1();
-		history_block0();
-
-		// advance to block4
-		println!("advancing to block4");
-		let block4 = block3 + 7;
-		let check4 = block3 + 6;
-		run_to_block(block4);
-		history_block0();
-		history_check1();
-		history_block1();
-		history_check2();
-		history_block2();
-		history_check3();
-		history_block3();
-		history(block4, amount + amount2, amount - transfer1, amount2 + transfer1);
+		history_snapshot3();
 
 		// transfer from second account to first account
-		println!("transfer from second account to first account");
 		assert_ok!(Assets::transfer(
 			RuntimeOrigin::signed(account_id2),
 			asset_id,
 			account_id,
 			transfer2
 		));
-
-		// history is up to date
-		let history_block4 = || {
-			history(
-				block4,
-				amount + amount2,
-				amount - transfer1 + transfer2,
-				amount2 + transfer1 - transfer2,
-			);
-		};
-		history_block4();
-
-		// history is preserved
-		let history_check4 = || {
-			history(check4, amount + amount2, amount - transfer1, amount2 + transfer1);
+		history_snapshot3();
+		history_snapshot2();
+		history_snapshot1();
+		history_snapshot0();
+
+		// two snapshots without changes in between
+		let snapshot4 = Assets::snapshot(asset_id).unwrap();
+		let snapshot5 = Assets::snapshot(asset_id).unwrap();
+		let history_snapshot4_5 = || {
+			for snapshot in [snapshot4, snapshot5] {
+				history(
+					snapshot,
+					amount + amount2,
+					amount - transfer1 + transfer2,
+					amount2 + transfer1 - transfer2,
+				);
+			}
 		};
-		history_check4();
-		history_block3();
-		history_check3();
-		history_block2();
-		history_check2();
-		history_block1();
-		history_check1();
-		history_block0();
-
-		// advance to block5
-		println!("advancing to block5");
-		let block5 = block4 + 4;
-		let check5 = block4 + 1;
-		run_to_block(block5);
-		history_block0();
-		history_check1();
-		history_block1();
-		history_check2();
-		history_block2();
-		history_check3();
-		history_block3();
-		history_check4();
-		history_block4();
-		history(
-			block5,
-			amount + amount2,
-			amount - transfer1 + transfer2,
-			amount2 + transfer1 - transfer2,
-		);
+		history_snapshot4_5();
 
 		// burn from first account
-		println!("burn from first account");
 		let flags = DebitFlags { keep_alive: false, best_effort: false };
 		assert_ok!(Assets::do_burn(asset_id, &account_id, burn_amount, flags));
-
-		// history is up to date
-		let history_block5 = || {
-			history(
-				block5,
-				amount + amount2 - burn_amount,
-				amount - transfer1 + transfer2 - burn_amount,
-				amount2 + transfer1 - transfer2,
-			);
-		};
-		history_block5();
-
-		// history is preserved
-		let history_check5 = || {
-			history(
-				check5,
-				amount + amount2,
-				amount - transfer1 + transfer2,
-				amount2 + transfer1 - transfer2,
-			);
-		};
-		history_check5();
-		history_block4();
-		history_check4();
-		history_block3();
-		history_check3();
-		history_block2();
-		history_check2();
-		history_block1();
-		history_check1();
-		history_block0();
+		history_snapshot4_5();
+		history_snapshot3();
+		history_snapshot2();
+		history_snapshot1();
+		history_snapshot0();
+		let snapshot6 = Assets::snapshot(asset_id).unwrap();
+		history(
+			snapshot6,
+			amount + amount2 - burn_amount,
+			amount - transfer1 + transfer2 - burn_amount,
+			amount2 + transfer1 - transfer2,
+		);
 	})
 }
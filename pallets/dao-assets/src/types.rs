@@ -2,6 +2,8 @@
 
 use super::*;
 use frame_support::{pallet_prelude::*, traits::fungible};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{UniqueSaturatedFrom, UniqueSaturatedInto};
 
 // Type alias for `frame_system`'s account id.
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -14,6 +16,12 @@ pub type DepositBalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<
 // The account data for an asset
 pub type AssetAccountOf<T> = AssetAccount<AssetBalanceOf<T>>;
 pub type AssetDetailsOf<T> = AssetDetails<AssetBalanceOf<T>, AccountIdOf<T>>;
+pub type SupplyPolicyOf<T> = SupplyPolicy<AssetBalanceOf<T>, BlockNumberFor<T>>;
+pub type VestingScheduleOf<T> = VestingSchedule<AssetBalanceOf<T>, BlockNumberFor<T>>;
+pub type CheckpointOf<T> = Checkpoint<AssetBalanceOf<T>>;
+// The index of a checkpoint within a history
+pub type CheckpointIndex = u32;
+pub type SnapshotId = u32;
 
 /// AssetStatus holds the current state of the asset. It could either be Live and available for use,
 /// or in a Destroying state.
@@ -72,18 +80,99 @@ pub struct AssetMetadata<BoundedString> {
 	pub(super) decimals: u8,
 }
 
+/// A historical value, valid for all snapshots up to and including `snapshot` that are newer
+/// than the snapshot of the previous checkpoint.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Checkpoint<Balance> {
+	/// The latest snapshot at the time the value was recorded.
+	pub snapshot: SnapshotId,
+	/// The value before the first change after `snapshot`.
+	pub value: Balance,
+}
+
+/// Limits to minting an asset after its issuance.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SupplyPolicy<Balance, BlockNumber> {
+	/// The total supply may never exceed this amount.
+	pub max_supply: Option<Balance>,
+	/// The inflation schedule limiting the amount minted per period.
+	pub inflation: Option<Inflation<BlockNumber>>,
+}
+
+/// An inflation schedule: per period at most a share of the supply at the beginning of the period
+/// may be minted.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Inflation<BlockNumber> {
+	/// The length of a period in blocks.
+	pub period: BlockNumber,
+	/// The share of the supply that may be minted per period.
+	pub max_per_period: Perbill,
+}
+
+/// The amount that may still be minted in the current inflation period of an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct InflationPeriod<Balance, BlockNumber> {
+	/// The first block of the period.
+	pub start: BlockNumber,
+	/// The amount that may still be minted in this period.
+	pub allowance: Balance,
+}
+
+/// A schedule locking an amount of assets that are linearly unlocked after a cliff.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<Balance, BlockNumber> {
+	/// The amount locked at the start of the schedule.
+	pub locked: Balance,
+	/// The amount unlocked per block after `start`.
+	pub per_block: Balance,
+	/// The block at which the amount starts to vest.
+	pub start: BlockNumber,
+	/// Until this block nothing is unlocked, afterwards everything vested since `start` is.
+	pub cliff: BlockNumber,
+}
+
+impl<Balance, BlockNumber> VestingSchedule<Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Whether the schedule locks anything and eventually unlocks everything.
+	pub fn is_valid(&self) -> bool {
+		!self.locked.is_zero() && !self.per_block.is_zero() && self.cliff >= self.start
+	}
+
+	/// The block from which on nothing is locked anymore.
+	pub fn end(&self) -> BlockNumber {
+		let locked: u128 = self.locked.unique_saturated_into();
+		let per_block: u128 = self.per_block.unique_saturated_into();
+		let blocks = locked.saturating_add(per_block.saturating_sub(1)) / per_block.max(1);
+		let end = self.start.saturating_add(BlockNumber::unique_saturated_from(blocks));
+		end.max(self.cliff)
+	}
+
+	/// The amount still locked at block `n`.
+	pub fn locked_at(&self, n: BlockNumber) -> Balance {
+		if n < self.cliff {
+			return self.locked
+		}
+		let elapsed: u128 = n.saturating_sub(self.start).unique_saturated_into();
+		let vested = self.per_block.saturating_mul(Balance::unique_saturated_from(elapsed));
+		self.locked.saturating_sub(vested)
+	}
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
-pub(super) struct TransferFlags {
+pub struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
 	/// this cannot be achieved legally.
-	pub(super) keep_alive: bool,
+	pub keep_alive: bool,
 	/// Less than the amount specified needs be debited by the operation for it to be considered
 	/// successful. If `false`, then the amount debited will always be at least the amount
 	/// specified.
-	pub(super) best_effort: bool,
+	pub best_effort: bool,
 	/// Any additional funds debited (due to minimum balance requirements) should be burned rather
 	/// than credited to the destination account.
-	pub(super) burn_dust: bool,
+	pub burn_dust: bool,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -57,6 +57,9 @@ pub trait WeightInfo {
 	fn transfer_approved() -> Weight;
 	fn cancel_approval() -> Weight;
 	fn force_cancel_approval() -> Weight;
+	fn set_supply_policy() -> Weight;
+	fn vest() -> Weight;
+	fn vested_transfer() -> Weight;
 }
 
 /// Weights for pallet_dao_assets using the Substrate node and recommended hardware.
@@ -78,9 +81,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 
 	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets OpenSnapshots (r:1 w:0)
 	fn start_destroy() -> Weight {
 		Weight::from_parts(31_000_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 
@@ -121,11 +125,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets SupplyPolicies (r:1 w:0)
+	// Storage: Assets InflationPeriods (r:1 w:1)
 	fn mint() -> Weight {
 		// Minimum execution time: 36_782 nanoseconds.
 		Weight::from_parts(37_340_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:1 w:1)
@@ -138,20 +144,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:2 w:2)
 	// Storage: System Account (r:1 w:1)
+	// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	// Storage: Assets AccountCheckpointCount (r:2 w:2)
+	// Storage: Assets RetainedSnapshotId (r:1 w:0)
+	// Storage: Assets PrunedAccountCheckpoints (r:2 w:2)
+	// Storage: Assets AccountCheckpoints (r:6 w:6)
 	fn transfer() -> Weight {
 		// Minimum execution time: 58_294 nanoseconds.
 		Weight::from_parts(59_447_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
 	}
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:2 w:2)
 	// Storage: System Account (r:1 w:1)
+	// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	// Storage: Assets AccountCheckpointCount (r:2 w:2)
+	// Storage: Assets RetainedSnapshotId (r:1 w:0)
+	// Storage: Assets PrunedAccountCheckpoints (r:2 w:2)
+	// Storage: Assets AccountCheckpoints (r:6 w:6)
 	fn transfer_keep_alive() -> Weight {
 		// Minimum execution time: 46_704 nanoseconds.
 		Weight::from_parts(47_521_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
 	}
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:2 w:2)
@@ -290,6 +306,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets SupplyPolicies (r:0 w:1)
+	// Storage: Assets InflationPeriods (r:0 w:1)
+	fn set_supply_policy() -> Weight {
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_parts(22_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Vesting (r:1 w:1)
+	// Storage: Assets VestingLocks (r:0 w:1)
+	fn vest() -> Weight {
+		// Minimum execution time: 24_310 nanoseconds.
+		Weight::from_parts(25_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Vesting (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets VestingLocks (r:2 w:1)
+	// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	// Storage: Assets AccountCheckpointCount (r:2 w:2)
+	// Storage: Assets RetainedSnapshotId (r:1 w:0)
+	// Storage: Assets PrunedAccountCheckpoints (r:2 w:2)
+	// Storage: Assets AccountCheckpoints (r:6 w:6)
+	// Storage: System Account (r:1 w:1)
+	fn vested_transfer() -> Weight {
+		// Minimum execution time: 61_482 nanoseconds.
+		Weight::from_parts(62_500_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -310,9 +360,10 @@ impl WeightInfo for () {
 	}
 
 	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets OpenSnapshots (r:1 w:0)
 	fn start_destroy() -> Weight {
 		Weight::from_parts(31_000_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 
@@ -353,11 +404,13 @@ impl WeightInfo for () {
 
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets SupplyPolicies (r:1 w:0)
+	// Storage: Assets InflationPeriods (r:1 w:1)
 	fn mint() -> Weight {
 		// Minimum execution time: 36_782 nanoseconds.
 		Weight::from_parts(37_340_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:1 w:1)
@@ -370,20 +423,30 @@ impl WeightInfo for () {
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:2 w:2)
 	// Storage: System Account (r:1 w:1)
+	// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	// Storage: Assets AccountCheckpointCount (r:2 w:2)
+	// Storage: Assets RetainedSnapshotId (r:1 w:0)
+	// Storage: Assets PrunedAccountCheckpoints (r:2 w:2)
+	// Storage: Assets AccountCheckpoints (r:6 w:6)
 	fn transfer() -> Weight {
 		// Minimum execution time: 58_294 nanoseconds.
 		Weight::from_parts(59_447_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
 	}
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:2 w:2)
 	// Storage: System Account (r:1 w:1)
+	// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	// Storage: Assets AccountCheckpointCount (r:2 w:2)
+	// Storage: Assets RetainedSnapshotId (r:1 w:0)
+	// Storage: Assets PrunedAccountCheckpoints (r:2 w:2)
+	// Storage: Assets AccountCheckpoints (r:6 w:6)
 	fn transfer_keep_alive() -> Weight {
 		// Minimum execution time: 46_704 nanoseconds.
 		Weight::from_parts(47_521_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
 	}
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:2 w:2)
@@ -522,4 +585,38 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets SupplyPolicies (r:0 w:1)
+	// Storage: Assets InflationPeriods (r:0 w:1)
+	fn set_supply_policy() -> Weight {
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_parts(22_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Vesting (r:1 w:1)
+	// Storage: Assets VestingLocks (r:0 w:1)
+	fn vest() -> Weight {
+		// Minimum execution time: 24_310 nanoseconds.
+		Weight::from_parts(25_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Vesting (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets VestingLocks (r:2 w:1)
+	// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	// Storage: Assets AccountCheckpointCount (r:2 w:2)
+	// Storage: Assets RetainedSnapshotId (r:1 w:0)
+	// Storage: Assets PrunedAccountCheckpoints (r:2 w:2)
+	// Storage: Assets AccountCheckpoints (r:6 w:6)
+	// Storage: System Account (r:1 w:1)
+	fn vested_transfer() -> Weight {
+		// Minimum execution time: 61_482 nanoseconds.
+		Weight::from_parts(62_500_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
 }
@@ -8,7 +8,8 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
+use frame_support::traits::{EnsureOrigin, Get};
 use frame_system::RawOrigin;
 
 use crate::Pallet as DaoCore;
@@ -55,12 +56,26 @@ benchmarks! {
 	destroy_dao {
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao::<T>(caller.clone());
+		let treasury = DaoCore::<T>::dao_account_id(&dao_id.clone().try_into().expect("fits"));
+		<T as Config>::Currency::make_free_balance_be(&treasury, <T as Config>::Currency::minimum_balance());
 	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone())
   	verify {
 		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
 		assert_last_event::<T>(Event::DaoDestroyed { dao_id }.into());
 	}
 
+	continue_destroy_dao {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let id: DaoIdOf<T> = dao_id.clone().try_into().expect("fits");
+		DestroyingDaos::<T>::insert(&id, ());
+		let treasury = DaoCore::<T>::dao_account_id(&id);
+		<T as Config>::Currency::make_free_balance_be(&treasury, <T as Config>::Currency::minimum_balance());
+	}: _(RawOrigin::Signed(caller), dao_id)
+	verify {
+		assert_last_event::<T>(Event::DaoDestroyed { dao_id: id }.into());
+	}
+
 	issue_token {
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao::<T>(caller.clone());
@@ -83,15 +98,117 @@ benchmarks! {
 		assert_last_event::<T>(Event::DaoMetadataSet { dao_id }.into());
 	}
 
+	spend {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let treasury = DaoCore::<T>::dao_account_id(&dao_id.clone().try_into().expect("fits"));
+		let amount = <T as Config>::Currency::minimum_balance() * 100u32.into();
+		<T as Config>::Currency::make_free_balance_be(&treasury, amount * 2u32.into());
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let origin = T::DaoOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, dao_id.clone(), beneficiary.clone(), amount)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::TreasurySpent { dao_id, beneficiary, amount }.into());
+	}
+
+	spend_token {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+		let treasury = DaoCore::<T>::dao_account_id(&dao_id.clone().try_into().expect("fits"));
+		let asset_id = DaoCore::<T>::load_dao(dao_id.clone()).unwrap().asset_id.unwrap();
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+		Assets::<T>::do_transfer(asset_id.into(), &caller, &treasury, 200u32.into(), f)?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let amount: T::Balance = 100u32.into();
+		let origin = T::DaoOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, dao_id.clone(), beneficiary.clone(), amount)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::TreasuryTokenSpent { dao_id, beneficiary, amount }.into());
+	}
+
+	set_council {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let members: Vec<T::AccountId> =
+			(0..T::MaxCouncilMembers::get()).map(|i| account("member", i, 0)).collect();
+		let threshold = T::MaxCouncilMembers::get();
+	}: _(RawOrigin::Signed(caller), dao_id.clone(), members.clone(), threshold)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoCouncilSet { dao_id, members, threshold }.into());
+	}
+
 	change_owner {
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao::<T>(caller.clone());
-		let new_owner: T::AccountId = account("new owner", 0, 0);
-	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), new_owner.clone())
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		<T as Config>::Currency::make_free_balance_be(&new_owner, <T as Config>::Currency::minimum_balance() * 100u32.into());
+		let origin = T::DaoOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, dao_id.clone(), new_owner.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoOwnerChanged { dao_id, new_owner }.into());
+	}
+
+	propose_owner {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+	}: _(RawOrigin::Signed(caller), dao_id.clone(), new_owner.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoOwnerProposed { dao_id, new_owner }.into());
+	}
+
+	accept_ownership {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		<T as Config>::Currency::make_free_balance_be(&new_owner, <T as Config>::Currency::minimum_balance() * 100u32.into());
+		DaoCore::<T>::propose_owner(RawOrigin::Signed(caller).into(), dao_id.clone(), new_owner.clone())?;
+	}: _(RawOrigin::Signed(new_owner.clone()), dao_id.clone())
 	verify {
 		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
 		assert_last_event::<T>(Event::DaoOwnerChanged { dao_id, new_owner }.into());
 	}
 
+	cancel_ownership_transfer {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		DaoCore::<T>::propose_owner(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), new_owner)?;
+	}: _(RawOrigin::Signed(caller), dao_id.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoOwnershipTransferCancelled { dao_id }.into());
+	}
+
+	set_strict_metadata {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+	}: _(RawOrigin::Signed(caller), dao_id.clone(), true)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoStrictMetadataSet { dao_id, strict: true }.into());
+	}
+
+	locate_dao_deposit {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let id: DaoIdOf<T> = dao_id.clone().try_into().expect("fits");
+		let owner: T::AccountId = account("owner", 0, 0);
+		<T as Config>::Currency::make_free_balance_be(&owner, <T as Config>::Currency::minimum_balance() * 100u32.into());
+		Daos::<T>::mutate(&id, |dao| dao.as_mut().expect("exists").owner = owner);
+		UnlocatedDeposits::<T>::insert(&id, ());
+	}: _(RawOrigin::Root, dao_id, caller.clone())
+	verify {
+		assert_last_event::<T>(Event::DaoDepositLocated { dao_id: id, holder: caller }.into());
+	}
+
 	impl_benchmark_test_suite!(DaoCore, crate::mock::new_test_ext(), crate::mock::Test)
 }
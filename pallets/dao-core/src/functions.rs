@@ -1,5 +1,14 @@
 //! Functions for the dao-core pallet.
 use super::*;
+use codec::Encode;
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	sp_runtime::traits::{AccountIdConversion, Hash},
+	traits::{BalanceStatus, EnsureOrigin, ReservableCurrency},
+};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use pallet_dao_assets::{AssetStatus, WeightInfo as _};
 
 impl<T: Config> Pallet<T> {
 	/// Load a dao from storage by id.
@@ -11,10 +20,220 @@ impl<T: Config> Pallet<T> {
 		<Daos<T>>::get(bounded_dao_id).ok_or(Error::<T>::DaoDoesNotExist)
 	}
 
+	/// Load all DAOs owned by an account.
+	///
+	/// This iterates over all DAOs and is meant to be used off-chain, e.g. by the runtime API.
+	///
+	/// - `owner`: the owner of the DAOs
+	pub fn daos_by_owner(owner: &AccountIdOf<T>) -> Vec<DaoOf<T>> {
+		<Daos<T>>::iter_values().filter(|dao| dao.owner == *owner).collect()
+	}
+
+	/// Ensure that `origin` is either signed by the owner of the DAO or the DAO itself acting
+	/// collectively, and that the DAO is not being destroyed.
+	///
+	/// - `origin`: the origin to check
+	/// - `dao`: the DAO the origin needs to be authorized for
+	pub fn ensure_owner_or_dao(origin: OriginFor<T>, dao: &DaoOf<T>) -> DispatchResult {
+		match T::DaoOrigin::try_origin(origin) {
+			Ok(dao_id) => ensure!(dao_id == dao.id, Error::<T>::DaoOriginMismatch),
+			Err(origin) => {
+				let sender = ensure_signed(origin)?;
+				ensure!(sender == dao.owner, Error::<T>::DaoSignerNotOwner);
+			},
+		}
+		ensure!(!<DestroyingDaos<T>>::contains_key(&dao.id), Error::<T>::DaoIsBeingDestroyed);
+		Ok(())
+	}
+
+	/// Authorize an action restricted to the owner of a DAO, which its council can take as well.
+	///
+	/// The owner is authorized right away. A member of the council approves the action instead,
+	/// which is authorized once _threshold_ members approved it. Approvals of former members do
+	/// not count.
+	///
+	/// Returns whether the action is authorized, `false` if approvals of the council are missing.
+	///
+	/// - `sender`: the signer of the action
+	/// - `dao`: the DAO the action is taken for
+	/// - `action`: the action, e.g. the call taking it, approvals are counted per hash of it
+	pub fn authorize_owner_action(
+		sender: &AccountIdOf<T>,
+		dao: &DaoOf<T>,
+		action: &impl Encode,
+	) -> Result<bool, DispatchError> {
+		if *sender == dao.owner {
+			return Ok(true);
+		}
+		let council = <Councils<T>>::get(&dao.id).ok_or(Error::<T>::DaoSignerNotOwner)?;
+		ensure!(council.members.contains(sender), Error::<T>::DaoSignerNotOwner);
+
+		let action = T::Hashing::hash_of(action);
+		let mut approvals = <CouncilApprovals<T>>::get(&dao.id, action);
+		approvals.retain(|member| council.members.contains(member));
+		if !approvals.contains(sender) {
+			// cannot fail, there are never more approvals than council members
+			let _ = approvals.try_push(sender.clone());
+		}
+		Self::deposit_event(Event::CouncilActionApproved {
+			dao_id: dao.id.clone(),
+			member: sender.clone(),
+			action,
+			approvals: approvals.len() as u32,
+		});
+
+		if approvals.len() as u32 >= council.threshold {
+			<CouncilApprovals<T>>::remove(&dao.id, action);
+			Ok(true)
+		} else {
+			<CouncilApprovals<T>>::insert(&dao.id, action, approvals);
+			Ok(false)
+		}
+	}
+
+	/// Transfer the ownership of a DAO, along with its asset if a token has been issued. The
+	/// _DaoDeposit_ is moved from the reserve of the previous owner to the reserve of the new
+	/// owner, who therefore needs an existing account, unless the deposit hasn't been located.
+	/// A pending ownership transfer is discarded.
+	///
+	/// - `dao`: the DAO to transfer the ownership of
+	/// - `new_owner`: the new owner
+	pub(crate) fn do_change_owner(mut dao: DaoOf<T>, new_owner: AccountIdOf<T>) -> DispatchResult {
+		if dao.owner != new_owner {
+			if let Some(asset_id) = dao.asset_id {
+				Assets::<T>::change_owner(asset_id.into(), new_owner.clone())?;
+			}
+			if !<UnlocatedDeposits<T>>::contains_key(&dao.id) {
+				Self::move_deposit(&dao.owner, &new_owner)?;
+			}
+			dao.owner = new_owner.clone();
+		}
+		<ProposedOwners<T>>::remove(&dao.id);
+		Self::deposit_event(Event::DaoOwnerChanged { dao_id: dao.id.clone(), new_owner });
+		<Daos<T>>::insert(dao.id.clone(), dao);
+		Ok(())
+	}
+
+	/// Move the _DaoDeposit_ from the reserve of one account to the reserve of another.
+	///
+	/// - `from`: the account reserving the deposit, failing if it doesn't reserve all of it
+	/// - `to`: the account to reserve the deposit, which needs to exist
+	pub(crate) fn move_deposit(from: &AccountIdOf<T>, to: &AccountIdOf<T>) -> DispatchResult {
+		let remaining = <T as Config>::Currency::repatriate_reserved(
+			from,
+			to,
+			<T as Config>::DaoDeposit::get(),
+			BalanceStatus::Reserved,
+		)?;
+		ensure!(remaining.is_zero(), Error::<T>::DaoDepositNotReserved);
+		Ok(())
+	}
+
+	/// Remove up to _RemoveItemsLimit_ items of a DAO being destroyed: first what other pallets
+	/// keep for it, then the accounts and approvals of its token. Once all items have been
+	/// removed, the treasury is swept to the owner, the DAO is removed and the _DaoDeposit_
+	/// released, unless it hasn't been located.
+	///
+	/// - `dao`: the DAO being destroyed
+	pub(crate) fn destroy_items(dao: DaoOf<T>) -> DispatchResult {
+		let limit = <T as pallet_dao_assets::Config>::RemoveItemsLimit::get();
+		if !T::DaoTeardown::teardown(&dao.id, limit) {
+			return Ok(());
+		}
+
+		if let Some(asset_id) = dao.asset_id {
+			let asset_id = asset_id.into();
+			if let Some(asset) = pallet_dao_assets::Asset::<T>::get(asset_id) {
+				if asset.status == AssetStatus::Live {
+					Assets::<T>::do_start_destroy(asset_id, None)?;
+				}
+				if asset.status != AssetStatus::Destroyed {
+					Assets::<T>::do_destroy_accounts(asset_id, limit)?;
+					Assets::<T>::do_destroy_approvals(asset_id, limit)?;
+					// fails as long as accounts, checkpoints or approvals are left
+					if Assets::<T>::do_finish_destroy(asset_id).is_err() {
+						return Ok(());
+					}
+				}
+			}
+		}
+
+		// the native currency that cannot be transferred to the owner is burned
+		let treasury = Self::dao_account_id(&dao.id);
+		let balance = <T as Config>::Currency::free_balance(&treasury);
+		if <T as Config>::Currency::transfer(
+			&treasury,
+			&dao.owner,
+			balance,
+			ExistenceRequirement::AllowDeath,
+		)
+		.is_err()
+		{
+			let _ = <T as Config>::Currency::slash(&treasury, balance);
+		}
+
+		if <UnlocatedDeposits<T>>::take(&dao.id).is_none() {
+			<T as Config>::Currency::unreserve(&dao.owner, <T as Config>::DaoDeposit::get());
+		}
+		let _ = <CouncilApprovals<T>>::clear_prefix(&dao.id, T::MaxCouncilMembers::get(), None);
+		<Councils<T>>::remove(&dao.id);
+		<ProposedOwners<T>>::remove(&dao.id);
+		<StrictMetadataDaos<T>>::remove(&dao.id);
+		<DestroyingDaos<T>>::remove(&dao.id);
+		<Daos<T>>::remove(&dao.id);
+		Self::deposit_event(Event::DaoDestroyed { dao_id: dao.id });
+		Ok(())
+	}
+
+	/// The weight of removing up to _RemoveItemsLimit_ items of a DAO being destroyed, on top
+	/// of the weight of `continue_destroy_dao`.
+	pub fn destroy_items_weight() -> Weight {
+		let limit = <T as pallet_dao_assets::Config>::RemoveItemsLimit::get();
+		T::DaoTeardown::teardown_weight(limit)
+			.saturating_add(<T as pallet_dao_assets::Config>::WeightInfo::start_destroy())
+			.saturating_add(<T as pallet_dao_assets::Config>::WeightInfo::destroy_accounts(limit))
+			.saturating_add(<T as pallet_dao_assets::Config>::WeightInfo::destroy_approvals(limit))
+			.saturating_add(<T as pallet_dao_assets::Config>::WeightInfo::finish_destroy())
+	}
+
+	/// The keyless account of a DAO, holding its treasury.
+	///
+	/// It is derived from the pallet id and the `dao_id` and can only act through
+	/// the governance of the DAO.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn dao_account_id(dao_id: &DaoIdOf<T>) -> AccountIdOf<T> {
+		T::PalletId::get().into_sub_account_truncating(dao_id)
+	}
+
+	/// The native currency held by the treasury of a DAO.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn treasury_balance(dao_id: &DaoIdOf<T>) -> DepositBalanceOf<T> {
+		CurrencyOf::<T>::free_balance(&Self::dao_account_id(dao_id))
+	}
+
+	/// The DAO tokens held by the treasury of a DAO, zero if no token has been issued.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn treasury_token_balance(
+		dao_id: &DaoIdOf<T>,
+	) -> <T as pallet_dao_assets::Config>::Balance {
+		<Daos<T>>::get(dao_id)
+			.and_then(|dao| dao.asset_id)
+			.map(|asset_id| Assets::<T>::balance(asset_id.into(), Self::dao_account_id(dao_id)))
+			.unwrap_or_default()
+	}
+
+	/// Whether metadata of a DAO or its proposals is valid: according to the _MetadataValidator_
+	/// and, if the DAO opted into strict metadata, the _StrictMetadataValidator_.
+	///
+	/// - `dao_id`: the DAO the metadata belongs to
 	/// - `meta`: the address to be validated
-	pub fn metadata_is_valid(_meta: &MetadataOf<T>) -> bool {
-		// this is currently empty, but we will offer a hook to
-		// ink! in future versions to add custom validations.
-		true
+	/// - `hash`: the hash of the content found at `meta`
+	pub fn metadata_is_valid(dao_id: &DaoIdOf<T>, meta: &[u8], hash: &[u8]) -> bool {
+		T::MetadataValidator::is_valid(meta, hash) &&
+			(!<StrictMetadataDaos<T>>::contains_key(dao_id) ||
+				T::StrictMetadataValidator::is_valid(meta, hash))
 	}
 }
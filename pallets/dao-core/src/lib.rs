@@ -15,19 +15,28 @@ mod tests;
 
 pub mod functions;
 
+pub mod migrations;
+
+pub mod metadata;
+pub use metadata::MetadataValidator;
+
 mod types;
-pub use types::Dao;
+pub use types::{Council, Dao, DaoTeardown, RawOrigin};
+
+mod origin;
+pub use origin::{EnsureDao, EnsureDaoToken};
 
 pub use frame_support::{
-	sp_runtime::traits::{One, Saturating},
+	sp_runtime::traits::{One, Saturating, Zero},
 	storage::bounded_vec::BoundedVec,
 	traits::{
 		tokens::fungibles::{metadata::Mutate as MetadataMutate, Mutate},
-		Currency,
+		Currency, ExistenceRequirement,
 	},
 	weights::Weight,
+	PalletId,
 };
-use pallet_dao_assets::{Pallet as Assets};
+use pallet_dao_assets::{Pallet as Assets, TransferFlags};
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type CurrencyOf<T> = <T as Config>::Currency;
@@ -37,13 +46,15 @@ type AssetIdOf<T> = <T as Config>::AssetId;
 pub type DaoIdOf<T> = BoundedVec<u8, <T as Config>::MaxLengthId>;
 type DaoNameOf<T> = BoundedVec<u8, <T as Config>::MaxLengthName>;
 pub type MetadataOf<T> = BoundedVec<u8, <T as Config>::MaxLengthMetadata>;
-type DaoOf<T> = Dao<
+pub type DaoOf<T> = Dao<
 	DaoIdOf<T>,
 	<T as frame_system::Config>::AccountId,
 	DaoNameOf<T>,
 	AssetIdOf<T>,
 	MetadataOf<T>,
 >;
+pub type CouncilOf<T> =
+	Council<BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxCouncilMembers>>;
 
 pub mod weights;
 use weights::WeightInfo;
@@ -56,7 +67,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -96,8 +107,32 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type TokenUnits: Get<u8>;
+
+		/// The maximum number of members of the council of a DAO.
+		#[pallet::constant]
+		type MaxCouncilMembers: Get<u32>;
+
+		/// The pallet id used to derive the keyless account of each DAO.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The origin of a DAO acting collectively, i.e. through an accepted proposal.
+		type DaoOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = DaoIdOf<Self>>;
+
+		/// Removes what other pallets keep for a DAO while it is being destroyed.
+		type DaoTeardown: DaoTeardown<DaoIdOf<Self>>;
+
+		/// Validates the metadata of DAOs and their proposals.
+		type MetadataValidator: MetadataValidator;
+
+		/// Validates the metadata of DAOs that opted into strict metadata, and of their proposals,
+		/// in addition to the _MetadataValidator_.
+		type StrictMetadataValidator: MetadataValidator;
 	}
 
+	#[pallet::origin]
+	pub type Origin<T> = RawOrigin<DaoIdOf<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -108,6 +143,9 @@ pub mod pallet {
 		DaoDestroyed {
 			dao_id: DaoIdOf<T>,
 		},
+		DaoDestructionStarted {
+			dao_id: DaoIdOf<T>,
+		},
 		DaoTokenIssued {
 			dao_id: DaoIdOf<T>,
 			supply: <T as pallet_dao_assets::Config>::Balance,
@@ -120,6 +158,42 @@ pub mod pallet {
 			dao_id: DaoIdOf<T>,
 			new_owner: T::AccountId,
 		},
+		DaoOwnerProposed {
+			dao_id: DaoIdOf<T>,
+			new_owner: T::AccountId,
+		},
+		DaoOwnershipTransferCancelled {
+			dao_id: DaoIdOf<T>,
+		},
+		DaoStrictMetadataSet {
+			dao_id: DaoIdOf<T>,
+			strict: bool,
+		},
+		TreasurySpent {
+			dao_id: DaoIdOf<T>,
+			beneficiary: T::AccountId,
+			amount: DepositBalanceOf<T>,
+		},
+		TreasuryTokenSpent {
+			dao_id: DaoIdOf<T>,
+			beneficiary: T::AccountId,
+			amount: <T as pallet_dao_assets::Config>::Balance,
+		},
+		DaoCouncilSet {
+			dao_id: DaoIdOf<T>,
+			members: Vec<T::AccountId>,
+			threshold: u32,
+		},
+		CouncilActionApproved {
+			dao_id: DaoIdOf<T>,
+			member: T::AccountId,
+			action: T::Hash,
+			approvals: u32,
+		},
+		DaoDepositLocated {
+			dao_id: DaoIdOf<T>,
+			holder: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -133,9 +207,20 @@ pub mod pallet {
 		DaoDoesNotExist,
 		DaoSignerNotOwner,
 		DaoTokenAlreadyIssued,
+		DaoTokenNotYetIssued,
+		DaoOriginMismatch,
 		MetadataInvalidLengthTooLong,
 		MetadataInvalid,
 		HashInvalidWrongLength,
+		CouncilTooManyMembers,
+		CouncilMemberDuplicate,
+		CouncilThresholdInvalid,
+		DaoOwnerNotProposed,
+		DaoSignerNotProposedOwner,
+		DaoIsBeingDestroyed,
+		DaoIsNotBeingDestroyed,
+		DaoDepositNotReserved,
+		DaoDepositAlreadyLocated,
 	}
 
 	/// Key-Value Store of all _DAOs_, with the key being the `dao_id`.
@@ -143,6 +228,42 @@ pub mod pallet {
 	#[pallet::getter(fn get_dao)]
 	pub type Daos<T: Config> = StorageMap<_, Blake2_128Concat, DaoIdOf<T>, DaoOf<T>>;
 
+	/// The accounts proposed as new owners of DAOs, which have not accepted the ownership yet.
+	#[pallet::storage]
+	#[pallet::getter(fn get_proposed_owner)]
+	pub type ProposedOwners<T: Config> = StorageMap<_, Blake2_128Concat, DaoIdOf<T>, T::AccountId>;
+
+	/// The DAOs that opted into strict metadata, see _StrictMetadataValidator_.
+	#[pallet::storage]
+	pub type StrictMetadataDaos<T: Config> = StorageMap<_, Blake2_128Concat, DaoIdOf<T>, ()>;
+
+	/// The DAOs being destroyed, whose items are removed over several blocks.
+	#[pallet::storage]
+	pub type DestroyingDaos<T: Config> = StorageMap<_, Blake2_128Concat, DaoIdOf<T>, ()>;
+
+	/// The DAOs whose _DaoDeposit_ is not known to be reserved by their owner, because they
+	/// changed owners before the deposit was moved along with the ownership. Their deposit is
+	/// neither moved nor released until it is located with `locate_dao_deposit`.
+	#[pallet::storage]
+	pub type UnlocatedDeposits<T: Config> = StorageMap<_, Blake2_128Concat, DaoIdOf<T>, ()>;
+
+	/// The councils of DAOs, which can act in place of the owner.
+	#[pallet::storage]
+	#[pallet::getter(fn get_council)]
+	pub type Councils<T: Config> = StorageMap<_, Blake2_128Concat, DaoIdOf<T>, CouncilOf<T>>;
+
+	/// The council members that approved an action of the owner of a DAO, by hash of the action.
+	#[pallet::storage]
+	pub type CouncilApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DaoIdOf<T>,
+		Identity,
+		T::Hash,
+		BoundedVec<T::AccountId, T::MaxCouncilMembers>,
+		ValueQuery,
+	>;
+
 	/// Internal incrementor of all assets issued by this module.
 	/// The first asset starts with _1_ (sic!, not 0) and then the id is assigned by order of
 	/// creation.
@@ -150,6 +271,13 @@ pub mod pallet {
 	#[pallet::getter(fn get_current_asset_id)]
 	pub type CurrentAssetId<T> = StorageValue<_, AssetIdOf<T>, ValueQuery>;
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			migrations::v2::move_dao_deposits::<T>(remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a fresh DAO.
@@ -205,26 +333,43 @@ pub mod pallet {
 		///
 		/// - `dao_id`: The DAO to destroy
 		///
-		/// Signer of this TX needs to be the owner of the DAO.
+		/// Signer of this TX needs to be the owner of the DAO. Its governance and proposals are
+		/// removed, returning the deposits of proposals, and its token is destroyed if one has
+		/// been issued, burning the tokens held by the treasury along with all others. Up to
+		/// _RemoveItemsLimit_ items are removed right away, the remaining ones with
+		/// `continue_destroy_dao`. Once all items have been removed, the native currency left in
+		/// the treasury is transferred to the owner and the _DaoDeposit_ is released.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::destroy_dao())]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::destroy_dao()
+				.saturating_add(Pallet::<T>::destroy_items_weight())
+		)]
 		pub fn destroy_dao(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let dao = Self::load_dao(dao_id)?;
 			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			ensure!(!<DestroyingDaos<T>>::contains_key(&dao.id), Error::<T>::DaoIsBeingDestroyed);
 
-			if let Some(asset_id) = dao.asset_id {
-				if let Some(asset) = pallet_dao_assets::Asset::<T>::get(asset_id.into()) {
-					if pallet_dao_assets::AssetStatus::Destroyed != asset.status {
-						Err(Error::<T>::DaoTokenAlreadyIssued)?;
-					}
-				}
-			}
+			<DestroyingDaos<T>>::insert(&dao.id, ());
+			Self::deposit_event(Event::DaoDestructionStarted { dao_id: dao.id.clone() });
+			Self::destroy_items(dao)
+		}
 
-			<T as Config>::Currency::unreserve(&sender, <T as Config>::DaoDeposit::get());
-			Self::deposit_event(Event::DaoDestroyed { dao_id: dao.id.clone() });
-			<Daos<T>>::remove(&dao.id);
-			Ok(())
+		/// Continue to destroy a DAO, removing up to _RemoveItemsLimit_ of its items.
+		///
+		/// - `dao_id`: The DAO being destroyed
+		///
+		/// Anyone can call this once the owner started to destroy the DAO with `destroy_dao`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::continue_destroy_dao()
+				.saturating_add(Pallet::<T>::destroy_items_weight())
+		)]
+		pub fn continue_destroy_dao(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+			ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(<DestroyingDaos<T>>::contains_key(&dao.id), Error::<T>::DaoIsNotBeingDestroyed);
+			Self::destroy_items(dao)
 		}
 
 		/// Issue the DAO token
@@ -244,6 +389,7 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 			let dao = Self::load_dao(dao_id)?;
 			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			ensure!(!<DestroyingDaos<T>>::contains_key(&dao.id), Error::<T>::DaoIsBeingDestroyed);
 			ensure!(dao.asset_id.is_none(), Error::<T>::DaoTokenAlreadyIssued);
 
 			// create a fresh asset
@@ -289,6 +435,8 @@ pub mod pallet {
 		/// - `dao_id`: The DAO for which to set metadata
 		/// - `meta`: HTTP or IPFS address for the metadata about this DAO (description, logo)
 		/// - `hash`: SHA3 hash of the metadata to be found via `meta`
+		///
+		/// Signer of this TX needs to be the owner of the DAO or the DAO itself.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_metadata())]
 		pub fn set_metadata(
@@ -297,16 +445,16 @@ pub mod pallet {
 			meta: Vec<u8>,
 			hash: Vec<u8>,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
 			let dao = Self::load_dao(dao_id)?;
-			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			Self::ensure_owner_or_dao(origin, &dao)?;
 
 			let meta: BoundedVec<_, _> =
 				meta.try_into().map_err(|_| Error::<T>::MetadataInvalidLengthTooLong)?;
 			let hash: BoundedVec<_, _> =
 				hash.try_into().map_err(|_| Error::<T>::HashInvalidWrongLength)?;
 			ensure!(
-				meta.is_empty() && hash.is_empty() || Self::metadata_is_valid(&meta),
+				meta.is_empty() && hash.is_empty() ||
+					Self::metadata_is_valid(&dao.id, &meta, &hash),
 				Error::<T>::MetadataInvalid
 			);
 
@@ -324,31 +472,234 @@ pub mod pallet {
 		///
 		/// - `dao_id`: the DAO to transfer ownership of
 		/// - `new_owner`: the new owner
+		///
+		/// Origin of this TX needs to be the DAO itself, so this is only possible through the
+		/// governance of the DAO. The owner transfers the ownership with `propose_owner` and
+		/// `accept_ownership` instead, so that it cannot end up with an account nobody controls.
+		/// The _DaoDeposit_ is moved from the reserve of the previous owner to the reserve of the
+		/// new owner.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_metadata())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::change_owner())]
 		pub fn change_owner(
 			origin: OriginFor<T>,
 			dao_id: Vec<u8>,
 			new_owner: T::AccountId,
 		) -> DispatchResult {
+			let dao = Self::load_dao(dao_id)?;
+			let origin_dao_id = T::DaoOrigin::ensure_origin(origin)?;
+			ensure!(dao.id == origin_dao_id, Error::<T>::DaoOriginMismatch);
+			ensure!(!<DestroyingDaos<T>>::contains_key(&dao.id), Error::<T>::DaoIsBeingDestroyed);
+			Self::do_change_owner(dao, new_owner)
+		}
+
+		/// Propose a new owner, who needs to accept the ownership to become the owner
+		///
+		/// - `dao_id`: the DAO to transfer ownership of
+		/// - `new_owner`: the proposed owner, replacing a previously proposed one
+		///
+		/// Signer of this TX needs to be the owner of the DAO or the DAO itself.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_owner())]
+		pub fn propose_owner(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let dao = Self::load_dao(dao_id)?;
+			Self::ensure_owner_or_dao(origin, &dao)?;
+
+			<ProposedOwners<T>>::insert(&dao.id, &new_owner);
+			Self::deposit_event(Event::DaoOwnerProposed { dao_id: dao.id, new_owner });
+			Ok(())
+		}
+
+		/// Accept the ownership of a DAO
+		///
+		/// - `dao_id`: the DAO to become the owner of
+		///
+		/// Signer of this TX needs to be the proposed owner. The _DaoDeposit_ is moved from the
+		/// reserve of the previous owner to the reserve of the new owner.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_ownership())]
+		pub fn accept_ownership(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			let dao_id: BoundedVec<_, _> =
-				dao_id.try_into().map_err(|_| Error::<T>::DaoIdInvalidLengthTooLong)?;
-			Daos::<T>::try_mutate(dao_id.clone(), |maybe_dao| -> DispatchResult {
-				let dao = maybe_dao.as_mut().ok_or(Error::<T>::DaoDoesNotExist)?;
-				ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
-				if dao.owner == new_owner {
-					return Ok(());
-				}
-				// also change asset owner if token was issued
-				if let Some(asset_id) = dao.asset_id {
-					Assets::<T>::change_owner(asset_id.into(), new_owner.clone())?;
-				}
-
-				dao.owner = new_owner.clone();
-				Ok(())
-			})?;
-			Self::deposit_event(Event::DaoOwnerChanged { dao_id, new_owner });
+			let dao = Self::load_dao(dao_id)?;
+			let proposed_owner =
+				<ProposedOwners<T>>::get(&dao.id).ok_or(Error::<T>::DaoOwnerNotProposed)?;
+			ensure!(proposed_owner == sender, Error::<T>::DaoSignerNotProposedOwner);
+			ensure!(!<DestroyingDaos<T>>::contains_key(&dao.id), Error::<T>::DaoIsBeingDestroyed);
+			Self::do_change_owner(dao, sender)
+		}
+
+		/// Cancel the transfer of the ownership to a proposed owner
+		///
+		/// - `dao_id`: the DAO whose ownership transfer to cancel
+		///
+		/// Signer of this TX needs to be the owner of the DAO or the DAO itself.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_ownership_transfer())]
+		pub fn cancel_ownership_transfer(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+			let dao = Self::load_dao(dao_id)?;
+			Self::ensure_owner_or_dao(origin, &dao)?;
+			ensure!(<ProposedOwners<T>>::contains_key(&dao.id), Error::<T>::DaoOwnerNotProposed);
+
+			<ProposedOwners<T>>::remove(&dao.id);
+			Self::deposit_event(Event::DaoOwnershipTransferCancelled { dao_id: dao.id });
+			Ok(())
+		}
+
+		/// Opt into or out of strict metadata
+		///
+		/// - `dao_id`: the DAO to set strict metadata for
+		/// - `strict`: whether the metadata of the DAO and its proposals needs to be valid
+		///   according to the _StrictMetadataValidator_ as well
+		///
+		/// Signer of this TX needs to be the owner of the DAO or the DAO itself. Metadata that is
+		/// already set is not validated again.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_strict_metadata())]
+		pub fn set_strict_metadata(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			strict: bool,
+		) -> DispatchResult {
+			let dao = Self::load_dao(dao_id)?;
+			Self::ensure_owner_or_dao(origin, &dao)?;
+
+			if strict {
+				<StrictMetadataDaos<T>>::insert(&dao.id, ());
+			} else {
+				<StrictMetadataDaos<T>>::remove(&dao.id);
+			}
+			Self::deposit_event(Event::DaoStrictMetadataSet { dao_id: dao.id, strict });
+			Ok(())
+		}
+
+		/// Locate the _DaoDeposit_ of a DAO that changed owners before the deposit was moved
+		/// along with the ownership
+		///
+		/// - `dao_id`: the DAO whose deposit to locate
+		/// - `holder`: the account reserving the deposit, usually the creator of the DAO
+		///
+		/// Origin of this TX needs to be root. The _DaoDeposit_ is moved from the reserve of the
+		/// holder to the reserve of the owner.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::locate_dao_deposit())]
+		pub fn locate_dao_deposit(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			holder: T::AccountId,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(
+				<UnlocatedDeposits<T>>::contains_key(&dao.id),
+				Error::<T>::DaoDepositAlreadyLocated
+			);
+
+			Self::move_deposit(&holder, &dao.owner)?;
+			<UnlocatedDeposits<T>>::remove(&dao.id);
+			Self::deposit_event(Event::DaoDepositLocated { dao_id: dao.id, holder });
+			Ok(())
+		}
+
+		/// Spend native currency from the treasury of a DAO
+		///
+		/// - `dao_id`: the DAO whose treasury to spend from
+		/// - `beneficiary`: the account receiving the funds
+		/// - `amount`: the amount to transfer
+		///
+		/// Origin of this TX needs to be the DAO itself, so this is only possible through
+		/// the governance of the DAO.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::spend())]
+		pub fn spend(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			beneficiary: T::AccountId,
+			amount: DepositBalanceOf<T>,
+		) -> DispatchResult {
+			let dao = Self::load_dao(dao_id)?;
+			let origin_dao_id = T::DaoOrigin::ensure_origin(origin)?;
+			ensure!(dao.id == origin_dao_id, Error::<T>::DaoOriginMismatch);
+			let treasury = Self::dao_account_id(&dao.id);
+
+			<T as Config>::Currency::transfer(
+				&treasury,
+				&beneficiary,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::TreasurySpent { dao_id: dao.id, beneficiary, amount });
+			Ok(())
+		}
+
+		/// Spend DAO tokens from the treasury of a DAO
+		///
+		/// - `dao_id`: the DAO whose treasury to spend from
+		/// - `beneficiary`: the account receiving the tokens
+		/// - `amount`: the amount to transfer
+		///
+		/// Origin of this TX needs to be the DAO itself, so this is only possible through
+		/// the governance of the DAO.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::spend_token())]
+		pub fn spend_token(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			beneficiary: T::AccountId,
+			amount: <T as pallet_dao_assets::Config>::Balance,
+		) -> DispatchResult {
+			let dao = Self::load_dao(dao_id)?;
+			let origin_dao_id = T::DaoOrigin::ensure_origin(origin)?;
+			ensure!(dao.id == origin_dao_id, Error::<T>::DaoOriginMismatch);
+			let treasury = Self::dao_account_id(&dao.id);
+			let asset_id = dao.asset_id.ok_or(Error::<T>::DaoTokenNotYetIssued)?;
+
+			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+			Assets::<T>::do_transfer(asset_id.into(), &treasury, &beneficiary, amount, f)?;
+
+			Self::deposit_event(Event::TreasuryTokenSpent { dao_id: dao.id, beneficiary, amount });
+			Ok(())
+		}
+
+		/// Set the council of a DAO
+		///
+		/// - `dao_id`: the DAO to set the council of
+		/// - `members`: the distinct council members, bounded by _MaxCouncilMembers_, none to
+		///   remove the council
+		/// - `threshold`: the number of members needed to approve an action in place of the owner
+		///
+		/// Signer of this TX needs to be the owner of the DAO or the DAO itself.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_council())]
+		pub fn set_council(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			members: Vec<T::AccountId>,
+			threshold: u32,
+		) -> DispatchResult {
+			let dao = Self::load_dao(dao_id)?;
+			Self::ensure_owner_or_dao(origin, &dao)?;
+
+			if members.is_empty() {
+				<Councils<T>>::remove(&dao.id);
+			} else {
+				let bounded_members: BoundedVec<_, _> =
+					members.clone().try_into().map_err(|_| Error::<T>::CouncilTooManyMembers)?;
+				let mut sorted = members.clone();
+				sorted.sort();
+				sorted.dedup();
+				ensure!(sorted.len() == members.len(), Error::<T>::CouncilMemberDuplicate);
+				ensure!(
+					threshold > 0 && threshold as usize <= members.len(),
+					Error::<T>::CouncilThresholdInvalid
+				);
+				<Councils<T>>::insert(&dao.id, Council { members: bounded_members, threshold });
+			}
+
+			Self::deposit_event(Event::DaoCouncilSet { dao_id: dao.id, members, threshold });
 			Ok(())
 		}
 	}
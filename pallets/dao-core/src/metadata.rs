@@ -0,0 +1,134 @@
+//! Validation of the metadata of DAOs and their proposals: the address of a JSON file describing
+//! them alongside with the hash of that file.
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_std::marker::PhantomData;
+
+/// Validates metadata given by its address and the hash of the content found there.
+pub trait MetadataValidator {
+	/// Whether the metadata is valid.
+	///
+	/// - `meta`: the address of the metadata
+	/// - `hash`: the hash of the content found at `meta`
+	fn is_valid(meta: &[u8], hash: &[u8]) -> bool;
+}
+
+/// Any metadata is valid.
+impl MetadataValidator for () {
+	fn is_valid(_meta: &[u8], _hash: &[u8]) -> bool {
+		true
+	}
+}
+
+/// Metadata needs to be valid for both validators.
+impl<A: MetadataValidator, B: MetadataValidator> MetadataValidator for (A, B) {
+	fn is_valid(meta: &[u8], hash: &[u8]) -> bool {
+		A::is_valid(meta, hash) && B::is_valid(meta, hash)
+	}
+}
+
+/// Metadata needs to be valid for all three validators.
+impl<A: MetadataValidator, B: MetadataValidator, C: MetadataValidator> MetadataValidator
+	for (A, B, C)
+{
+	fn is_valid(meta: &[u8], hash: &[u8]) -> bool {
+		A::is_valid(meta, hash) && B::is_valid(meta, hash) && C::is_valid(meta, hash)
+	}
+}
+
+const IPFS_SCHEME: &[u8] = b"ipfs://";
+
+/// The schemes metadata can be addressed with.
+pub const URL_SCHEMES: [&[u8]; 3] = [b"http://", b"https://", IPFS_SCHEME];
+
+/// The address needs to be an HTTP, HTTPS or IPFS URL.
+pub struct UrlScheme;
+
+impl MetadataValidator for UrlScheme {
+	fn is_valid(meta: &[u8], _hash: &[u8]) -> bool {
+		URL_SCHEMES
+			.iter()
+			.any(|scheme| meta.len() > scheme.len() && meta.starts_with(scheme))
+	}
+}
+
+/// IPFS addresses need to start with a syntactically valid CID, addresses of other schemes are
+/// left to other validators.
+pub struct IpfsCid;
+
+impl MetadataValidator for IpfsCid {
+	fn is_valid(meta: &[u8], _hash: &[u8]) -> bool {
+		meta.strip_prefix(IPFS_SCHEME).map_or(true, |path| is_valid_cid(cid_of(path)))
+	}
+}
+
+/// The address needs to be an IPFS URL starting with a syntactically valid CID, so the metadata
+/// cannot change anymore.
+pub struct IpfsOnly;
+
+impl MetadataValidator for IpfsOnly {
+	fn is_valid(meta: &[u8], _hash: &[u8]) -> bool {
+		meta.strip_prefix(IPFS_SCHEME).map_or(false, |path| is_valid_cid(cid_of(path)))
+	}
+}
+
+/// The CID an IPFS path starts with.
+fn cid_of(path: &[u8]) -> &[u8] {
+	path.split(|c| *c == b'/').next().unwrap_or_default()
+}
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The length of a CIDv0, a base58btc encoded SHA2-256 multihash.
+const CIDV0_LENGTH: usize = 46;
+
+/// The minimum lengths of a CIDv1 with a 256 bit digest in base32 and base58btc, without the
+/// multibase prefix.
+const CIDV1_MIN_LENGTH_BASE32: usize = 58;
+const CIDV1_MIN_LENGTH_BASE58: usize = 48;
+
+/// Whether `cid` is syntactically a CIDv0 or a CIDv1 in one of the multibase encodings used by
+/// IPFS: base32 (`b` prefix) or base58btc (`z` prefix). The CID is not decoded.
+pub fn is_valid_cid(cid: &[u8]) -> bool {
+	let is_base58 = |c: &u8| BASE58_ALPHABET.contains(c);
+	let is_base32 = |c: &u8| c.is_ascii_lowercase() || (b'2'..=b'7').contains(c);
+	match cid {
+		[b'Q', b'm', ..] => cid.len() == CIDV0_LENGTH && cid.iter().all(is_base58),
+		// the version 1 is encoded as a leading `a` in base32
+		[b'b', b'a', ..] => cid.len() > CIDV1_MIN_LENGTH_BASE32 && cid[1..].iter().all(is_base32),
+		[b'z', ..] => cid.len() > CIDV1_MIN_LENGTH_BASE58 && cid[1..].iter().all(is_base58),
+		_ => false,
+	}
+}
+
+/// The algorithms the hash of metadata can be computed with.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum HashAlgorithm {
+	Sha2_256,
+	Sha3_256,
+	Keccak256,
+	Blake2_256,
+	Sha3_512,
+}
+
+impl HashAlgorithm {
+	/// The length of a digest in bytes.
+	pub fn digest_len(&self) -> usize {
+		match self {
+			Self::Sha2_256 | Self::Sha3_256 | Self::Keccak256 | Self::Blake2_256 => 32,
+			Self::Sha3_512 => 64,
+		}
+	}
+}
+
+/// The hash needs to have the length of a digest of the algorithm `A`, either as raw bytes or
+/// hex encoded. The algorithm is fixed by the runtime, the hash carries no multihash prefix.
+pub struct HashLength<A>(PhantomData<A>);
+
+impl<A: Get<HashAlgorithm>> MetadataValidator for HashLength<A> {
+	fn is_valid(_meta: &[u8], hash: &[u8]) -> bool {
+		let len = A::get().digest_len();
+		hash.len() == len || hash.len() == 2 * len && hash.iter().all(u8::is_ascii_hexdigit)
+	}
+}
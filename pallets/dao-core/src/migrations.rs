@@ -0,0 +1,164 @@
+//! Storage migrations for the dao-core pallet.
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::StoragePrefixedMap,
+	storage_alias,
+	traits::{BalanceStatus, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency},
+};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+pub mod v2 {
+	use super::*;
+
+	/// The creators of the DAOs listed by the runtime, kept while `MoveDaoDeposits` runs.
+	#[storage_alias]
+	type DaoCreators<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, DaoIdOf<T>, AccountIdOf<T>>;
+
+	/// The raw key of the last DAO `MoveDaoDeposits` visited, present while it runs.
+	#[storage_alias]
+	type MigrationCursor<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+
+	/// Moves the _DaoDeposit_ of DAOs that changed owners before the deposit was moved along with
+	/// the ownership, from the reserve of the creator to the reserve of the current owner.
+	///
+	/// The creators are not kept in storage, so `Creators` needs to list the DAOs by id along
+	/// with the account that created them. DAOs still owned by their creator are left untouched.
+	/// The deposit of DAOs that aren't listed, or whose creator doesn't reserve the deposit
+	/// anymore, is considered located if the owner reserves at least the deposit. Otherwise it is
+	/// marked as unlocated, so that no other reserve is moved or released in its place. It can then
+	/// be located with `locate_dao_deposit`.
+	///
+	/// The upgrade only stores the creators, the DAOs are visited in `on_idle` of the pallet by
+	/// `move_dao_deposits` over as many blocks as needed. The storage version is updated once all
+	/// DAOs have been visited.
+	pub struct MoveDaoDeposits<T, Creators>(PhantomData<(T, Creators)>);
+
+	impl<T: Config, Creators: Get<Vec<(Vec<u8>, AccountIdOf<T>)>>> OnRuntimeUpgrade
+		for MoveDaoDeposits<T, Creators>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 || <MigrationCursor<T>>::exists() {
+				return T::DbWeight::get().reads(2);
+			}
+
+			let mut writes = 1_u64;
+			for (dao_id, creator) in Creators::get() {
+				if let Ok(dao_id) = DaoIdOf::<T>::try_from(dao_id) {
+					<DaoCreators<T>>::insert(dao_id, creator);
+					writes += 1;
+				}
+			}
+			<MigrationCursor<T>>::put(<Daos<T>>::final_prefix().to_vec());
+			T::DbWeight::get().reads_writes(2, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let accounts = Self::accounts();
+			let reserved = Self::total_reserved(&accounts);
+			Ok((accounts, reserved).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(<MigrationCursor<T>>::exists(), "migration not started");
+
+			// visit all DAOs at once, as `on_idle` would over several blocks
+			move_dao_deposits::<T>(Weight::MAX);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version not updated");
+			ensure!(<DaoCreators<T>>::iter().next().is_none(), "creators left");
+
+			let (accounts, reserved): (Vec<AccountIdOf<T>>, DepositBalanceOf<T>) =
+				Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				Self::total_reserved(&accounts) == reserved,
+				"reserves moved outside of owners and creators"
+			);
+
+			// every located deposit needs to be reserved by the owner of the DAO
+			let mut located = BTreeMap::<AccountIdOf<T>, u32>::new();
+			for dao in <Daos<T>>::iter_values() {
+				if !<UnlocatedDeposits<T>>::contains_key(&dao.id) {
+					*located.entry(dao.owner).or_default() += 1;
+				}
+			}
+			for (owner, count) in located {
+				ensure!(
+					<T as Config>::Currency::reserved_balance(&owner) >=
+						<T as Config>::DaoDeposit::get().saturating_mul(count.into()),
+					"owner does not reserve the deposits of its DAOs"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	impl<T: Config, Creators: Get<Vec<(Vec<u8>, AccountIdOf<T>)>>> MoveDaoDeposits<T, Creators> {
+		/// The owners and creators of all DAOs, whose reserves the migration may move.
+		fn accounts() -> Vec<AccountIdOf<T>> {
+			let mut accounts: Vec<_> = <Daos<T>>::iter_values()
+				.map(|dao| dao.owner)
+				.chain(Creators::get().into_iter().map(|(_, creator)| creator))
+				.collect();
+			accounts.sort();
+			accounts.dedup();
+			accounts
+		}
+
+		/// The sum of the reserves of the given accounts.
+		fn total_reserved(accounts: &[AccountIdOf<T>]) -> DepositBalanceOf<T> {
+			accounts.iter().fold(Zero::zero(), |total: DepositBalanceOf<T>, account| {
+				total.saturating_add(<T as Config>::Currency::reserved_balance(account))
+			})
+		}
+	}
+
+	/// Visit as many DAOs as fit into `remaining_weight` for `MoveDaoDeposits`, returns the
+	/// consumed weight. Once all DAOs have been visited, the creators are removed and the storage
+	/// version is updated.
+	pub fn move_dao_deposits<T: Config>(remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let mut consumed = db_weight.reads(1);
+		let Some(cursor) = <MigrationCursor<T>>::get() else {
+			return consumed;
+		};
+
+		let deposit = <T as Config>::DaoDeposit::get();
+		let max_dao_weight = db_weight.reads_writes(4, 2);
+		let mut daos = <Daos<T>>::iter_from(cursor);
+		loop {
+			if consumed.saturating_add(max_dao_weight).any_gt(remaining_weight) {
+				<MigrationCursor<T>>::put(daos.last_raw_key().to_vec());
+				return consumed.saturating_add(db_weight.writes(1));
+			}
+			let Some((_, dao)) = daos.next() else {
+				break;
+			};
+			consumed.saturating_accrue(max_dao_weight);
+
+			match <DaoCreators<T>>::get(&dao.id) {
+				Some(creator) if creator == dao.owner => continue,
+				Some(creator) if <T as Config>::Currency::reserved_balance(&creator) >= deposit => {
+					let _ = <T as Config>::Currency::repatriate_reserved(
+						&creator,
+						&dao.owner,
+						deposit,
+						BalanceStatus::Reserved,
+					);
+				},
+				_ if <T as Config>::Currency::reserved_balance(&dao.owner) >= deposit => continue,
+				_ => <UnlocatedDeposits<T>>::insert(&dao.id, ()),
+			}
+		}
+
+		let result = <DaoCreators<T>>::clear(u32::MAX, None);
+		<MigrationCursor<T>>::kill();
+		StorageVersion::new(2).put::<Pallet<T>>();
+		consumed.saturating_add(
+			db_weight.reads_writes(result.loops.into(), u64::from(result.backend) + 2),
+		)
+	}
+}
@@ -1,7 +1,9 @@
 use crate as pallet_dao_core;
+use crate::metadata::{HashAlgorithm, HashLength, IpfsCid, IpfsOnly, UrlScheme};
 use frame_support::{
 	parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, ConstU8},
+	PalletId,
 };
 use frame_system as system;
 use sp_core::H256;
@@ -18,6 +20,8 @@ pub(crate) type Balance = u128;
 
 parameter_types! {
 	pub const ExistentialDeposit: Balance = 1;
+	pub const DaoPalletId: PalletId = PalletId(*b"gdao/acc");
+	pub const MetadataHashAlgorithm: HashAlgorithm = HashAlgorithm::Sha3_256;
 }
 
 frame_support::construct_runtime!(
@@ -91,11 +95,14 @@ impl pallet_dao_assets::Config for Test {
 	type AssetIdParameter = u32;
 	type Currency = Balances;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AdminOrigin = pallet_dao_core::EnsureDaoToken<Self>;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
 	type ApprovalDeposit = ApprovalDeposit;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type StringLimit = AssetsStringLimit;
-	type HistoryHorizon = ConstU32<4200>;
+	type MaxVestingSchedules = ConstU32<3>;
+	type MinVestedTransfer = ConstU128<10>;
+	type MaxVestingDuration = ConstU64<1000>;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -110,7 +117,13 @@ impl pallet_dao_core::Config for Test {
 	type Currency = Balances;
 	type DaoDeposit = ConstU128<10>;
 	type TokenUnits = ConstU8<10>;
+	type MaxCouncilMembers = ConstU32<4>;
 	type AssetId = u32;
+	type PalletId = DaoPalletId;
+	type DaoOrigin = pallet_dao_core::EnsureDao<Self>;
+	type DaoTeardown = ();
+	type MetadataValidator = (UrlScheme, IpfsCid, HashLength<MetadataHashAlgorithm>);
+	type StrictMetadataValidator = IpfsOnly;
 	type WeightInfo = ();
 }
 
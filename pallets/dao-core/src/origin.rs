@@ -0,0 +1,56 @@
+//! Origin checks for a DAO acting collectively.
+use super::*;
+use frame_support::traits::{EnsureOrigin, EnsureOriginWithArg};
+use sp_std::marker::PhantomData;
+
+/// Ensure the origin is a DAO acting collectively, succeeds with the id of the DAO.
+pub struct EnsureDao<T>(PhantomData<T>);
+
+impl<T: Config, O> EnsureOrigin<O> for EnsureDao<T>
+where
+	O: Into<Result<RawOrigin<DaoIdOf<T>>, O>> + From<RawOrigin<DaoIdOf<T>>>,
+{
+	type Success = DaoIdOf<T>;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().map(|RawOrigin::Dao(dao_id)| dao_id)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		let dao_id: DaoIdOf<T> = b"GDAO".to_vec().try_into().map_err(|_| ())?;
+		Ok(O::from(RawOrigin::Dao(dao_id)))
+	}
+}
+
+/// Ensure the origin is the DAO that issued the given asset, acting collectively.
+/// Succeeds with the id of the DAO.
+pub struct EnsureDaoToken<T>(PhantomData<T>);
+
+impl<T: Config, O> EnsureOriginWithArg<O, <T as pallet_dao_assets::Config>::AssetId>
+	for EnsureDaoToken<T>
+where
+	O: Into<Result<RawOrigin<DaoIdOf<T>>, O>> + From<RawOrigin<DaoIdOf<T>>>,
+{
+	type Success = DaoIdOf<T>;
+
+	fn try_origin(
+		o: O,
+		asset_id: &<T as pallet_dao_assets::Config>::AssetId,
+	) -> Result<Self::Success, O> {
+		let RawOrigin::Dao(dao_id) = o.into()?;
+		let dao_asset_id: Option<<T as pallet_dao_assets::Config>::AssetId> =
+			<Daos<T>>::get(&dao_id).and_then(|dao| dao.asset_id).map(Into::into);
+		match dao_asset_id {
+			Some(id) if id == *asset_id => Ok(dao_id),
+			_ => Err(O::from(RawOrigin::Dao(dao_id))),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(
+		_asset_id: &<T as pallet_dao_assets::Config>::AssetId,
+	) -> Result<O, ()> {
+		Err(())
+	}
+}
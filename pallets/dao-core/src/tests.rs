@@ -1,6 +1,16 @@
-use crate::{mock::*, Config, Error};
-use frame_support::{assert_noop, assert_ok, traits::TypedGet};
+use crate::{
+	migrations::v2::MoveDaoDeposits, mock::*, Config, CouncilApprovals, Councils, Daos,
+	DestroyingDaos, Error, RawOrigin, UnlocatedDeposits,
+};
+use frame_support::{
+	assert_noop, assert_ok, parameter_types,
+	traits::{
+		GetStorageVersion, Hooks, OnRuntimeUpgrade, ReservableCurrency, StorageVersion, TypedGet,
+	},
+	weights::Weight,
+};
 use pallet_balances::Error as BalancesError;
+use sp_runtime::DispatchError;
 
 #[test]
 fn dao_id_valid_chars() {
@@ -89,19 +99,21 @@ fn it_destroys_a_dao() {
 		);
 
 		assert_ok!(DaoCore::issue_token(RuntimeOrigin::signed(1), b"GDAO".to_vec(), 1000));
-
-		assert_noop!(
-			DaoCore::destroy_dao(RuntimeOrigin::signed(1), b"GDAO".to_vec()),
-			Error::<Test>::DaoTokenAlreadyIssued
-		);
-
 		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
 		let asset_id = dao.asset_id.unwrap();
-		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), asset_id));
-		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), asset_id));
-		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), asset_id));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), asset_id, 2, 100));
+
+		// tokens held by the treasury don't keep the DAO from being destroyed
+		let treasury = DaoCore::dao_account_id(&dao.id);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), asset_id, treasury, 100));
 
+		// the token is destroyed along with the DAO
 		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(1), b"GDAO".to_vec()));
+		let asset = pallet_dao_assets::Asset::<Test>::get(asset_id).unwrap();
+		assert_eq!(asset.status, pallet_dao_assets::AssetStatus::Destroyed);
+		assert_eq!(Assets::balance(asset_id, 2), 0);
+		assert_eq!(Assets::balance(asset_id, treasury), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
 
 		assert_noop!(
 			DaoCore::destroy_dao(RuntimeOrigin::signed(1), b"GDAO".to_vec()),
@@ -188,12 +200,85 @@ fn it_sets_metadata() {
 	});
 }
 
+#[test]
+fn metadata_is_validated() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		// https://en.wikipedia.org/wiki/SHA-3#Examples_of_SHA-3_variants
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+		let origin = RuntimeOrigin::signed(1);
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"Genesis DAO".to_vec()));
+
+		// only HTTP, HTTPS and IPFS addresses are allowed, IPFS addresses with a valid CID
+		for meta in [
+			b"ftp://my.cool.dao".to_vec(),
+			b"https://".to_vec(),
+			b"ipfs://my.cool.dao".to_vec(),
+			b"ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0".to_vec(),
+		] {
+			assert_noop!(
+				DaoCore::set_metadata(origin.clone(), dao_id.clone(), meta, hash.clone()),
+				Error::<Test>::MetadataInvalid
+			);
+		}
+		for meta in [
+			b"https://my.cool.dao".to_vec(),
+			b"ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG/dao.json".to_vec(),
+			b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec(),
+		] {
+			assert_ok!(DaoCore::set_metadata(origin.clone(), dao_id.clone(), meta, hash.clone()));
+		}
+
+		// the hash needs to be a SHA3-256 digest, either raw or hex encoded
+		let meta = b"http://my.cool.dao".to_vec();
+		for hash in [hash[1..].to_vec(), [b'g'; 64].to_vec()] {
+			assert_noop!(
+				DaoCore::set_metadata(origin.clone(), dao_id.clone(), meta.clone(), hash),
+				Error::<Test>::MetadataInvalid
+			);
+		}
+		assert_ok!(DaoCore::set_metadata(origin.clone(), dao_id.clone(), meta, [0; 32].to_vec()));
+
+		// the metadata can still be removed
+		assert_ok!(DaoCore::set_metadata(origin, dao_id, vec![], vec![]));
+	});
+}
+
+#[test]
+fn dao_can_opt_into_strict_metadata() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+		let http = b"http://my.cool.dao".to_vec();
+		let ipfs = b"ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+		let origin = RuntimeOrigin::signed(1);
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"Genesis DAO".to_vec()));
+
+		assert_noop!(
+			DaoCore::set_strict_metadata(RuntimeOrigin::signed(2), dao_id.clone(), true),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_ok!(DaoCore::set_strict_metadata(origin.clone(), dao_id.clone(), true));
+
+		// strict metadata needs to be addressed by IPFS
+		assert_noop!(
+			DaoCore::set_metadata(origin.clone(), dao_id.clone(), http.clone(), hash.clone()),
+			Error::<Test>::MetadataInvalid
+		);
+		assert_ok!(DaoCore::set_metadata(origin.clone(), dao_id.clone(), ipfs, hash.clone()));
+
+		assert_ok!(DaoCore::set_strict_metadata(origin.clone(), dao_id.clone(), false));
+		assert_ok!(DaoCore::set_metadata(origin, dao_id, http, hash));
+	});
+}
+
 #[test]
 fn can_change_owner() {
 	new_test_ext().execute_with(|| {
 		let new_owner = 61;
+		let dao_origin: RuntimeOrigin = RawOrigin::Dao(b"GDAO".to_vec().try_into().unwrap()).into();
 		assert_noop!(
-			DaoCore::change_owner(RuntimeOrigin::signed(1), b"NONE".to_vec(), new_owner),
+			DaoCore::change_owner(dao_origin.clone(), b"NONE".to_vec(), new_owner),
 			Error::<Test>::DaoDoesNotExist
 		);
 
@@ -208,10 +293,437 @@ fn can_change_owner() {
 		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
 		assert_eq!(dao.owner, creator);
 
+		// the owner cannot hand over the ownership without the new owner accepting it
+		assert_noop!(
+			DaoCore::change_owner(RuntimeOrigin::signed(creator), b"GDAO".to_vec(), new_owner),
+			DispatchError::BadOrigin
+		);
+
+		// the new owner needs an account to take over the deposit
+		assert_noop!(
+			DaoCore::change_owner(dao_origin.clone(), b"GDAO".to_vec(), new_owner),
+			BalancesError::<Test>::DeadAccount
+		);
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(creator), new_owner, 100));
+
 		// change owner
-		assert_ok!(DaoCore::change_owner(RuntimeOrigin::signed(1), b"GDAO".to_vec(), new_owner));
+		assert_ok!(DaoCore::change_owner(dao_origin, b"GDAO".to_vec(), new_owner));
+
+		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
+		assert_eq!(dao.owner, new_owner);
+
+		// the deposit moved to the new owner
+		let deposit = <Test as Config>::DaoDeposit::get();
+		assert_eq!(Balances::reserved_balance(creator), 0);
+		assert_eq!(Balances::reserved_balance(new_owner), deposit);
+
+		// destroying the DAO releases the deposit of the new owner
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(new_owner), b"GDAO".to_vec()));
+		assert_eq!(Balances::reserved_balance(new_owner), 0);
+		assert_eq!(Balances::free_balance(new_owner), 100 + deposit);
+	});
+}
+
+#[test]
+fn can_transfer_ownership_in_two_steps() {
+	new_test_ext().execute_with(|| {
+		let creator = 1;
+		let new_owner = 2;
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(creator),
+			b"GDAO".to_vec(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_ok!(DaoCore::issue_token(RuntimeOrigin::signed(creator), b"GDAO".to_vec(), 1000));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(creator), new_owner, 100));
+
+		// there is nothing to accept or cancel yet
+		assert_noop!(
+			DaoCore::accept_ownership(RuntimeOrigin::signed(new_owner), b"GDAO".to_vec()),
+			Error::<Test>::DaoOwnerNotProposed
+		);
+		assert_noop!(
+			DaoCore::cancel_ownership_transfer(RuntimeOrigin::signed(creator), b"GDAO".to_vec()),
+			Error::<Test>::DaoOwnerNotProposed
+		);
+
+		// only the owner can propose a new owner
+		assert_noop!(
+			DaoCore::propose_owner(RuntimeOrigin::signed(new_owner), b"GDAO".to_vec(), new_owner),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_ok!(DaoCore::propose_owner(RuntimeOrigin::signed(creator), b"GDAO".to_vec(), 3));
+		assert_ok!(DaoCore::cancel_ownership_transfer(
+			RuntimeOrigin::signed(creator),
+			b"GDAO".to_vec()
+		));
+		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
+		assert_eq!(DaoCore::get_proposed_owner(&dao.id), None);
+
+		// only the proposed owner can accept the ownership
+		assert_ok!(DaoCore::propose_owner(
+			RuntimeOrigin::signed(creator),
+			b"GDAO".to_vec(),
+			new_owner
+		));
+		assert_noop!(
+			DaoCore::accept_ownership(RuntimeOrigin::signed(3), b"GDAO".to_vec()),
+			Error::<Test>::DaoSignerNotProposedOwner
+		);
+		assert_eq!(DaoCore::load_dao(b"GDAO".to_vec()).unwrap().owner, creator);
 
+		assert_ok!(DaoCore::accept_ownership(RuntimeOrigin::signed(new_owner), b"GDAO".to_vec()));
 		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
 		assert_eq!(dao.owner, new_owner);
+		assert_eq!(DaoCore::get_proposed_owner(&dao.id), None);
+
+		// the deposit moved to the new owner
+		let deposit = <Test as Config>::DaoDeposit::get();
+		assert_eq!(Balances::reserved_balance(creator), 0);
+		assert_eq!(Balances::reserved_balance(new_owner), deposit);
+		assert_eq!(Balances::free_balance(new_owner), 100);
+	});
+}
+
+#[test]
+fn dao_being_destroyed_can_only_be_destroyed_further() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			b"GDAO".to_vec(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_noop!(
+			DaoCore::continue_destroy_dao(RuntimeOrigin::signed(2), b"GDAO".to_vec()),
+			Error::<Test>::DaoIsNotBeingDestroyed
+		);
+
+		// items are left that did not fit in a single page
+		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
+		DestroyingDaos::<Test>::insert(&dao.id, ());
+		assert_noop!(
+			DaoCore::set_metadata(
+				RuntimeOrigin::signed(1),
+				b"GDAO".to_vec(),
+				b"http://my.cool.dao".to_vec(),
+				b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec()
+			),
+			Error::<Test>::DaoIsBeingDestroyed
+		);
+		assert_noop!(
+			DaoCore::issue_token(RuntimeOrigin::signed(1), b"GDAO".to_vec(), 1000),
+			Error::<Test>::DaoIsBeingDestroyed
+		);
+		assert_noop!(
+			DaoCore::destroy_dao(RuntimeOrigin::signed(1), b"GDAO".to_vec()),
+			Error::<Test>::DaoIsBeingDestroyed
+		);
+
+		// anyone can continue to destroy the DAO
+		assert_ok!(DaoCore::continue_destroy_dao(RuntimeOrigin::signed(2), b"GDAO".to_vec()));
+		assert_eq!(DaoCore::get_dao(&dao.id), None);
+		assert!(!DestroyingDaos::<Test>::contains_key(&dao.id));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn treasury_can_only_be_spent_by_dao() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			b"GDAO".to_vec(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_ok!(DaoCore::issue_token(RuntimeOrigin::signed(1), b"GDAO".to_vec(), 1000));
+		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
+		let asset_id = dao.asset_id.unwrap();
+		let treasury = DaoCore::dao_account_id(&dao.id);
+		let dao_origin: RuntimeOrigin = RawOrigin::Dao(dao.id.clone()).into();
+
+		// fund the treasury
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), treasury, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), asset_id, treasury, 300));
+		assert_eq!(DaoCore::treasury_balance(&dao.id), 100);
+		assert_eq!(DaoCore::treasury_token_balance(&dao.id), 300);
+
+		// neither the owner nor the treasury account itself can spend the funds
+		assert_noop!(
+			DaoCore::spend(RuntimeOrigin::signed(1), b"GDAO".to_vec(), 2, 50),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			DaoCore::spend_token(RuntimeOrigin::signed(treasury), b"GDAO".to_vec(), 2, 50),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(DaoCore::spend(dao_origin.clone(), b"GDAO".to_vec(), 2, 50));
+		assert_ok!(DaoCore::spend_token(dao_origin, b"GDAO".to_vec(), 2, 50));
+		assert_eq!(Balances::free_balance(2), 50);
+		assert_eq!(Assets::balance(asset_id, 2), 50);
+		assert_eq!(DaoCore::treasury_balance(&dao.id), 50);
+		assert_eq!(DaoCore::treasury_token_balance(&dao.id), 250);
+
+		// the native currency left in the treasury goes to the owner on destruction
+		let owner_balance = Balances::free_balance(1);
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(1), b"GDAO".to_vec()));
+		assert_eq!(Balances::free_balance(1), owner_balance + 50);
+		assert_eq!(DaoCore::treasury_balance(&dao.id), 0);
+	});
+}
+
+#[test]
+fn dao_origin_can_act_in_place_of_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			b"GDAO".to_vec(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			b"ODAO".to_vec(),
+			b"Other DAO".to_vec()
+		));
+		assert_ok!(DaoCore::issue_token(RuntimeOrigin::signed(1), b"GDAO".to_vec(), 1000));
+		assert_ok!(DaoCore::issue_token(RuntimeOrigin::signed(1), b"ODAO".to_vec(), 1000));
+		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
+		let other = DaoCore::load_dao(b"ODAO".to_vec()).unwrap();
+		let dao_origin: RuntimeOrigin = RawOrigin::Dao(dao.id.clone()).into();
+		let other_origin: RuntimeOrigin = RawOrigin::Dao(other.id.clone()).into();
+
+		// a DAO can only act on its own behalf
+		assert_noop!(
+			DaoCore::set_metadata(
+				other_origin.clone(),
+				b"GDAO".to_vec(),
+				b"http://my.cool.dao".to_vec(),
+				b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec()
+			),
+			Error::<Test>::DaoOriginMismatch
+		);
+		assert_noop!(
+			DaoCore::change_owner(other_origin, b"GDAO".to_vec(), 2),
+			Error::<Test>::DaoOriginMismatch
+		);
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 2, 100));
+
+		assert_ok!(DaoCore::set_metadata(
+			dao_origin.clone(),
+			b"GDAO".to_vec(),
+			b"http://my.cool.dao".to_vec(),
+			b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec()
+		));
+		assert_ok!(DaoCore::change_owner(dao_origin.clone(), b"GDAO".to_vec(), 2));
+		assert_eq!(DaoCore::load_dao(b"GDAO".to_vec()).unwrap().owner, 2);
+
+		// the DAO may administer its own token, but not other assets
+		assert_noop!(
+			Assets::start_destroy(dao_origin.clone(), other.asset_id.unwrap()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Assets::start_destroy(dao_origin, dao.asset_id.unwrap()));
+	});
+}
+
+#[test]
+fn council_can_act_in_place_of_owner() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+
+		assert_noop!(
+			DaoCore::set_council(RuntimeOrigin::signed(2), dao_id.clone(), vec![2, 3], 1),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_noop!(
+			DaoCore::set_council(RuntimeOrigin::signed(1), dao_id.clone(), vec![2, 3, 4, 5, 6], 3),
+			Error::<Test>::CouncilTooManyMembers
+		);
+		assert_noop!(
+			DaoCore::set_council(RuntimeOrigin::signed(1), dao_id.clone(), vec![2, 3, 2], 2),
+			Error::<Test>::CouncilMemberDuplicate
+		);
+		assert_noop!(
+			DaoCore::set_council(RuntimeOrigin::signed(1), dao_id.clone(), vec![2, 3], 3),
+			Error::<Test>::CouncilThresholdInvalid
+		);
+		assert_noop!(
+			DaoCore::set_council(RuntimeOrigin::signed(1), dao_id.clone(), vec![2, 3], 0),
+			Error::<Test>::CouncilThresholdInvalid
+		);
+		assert_ok!(DaoCore::set_council(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			vec![2, 3, 4],
+			2
+		));
+		let dao = DaoCore::load_dao(dao_id.clone()).unwrap();
+		assert_eq!(DaoCore::get_council(&dao.id).unwrap().threshold, 2);
+
+		// the owner is authorized right away, other accounts not at all
+		assert_eq!(DaoCore::authorize_owner_action(&1, &dao, b"action"), Ok(true));
+		assert_eq!(
+			DaoCore::authorize_owner_action(&5, &dao, b"action"),
+			Err(Error::<Test>::DaoSignerNotOwner.into())
+		);
+
+		// council members need to reach the threshold, approving twice does not count
+		assert_eq!(DaoCore::authorize_owner_action(&2, &dao, b"action"), Ok(false));
+		assert_eq!(DaoCore::authorize_owner_action(&2, &dao, b"action"), Ok(false));
+		assert_eq!(DaoCore::authorize_owner_action(&3, &dao, b"other action"), Ok(false));
+		assert_eq!(DaoCore::authorize_owner_action(&3, &dao, b"action"), Ok(true));
+		assert_eq!(CouncilApprovals::<Test>::iter_prefix(&dao.id).count(), 1);
+
+		// approvals of former members do not count
+		assert_ok!(DaoCore::set_council(RuntimeOrigin::signed(1), dao_id.clone(), vec![2, 4], 2));
+		assert_eq!(DaoCore::authorize_owner_action(&4, &dao, b"other action"), Ok(false));
+		assert_eq!(DaoCore::authorize_owner_action(&2, &dao, b"other action"), Ok(true));
+
+		assert_ok!(DaoCore::set_council(RuntimeOrigin::signed(1), dao_id.clone(), vec![], 0));
+		assert!(!Councils::<Test>::contains_key(&dao.id));
+		assert_eq!(
+			DaoCore::authorize_owner_action(&2, &dao, b"action"),
+			Err(Error::<Test>::DaoSignerNotOwner.into())
+		);
+	});
+}
+
+#[test]
+fn can_list_daos_by_owner() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(DaoCore::daos_by_owner(&1), vec![]);
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			b"GDAO".to_vec(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			b"ODAO".to_vec(),
+			b"Other DAO".to_vec()
+		));
+		assert_eq!(DaoCore::daos_by_owner(&1).len(), 2);
+
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 2, 100));
+		let dao_origin: RuntimeOrigin = RawOrigin::Dao(b"ODAO".to_vec().try_into().unwrap()).into();
+		assert_ok!(DaoCore::change_owner(dao_origin, b"ODAO".to_vec(), 2));
+		assert_eq!(DaoCore::daos_by_owner(&1), vec![DaoCore::load_dao(b"GDAO".to_vec()).unwrap()]);
+		assert_eq!(DaoCore::daos_by_owner(&2), vec![DaoCore::load_dao(b"ODAO".to_vec()).unwrap()]);
+	});
+}
+
+#[test]
+fn migration_moves_deposits_of_transferred_daos() {
+	new_test_ext().execute_with(|| {
+		parameter_types! {
+			pub Creators: Vec<(Vec<u8>, u32)> =
+				vec![(b"GDAO".to_vec(), 1), (b"ODAO".to_vec(), 1), (b"NONE".to_vec(), 1)];
+		}
+		let deposit = <Test as Config>::DaoDeposit::get();
+		for dao_id in [b"GDAO", b"ODAO", b"UDAO", b"RDAO"] {
+			assert_ok!(DaoCore::create_dao(
+				RuntimeOrigin::signed(1),
+				dao_id.to_vec(),
+				b"Genesis DAO".to_vec()
+			));
+		}
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 2, 100));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 3, 100));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 4, 100));
+
+		// ownership changed before the deposit moved along
+		for (dao_id, owner) in [(b"GDAO", 2), (b"UDAO", 3), (b"RDAO", 4)] {
+			let dao = DaoCore::load_dao(dao_id.to_vec()).unwrap();
+			Daos::<Test>::mutate(&dao.id, |dao| dao.as_mut().unwrap().owner = owner);
+		}
+		assert_ok!(Balances::reserve(&4, deposit));
+		StorageVersion::new(1).put::<DaoCore>();
+		assert_eq!(Balances::reserved_balance(1), 4 * deposit);
+
+		// the DAOs are visited once blocks have weight to spare
+		MoveDaoDeposits::<Test, Creators>::on_runtime_upgrade();
+		assert_eq!(DaoCore::on_chain_storage_version(), 1);
+		assert_eq!(Balances::reserved_balance(1), 4 * deposit);
+		DaoCore::on_idle(1, Weight::MAX);
+		assert_eq!(DaoCore::on_chain_storage_version(), 2);
+		assert_eq!(Balances::reserved_balance(1), 3 * deposit);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+
+		// the deposit of a DAO that isn't listed is located if the owner reserves it
+		assert_eq!(Balances::reserved_balance(4), deposit);
+		let reserved = DaoCore::load_dao(b"RDAO".to_vec()).unwrap();
+		assert!(!UnlocatedDeposits::<Test>::contains_key(&reserved.id));
+
+		// and stays where it is otherwise
+		assert_eq!(Balances::reserved_balance(3), 0);
+		let unlisted = DaoCore::load_dao(b"UDAO".to_vec()).unwrap();
+		assert!(UnlocatedDeposits::<Test>::contains_key(&unlisted.id));
+		assert_eq!(UnlocatedDeposits::<Test>::iter().count(), 1);
+
+		// the migration only runs once
+		MoveDaoDeposits::<Test, Creators>::on_runtime_upgrade();
+		DaoCore::on_idle(2, Weight::MAX);
+		assert_eq!(Balances::reserved_balance(1), 3 * deposit);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+	});
+}
+
+#[test]
+fn unlocated_deposits_are_neither_moved_nor_released() {
+	new_test_ext().execute_with(|| {
+		let deposit = <Test as Config>::DaoDeposit::get();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			b"GDAO".to_vec(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 2, 100));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 3, 100));
+		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
+
+		// the owner needs to reserve the whole deposit to transfer the ownership
+		assert_ok!(Balances::reserve(&2, deposit / 2));
+		Daos::<Test>::mutate(&dao.id, |dao| dao.as_mut().unwrap().owner = 2);
+		let dao_origin: RuntimeOrigin = RawOrigin::Dao(dao.id.clone()).into();
+		assert_noop!(
+			DaoCore::change_owner(dao_origin.clone(), b"GDAO".to_vec(), 3),
+			Error::<Test>::DaoDepositNotReserved
+		);
+
+		// the deposit of the previous owner is left alone once marked as unlocated
+		UnlocatedDeposits::<Test>::insert(&dao.id, ());
+		assert_ok!(DaoCore::change_owner(dao_origin, b"GDAO".to_vec(), 3));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_eq!(Balances::reserved_balance(2), deposit / 2);
+		assert_eq!(Balances::reserved_balance(3), 0);
+
+		// only root can locate the deposit, which needs to be reserved by the holder
+		assert_noop!(
+			DaoCore::locate_dao_deposit(RuntimeOrigin::signed(3), b"GDAO".to_vec(), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			DaoCore::locate_dao_deposit(RuntimeOrigin::root(), b"GDAO".to_vec(), 2),
+			Error::<Test>::DaoDepositNotReserved
+		);
+		assert_ok!(DaoCore::locate_dao_deposit(RuntimeOrigin::root(), b"GDAO".to_vec(), 1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(3), deposit);
+		assert!(!UnlocatedDeposits::<Test>::contains_key(&dao.id));
+		assert_noop!(
+			DaoCore::locate_dao_deposit(RuntimeOrigin::root(), b"GDAO".to_vec(), 1),
+			Error::<Test>::DaoDepositAlreadyLocated
+		);
+
+		// destroying a DAO with an unlocated deposit releases no reserve
+		UnlocatedDeposits::<Test>::insert(&dao.id, ());
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(3), b"GDAO".to_vec()));
+		assert_eq!(Balances::reserved_balance(3), deposit);
+		assert!(!UnlocatedDeposits::<Test>::contains_key(&dao.id));
 	});
 }
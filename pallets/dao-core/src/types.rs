@@ -2,9 +2,12 @@ use codec::MaxEncodedLen;
 use frame_support::{
 	codec::{Decode, Encode},
 	traits::ConstU32,
+	weights::Weight,
 	BoundedVec, RuntimeDebug,
 };
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 /// The DAO model
 ///
@@ -15,6 +18,7 @@ use scale_info::TypeInfo;
 /// - `meta` : HTTP or IPFS address for the metadata about this DAO (description, logo)
 /// - `meta_hash` : SHA3 hash of the metadata to be found via `meta`
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Dao<DaoId, AccountId, DaoName, AssetId, Metadata> {
 	pub id: DaoId,
 	pub owner: AccountId,
@@ -23,3 +27,40 @@ pub struct Dao<DaoId, AccountId, DaoName, AssetId, Metadata> {
 	pub meta: Metadata,
 	pub meta_hash: BoundedVec<u8, ConstU32<64>>,
 }
+
+/// The council of a DAO
+///
+/// - `members`: AccountIds of the council members
+/// - `threshold`: number of members that need to approve an action of the owner
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Council<Members> {
+	pub members: Members,
+	pub threshold: u32,
+}
+
+/// The origin of a DAO acting collectively, i.e. through an accepted proposal.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RawOrigin<DaoId> {
+	Dao(DaoId),
+}
+
+/// Removes what other pallets keep for a DAO while it is being destroyed, e.g. its governance.
+pub trait DaoTeardown<DaoId> {
+	/// Remove up to `limit` items kept for a DAO, returning deposits held for them. Returns
+	/// whether all items have been removed.
+	fn teardown(dao_id: &DaoId, limit: u32) -> bool;
+
+	/// The weight of removing up to `limit` items kept for a DAO.
+	fn teardown_weight(limit: u32) -> Weight;
+}
+
+impl<DaoId> DaoTeardown<DaoId> for () {
+	fn teardown(_dao_id: &DaoId, _limit: u32) -> bool {
+		true
+	}
+
+	fn teardown_weight(_limit: u32) -> Weight {
+		Weight::zero()
+	}
+}
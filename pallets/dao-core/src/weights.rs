@@ -55,9 +55,29 @@ pub trait WeightInfo {
 	#[rustfmt::skip]
 	fn destroy_dao() -> Weight;
 	#[rustfmt::skip]
+	fn continue_destroy_dao() -> Weight;
+	#[rustfmt::skip]
 	fn issue_token() -> Weight;
 	#[rustfmt::skip]
 	fn set_metadata() -> Weight;
+	#[rustfmt::skip]
+	fn change_owner() -> Weight;
+	#[rustfmt::skip]
+	fn spend() -> Weight;
+	#[rustfmt::skip]
+	fn spend_token() -> Weight;
+	#[rustfmt::skip]
+	fn set_council() -> Weight;
+	#[rustfmt::skip]
+	fn propose_owner() -> Weight;
+	#[rustfmt::skip]
+	fn accept_ownership() -> Weight;
+	#[rustfmt::skip]
+	fn cancel_ownership_transfer() -> Weight;
+	#[rustfmt::skip]
+	fn set_strict_metadata() -> Weight;
+	#[rustfmt::skip]
+	fn locate_dao_deposit() -> Weight;
 }
 
 /// Weights for pallet_dao_core using the Substrate node and recommended hardware.
@@ -71,11 +91,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DestroyingDaos (r:1 w:2)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DaoCore CouncilApprovals (r:0 w:1)
+	// Storage: DaoCore Councils (r:0 w:1)
+	// Storage: DaoCore ProposedOwners (r:0 w:1)
 	#[rustfmt::skip]
 	fn destroy_dao() -> Weight {
-		Weight::from_parts(183_000_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(224_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DestroyingDaos (r:1 w:1)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DaoCore CouncilApprovals (r:0 w:1)
+	// Storage: DaoCore Councils (r:0 w:1)
+	// Storage: DaoCore ProposedOwners (r:0 w:1)
+	#[rustfmt::skip]
+	fn continue_destroy_dao() -> Weight {
+		Weight::from_parts(199_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
 	// Storage: DaoCore CurrentAssetId (r:1 w:1)
@@ -89,12 +128,100 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore StrictMetadataDaos (r:1 w:0)
 	#[rustfmt::skip]
 	fn set_metadata() -> Weight {
-		Weight::from_parts(161_000_000_u64, 0)
+		Weight::from_parts(168_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore ProposedOwners (r:0 w:1)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:0)
+	#[rustfmt::skip]
+	fn change_owner() -> Weight {
+		Weight::from_parts(265_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+	fn spend() -> Weight {
+		Weight::from_parts(182_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	// Storage: Assets AccountCheckpointCount (r:2 w:2)
+	// Storage: Assets RetainedSnapshotId (r:1 w:0)
+	// Storage: Assets PrunedAccountCheckpoints (r:2 w:2)
+	// Storage: Assets AccountCheckpoints (r:6 w:6)
+	#[rustfmt::skip]
+	fn spend_token() -> Weight {
+		Weight::from_parts(241_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore Councils (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_council() -> Weight {
+		Weight::from_parts(164_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore ProposedOwners (r:0 w:1)
+	#[rustfmt::skip]
+	fn propose_owner() -> Weight {
+		Weight::from_parts(158_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore ProposedOwners (r:1 w:1)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:0)
+	#[rustfmt::skip]
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(277_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore ProposedOwners (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(154_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore StrictMetadataDaos (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_strict_metadata() -> Weight {
+		Weight::from_parts(148_000_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+	fn locate_dao_deposit() -> Weight {
+		Weight::from_parts(176_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -107,11 +234,30 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DestroyingDaos (r:1 w:2)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DaoCore CouncilApprovals (r:0 w:1)
+	// Storage: DaoCore Councils (r:0 w:1)
+	// Storage: DaoCore ProposedOwners (r:0 w:1)
 	#[rustfmt::skip]
 	fn destroy_dao() -> Weight {
-		Weight::from_parts(183_000_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(224_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DestroyingDaos (r:1 w:1)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DaoCore CouncilApprovals (r:0 w:1)
+	// Storage: DaoCore Councils (r:0 w:1)
+	// Storage: DaoCore ProposedOwners (r:0 w:1)
+	#[rustfmt::skip]
+	fn continue_destroy_dao() -> Weight {
+		Weight::from_parts(199_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
 	// Storage: DaoCore CurrentAssetId (r:1 w:1)
@@ -125,10 +271,98 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore StrictMetadataDaos (r:1 w:0)
 	#[rustfmt::skip]
 	fn set_metadata() -> Weight {
-		Weight::from_parts(161_000_000_u64, 0)
+		Weight::from_parts(168_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore ProposedOwners (r:0 w:1)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:0)
+	#[rustfmt::skip]
+	fn change_owner() -> Weight {
+		Weight::from_parts(265_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+	fn spend() -> Weight {
+		Weight::from_parts(182_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	// Storage: Assets AccountCheckpointCount (r:2 w:2)
+	// Storage: Assets RetainedSnapshotId (r:1 w:0)
+	// Storage: Assets PrunedAccountCheckpoints (r:2 w:2)
+	// Storage: Assets AccountCheckpoints (r:6 w:6)
+	#[rustfmt::skip]
+	fn spend_token() -> Weight {
+		Weight::from_parts(241_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore Councils (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_council() -> Weight {
+		Weight::from_parts(164_000_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore ProposedOwners (r:0 w:1)
+	#[rustfmt::skip]
+	fn propose_owner() -> Weight {
+		Weight::from_parts(158_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore ProposedOwners (r:1 w:1)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:0)
+	#[rustfmt::skip]
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(277_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore ProposedOwners (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(154_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore StrictMetadataDaos (r:0 w:1)
+	#[rustfmt::skip]
+	fn set_strict_metadata() -> Weight {
+		Weight::from_parts(148_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore UnlocatedDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+	fn locate_dao_deposit() -> Weight {
+		Weight::from_parts(176_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+//! Runtime API definition for querying DAOs, their governance, proposals and votes.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	pub trait DaoApi<AccountId, Balance, Dao, Governance, Proposal, ProposalId, Vote>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		Dao: Codec,
+		Governance: Codec,
+		Proposal: Codec,
+		ProposalId: Codec,
+		Vote: Codec,
+	{
+		/// The DAO with the given id.
+		fn get_dao(dao_id: Vec<u8>) -> Option<Dao>;
+
+		/// All DAOs owned by an account.
+		fn get_daos_by_owner(owner: AccountId) -> Vec<Dao>;
+
+		/// The governance of a DAO.
+		fn get_governance(dao_id: Vec<u8>) -> Option<Governance>;
+
+		/// A proposal including its tallies.
+		fn get_proposal(proposal_id: ProposalId) -> Option<Proposal>;
+
+		/// The vote of an account on a proposal.
+		fn get_vote(proposal_id: ProposalId, voter: AccountId) -> Option<Vote>;
+
+		/// The historical voting power of an account on a proposal.
+		fn get_voting_power(proposal_id: ProposalId, voter: AccountId) -> Option<Balance>;
+	}
+}
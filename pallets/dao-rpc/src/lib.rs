@@ -0,0 +1,189 @@
+//! RPC interface for querying DAOs, their governance, proposals and votes.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_dao_rpc_runtime_api::DaoApi as DaoRuntimeApi;
+
+#[cfg(test)]
+mod tests;
+
+#[rpc(client, server)]
+pub trait DaoApi<BlockHash, AccountId, Balance, Dao, Governance, Proposal, ProposalId, Vote> {
+	/// The DAO with the given id.
+	#[method(name = "dao_getDao")]
+	fn get_dao(&self, dao_id: String, at: Option<BlockHash>) -> RpcResult<Option<Dao>>;
+
+	/// All DAOs owned by an account.
+	#[method(name = "dao_getDaosByOwner")]
+	fn get_daos_by_owner(&self, owner: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<Dao>>;
+
+	/// The governance of a DAO.
+	#[method(name = "dao_getGovernance")]
+	fn get_governance(
+		&self,
+		dao_id: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Governance>>;
+
+	/// A proposal including its tallies.
+	#[method(name = "dao_getProposal")]
+	fn get_proposal(
+		&self,
+		proposal_id: ProposalId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Proposal>>;
+
+	/// The vote of an account on a proposal: `"InFavor"`, `"Against"` or `"Abstain"`. Clients
+	/// reading a boolean, as returned before abstentions existed, break.
+	#[method(name = "dao_getVote")]
+	fn get_vote(
+		&self,
+		proposal_id: ProposalId,
+		voter: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vote>>;
+
+	/// The historical voting power of an account on a proposal.
+	#[method(name = "dao_getVotingPower")]
+	fn get_voting_power(
+		&self,
+		proposal_id: ProposalId,
+		voter: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Balance>>;
+}
+
+/// Provides RPC methods to query DAOs, proposals and votes.
+pub struct DaoRpc<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> DaoRpc<C, P> {
+	/// Creates a new instance of the DAO RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl ToString) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string())))
+		.into()
+}
+
+impl<C, Block, AccountId, Balance, Dao, Governance, Proposal, ProposalId, Vote>
+	DaoApiServer<
+		<Block as BlockT>::Hash,
+		AccountId,
+		Balance,
+		Dao,
+		Governance,
+		Proposal,
+		ProposalId,
+		Vote,
+	> for DaoRpc<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: DaoRuntimeApi<Block, AccountId, Balance, Dao, Governance, Proposal, ProposalId, Vote>,
+	// the parameters are deserialized from and the results serialized to JSON
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	Dao: Codec + Serialize + Send + Sync + 'static,
+	Governance: Codec + Serialize + Send + Sync + 'static,
+	Proposal: Codec + Serialize + Send + Sync + 'static,
+	ProposalId: Codec + DeserializeOwned + Send + Sync + 'static,
+	Vote: Codec + Serialize + Send + Sync + 'static,
+{
+	fn get_dao(&self, dao_id: String, at: Option<Block::Hash>) -> RpcResult<Option<Dao>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_dao(at_hash, dao_id.into_bytes())
+			.map_err(|e| runtime_error("Unable to query DAO.", e))
+	}
+
+	fn get_daos_by_owner(&self, owner: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<Dao>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_daos_by_owner(at_hash, owner)
+			.map_err(|e| runtime_error("Unable to query DAOs by owner.", e))
+	}
+
+	fn get_governance(
+		&self,
+		dao_id: String,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Governance>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_governance(at_hash, dao_id.into_bytes())
+			.map_err(|e| runtime_error("Unable to query governance.", e))
+	}
+
+	fn get_proposal(
+		&self,
+		proposal_id: ProposalId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Proposal>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_proposal(at_hash, proposal_id)
+			.map_err(|e| runtime_error("Unable to query proposal.", e))
+	}
+
+	fn get_vote(
+		&self,
+		proposal_id: ProposalId,
+		voter: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Vote>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_vote(at_hash, proposal_id, voter)
+			.map_err(|e| runtime_error("Unable to query vote.", e))
+	}
+
+	fn get_voting_power(
+		&self,
+		proposal_id: ProposalId,
+		voter: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Balance>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_voting_power(at_hash, proposal_id, voter)
+			.map_err(|e| runtime_error("Unable to query voting power.", e))
+	}
+}
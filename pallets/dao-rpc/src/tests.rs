@@ -0,0 +1,102 @@
+use super::*;
+use sp_api::ApiRef;
+use sp_blockchain::{BlockStatus, Info};
+use sp_runtime::testing::{Block as TestBlock, ExtrinsicWrapper, Header};
+
+type Block = TestBlock<ExtrinsicWrapper<u64>>;
+type Hash = <Block as BlockT>::Hash;
+
+type TestRpc = DaoRpc<TestClient, Block>;
+
+/// DAOs, governances, proposals and votes are represented by strings, accounts by numbers.
+#[derive(Clone, Default)]
+struct TestApi;
+
+sp_api::mock_impl_runtime_apis! {
+	impl DaoRuntimeApi<Block, u64, u128, String, String, String, u32, String> for TestApi {
+		fn get_dao(dao_id: Vec<u8>) -> Option<String> {
+			String::from_utf8(dao_id).ok()
+		}
+
+		fn get_daos_by_owner(owner: u64) -> Vec<String> {
+			vec![format!("DAO{owner}")]
+		}
+
+		fn get_governance(_dao_id: Vec<u8>) -> Option<String> {
+			None
+		}
+
+		fn get_proposal(proposal_id: u32) -> Option<String> {
+			Some(format!("proposal {proposal_id}"))
+		}
+
+		fn get_vote(_proposal_id: u32, _voter: u64) -> Option<String> {
+			Some("Abstain".into())
+		}
+
+		fn get_voting_power(proposal_id: u32, voter: u64) -> Option<u128> {
+			Some(u128::from(proposal_id) * u128::from(voter))
+		}
+	}
+}
+
+struct TestClient;
+
+impl ProvideRuntimeApi<Block> for TestClient {
+	type Api = TestApi;
+
+	fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+		TestApi.into()
+	}
+}
+
+impl HeaderBackend<Block> for TestClient {
+	fn header(&self, _hash: Hash) -> sp_blockchain::Result<Option<Header>> {
+		Ok(None)
+	}
+
+	fn info(&self) -> Info<Block> {
+		Info {
+			best_hash: Default::default(),
+			best_number: 0,
+			genesis_hash: Default::default(),
+			finalized_hash: Default::default(),
+			finalized_number: 0,
+			finalized_state: None,
+			number_leaves: 0,
+			block_gap: None,
+		}
+	}
+
+	fn status(&self, _hash: Hash) -> sp_blockchain::Result<BlockStatus> {
+		Ok(BlockStatus::Unknown)
+	}
+
+	fn number(&self, _hash: Hash) -> sp_blockchain::Result<Option<u64>> {
+		Ok(None)
+	}
+
+	fn hash(&self, _number: u64) -> sp_blockchain::Result<Option<Hash>> {
+		Ok(None)
+	}
+}
+
+#[tokio::test]
+async fn rpc_module_serves_the_runtime_api() {
+	let server = TestRpc::new(Arc::new(TestClient));
+	let rpc =
+		DaoApiServer::<Hash, u64, u128, String, String, String, u32, String>::into_rpc(server);
+
+	let dao: Option<String> = rpc.call("dao_getDao", ["GDAO"]).await.unwrap();
+	assert_eq!(dao, Some("GDAO".into()));
+	let daos: Vec<String> = rpc.call("dao_getDaosByOwner", [1_u64]).await.unwrap();
+	assert_eq!(daos, vec!["DAO1".to_string()]);
+	let governance: Option<String> = rpc.call("dao_getGovernance", ["GDAO"]).await.unwrap();
+	assert_eq!(governance, None);
+	let proposal: Option<String> = rpc.call("dao_getProposal", [2_u32]).await.unwrap();
+	assert_eq!(proposal, Some("proposal 2".into()));
+	let vote: Option<String> = rpc.call("dao_getVote", [2_u64, 3]).await.unwrap();
+	assert_eq!(vote, Some("Abstain".into()));
+	let voting_power: Option<u128> = rpc.call("dao_getVotingPower", [2_u64, 3]).await.unwrap();
+	assert_eq!(voting_power, Some(6));
+}
@@ -2,7 +2,7 @@
 
 use super::*;
 use crate::{test_utils::*, Pallet as Votes};
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_system::{Pallet as System, RawOrigin};
 use pallet_dao_core::{Config as DaoConfig, Currency};
 
@@ -17,20 +17,30 @@ fn setup_caller<T: Config>() -> T::AccountId {
 	caller
 }
 
+/// An account with enough funds to delegate
+fn setup_delegator<T: Config>(index: u32) -> T::AccountId {
+	let delegator: T::AccountId = account("delegator", index, 0);
+	let balance = <T as DaoConfig>::Currency::minimum_balance() + T::DelegationDeposit::get();
+	<T as DaoConfig>::Currency::make_free_balance_be(&delegator, balance);
+	delegator
+}
+
 /// Creates a DAO for the given caller with a governance set and a proposal created and accepted
 /// - `caller`: AccountId of the dao creator
 /// - `dao_id`: id of the dao
 fn setup_accepted_proposal<T: Config>(caller: T::AccountId, dao_id: Vec<u8>) -> T::ProposalId {
 	let proposal_id = setup_proposal::<T>(caller.clone(), dao_id);
 	assert_eq!(
-		Votes::<T>::vote(RawOrigin::Signed(caller.clone()).into(), proposal_id, Some(true)),
+		Votes::<T>::vote(
+			RawOrigin::Signed(caller.clone()).into(),
+			proposal_id,
+			Some(Vote::InFavor),
+			None
+		),
 		Ok(())
 	);
 	run_to_block::<T>(System::<T>::block_number() + 1_u32.into());
-	assert_eq!(
-		Votes::<T>::finalize_proposal(RawOrigin::Signed(caller).into(), proposal_id),
-		Ok(())
-	);
+	assert!(Votes::<T>::finalize_proposal(RawOrigin::Signed(caller).into(), proposal_id).is_ok());
 	proposal_id
 }
 
@@ -42,7 +52,7 @@ benchmarks! {
 	create_proposal {
 		let creator = setup_caller::<T>();
 		let dao_id = setup_dao_with_governance::<T>(creator.clone());
-	}: _(RawOrigin::Signed(creator.clone()), dao_id.clone())
+	}: _(RawOrigin::Signed(creator.clone()), dao_id.clone(), None)
 	verify {
 		assert_last_event::<T>(Event::ProposalCreated {
 			dao_id: dao_id.try_into().unwrap(),
@@ -51,10 +61,12 @@ benchmarks! {
 	}
 
 	set_metadata {
+		let m in 24 .. <T as pallet_dao_core::Config>::MaxLengthMetadata::get();
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao_with_governance::<T>(caller.clone());
 		let proposal_id = create_proposal_id::<T>(caller.clone(), dao_id);
-		let metadata = b"http://my.cool.proposal".to_vec();
+		let mut metadata = b"http://my.cool.proposal/".to_vec();
+		metadata.resize(m as usize, b'a');
 		// https://en.wikipedia.org/wiki/SHA-3#Examples_of_SHA-3_variants
 		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
 	}: _(RawOrigin::Signed(caller.clone()), proposal_id, metadata, hash)
@@ -62,6 +74,26 @@ benchmarks! {
 		assert_last_event::<T>(Event::ProposalMetadataSet { proposal_id: Votes::<T>::get_current_proposal_id() }.into());
 	}
 
+	cancel_proposal {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let proposal_id = create_proposal_id::<T>(caller.clone(), dao_id);
+	}: _(RawOrigin::Signed(caller), proposal_id)
+	verify {
+		assert_last_event::<T>(Event::ProposalCancelled { proposal_id }.into());
+	}
+
+	expire_proposal_slot {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let proposal_id = create_proposal_id::<T>(caller.clone(), dao_id);
+		let expiry = T::ProposalSlotExpiry::get();
+		System::<T>::set_block_number(System::<T>::block_number() + expiry + 1_u32.into());
+	}: _(RawOrigin::Signed(caller), proposal_id)
+	verify {
+		assert_last_event::<T>(Event::ProposalSlotExpired { proposal_id }.into());
+	}
+
 	fault_proposal {
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao_with_governance::<T>(caller.clone());
@@ -83,14 +115,90 @@ benchmarks! {
 	}
 
 	vote {
+		let d in 0 .. T::MaxDelegators::get();
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		for i in 0..d {
+			let delegator = setup_delegator::<T>(i);
+			assert_eq!(
+				Votes::<T>::delegate(RawOrigin::Signed(delegator).into(), dao_id.clone(), caller.clone()),
+				Ok(())
+			);
+		}
 		let proposal_id = setup_proposal::<T>(caller.clone(), dao_id);
+		// changing a vote uncounts and recounts the voting power of all delegators
+		assert_eq!(
+			Votes::<T>::vote(RawOrigin::Signed(caller.clone()).into(), proposal_id, Some(Vote::Against), None),
+			Ok(())
+		);
 		let voter = caller;
-		let in_favor = Some(true);
-	}: _(RawOrigin::Signed(voter.clone()), proposal_id, in_favor)
+		let vote = Some(Vote::InFavor);
+		let conviction = Some(Conviction::Locked6x);
+	}: _(RawOrigin::Signed(voter.clone()), proposal_id, vote, conviction)
 	verify {
-		assert_last_event::<T>(Event::VoteCast { proposal_id, voter, in_favor }.into());
+		assert_last_event::<T>(Event::VoteCast { proposal_id, voter, vote, conviction }.into());
+	}
+
+	set_options {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let proposal_id = create_proposal_id::<T>(caller.clone(), dao_id);
+		let options = T::MaxOptions::get() as OptionIndex;
+		let tallying = Tallying::RankedChoice;
+	}: _(RawOrigin::Signed(caller), proposal_id, options, tallying)
+	verify {
+		assert_last_event::<T>(Event::ProposalOptionsSet { proposal_id, options, tallying }.into());
+	}
+
+	vote_options {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let proposal_id = create_proposal_id::<T>(caller.clone(), dao_id);
+		let options = T::MaxOptions::get() as OptionIndex;
+		assert_eq!(
+			Votes::<T>::set_options(
+				RawOrigin::Signed(caller.clone()).into(),
+				proposal_id,
+				options,
+				Tallying::RankedChoice
+			),
+			Ok(())
+		);
+		setup_proposal_with_id::<T>(caller.clone(), proposal_id);
+		// changing a vote uncounts the previous ranking
+		let ranking: Vec<OptionIndex> = (0..options).collect();
+		assert_eq!(
+			Votes::<T>::vote_options(RawOrigin::Signed(caller.clone()).into(), proposal_id, ranking.clone()),
+			Ok(())
+		);
+		let ranking: Vec<OptionIndex> = ranking.into_iter().rev().collect();
+	}: _(RawOrigin::Signed(caller.clone()), proposal_id, ranking.clone())
+	verify {
+		assert_last_event::<T>(Event::OptionVoteCast { proposal_id, voter: caller, ranking }.into());
+	}
+
+	unlock {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let proposal_id = setup_proposal::<T>(caller.clone(), dao_id.clone());
+		assert_eq!(
+			Votes::<T>::vote(
+				RawOrigin::Signed(caller.clone()).into(),
+				proposal_id,
+				Some(Vote::InFavor),
+				Some(Conviction::Locked6x)
+			),
+			Ok(())
+		);
+		let lock = VotingLocks::<T>::get(
+			DaoIdOf::<T>::try_from(dao_id.clone()).expect("fits"),
+			&caller,
+		).expect("tokens are locked");
+		System::<T>::set_block_number(lock.until + 1_u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone())
+	verify {
+		let dao_id: DaoIdOf<T> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::VotingTokensUnlocked { dao_id, who: caller, amount: lock.amount }.into());
 	}
 
 	set_governance_majority_vote {
@@ -105,6 +213,44 @@ benchmarks! {
 		assert_last_event::<T>(Event::SetGovernanceMajorityVote { dao_id, proposal_duration, proposal_token_deposit, minimum_majority_per_1024 }.into());
 	}
 
+	set_governance_quorum_vote {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let proposal_duration = 1_u32;
+		let proposal_token_deposit = 1_u32.into();
+		let min_turnout_per_1024 = 256;
+		let approval_threshold_per_1024 = 512;
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), proposal_duration, proposal_token_deposit, min_turnout_per_1024, approval_threshold_per_1024)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::SetGovernanceQuorumVote { dao_id, proposal_duration, proposal_token_deposit, min_turnout_per_1024, approval_threshold_per_1024 }.into());
+	}
+
+	set_governance_supermajority_vote {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let proposal_duration = 1_u32;
+		let proposal_token_deposit = 1_u32.into();
+		let min_approval_per_1024 = 683;
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), proposal_duration, proposal_token_deposit, min_approval_per_1024)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::SetGovernanceSupermajorityVote { dao_id, proposal_duration, proposal_token_deposit, min_approval_per_1024 }.into());
+	}
+
+	set_governance_quadratic_vote {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let proposal_duration = 1_u32;
+		let proposal_token_deposit = 1_u32.into();
+		let approval_threshold_per_1024 = 512;
+		let personhood = T::Personhood::is_available();
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), proposal_duration, proposal_token_deposit, approval_threshold_per_1024, personhood)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::SetGovernanceQuadraticVote { dao_id, proposal_duration, proposal_token_deposit, approval_threshold_per_1024, personhood }.into());
+	}
+
 	mark_implemented {
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao_with_governance::<T>(caller.clone());
@@ -114,5 +260,100 @@ benchmarks! {
 		assert_last_event::<T>(Event::ProposalImplemented { proposal_id }.into());
 	}
 
+	delegate {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		// the delegation is moved from a delegate to another one with all but one delegator
+		let previous: T::AccountId = account("previous", 0, 0);
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		assert_eq!(
+			Votes::<T>::delegate(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), previous),
+			Ok(())
+		);
+		for i in 1..T::MaxDelegators::get() {
+			let delegator = setup_delegator::<T>(i);
+			assert_eq!(
+				Votes::<T>::delegate(RawOrigin::Signed(delegator).into(), dao_id.clone(), delegate.clone()),
+				Ok(())
+			);
+		}
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), delegate.clone())
+	verify {
+		let dao_id: DaoIdOf<T> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::VoteDelegated { dao_id, delegator: caller, delegate }.into());
+	}
+
+	undelegate {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		for i in 1..T::MaxDelegators::get() {
+			let delegator = setup_delegator::<T>(i);
+			assert_eq!(
+				Votes::<T>::delegate(RawOrigin::Signed(delegator).into(), dao_id.clone(), delegate.clone()),
+				Ok(())
+			);
+		}
+		assert_eq!(
+			Votes::<T>::delegate(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), delegate),
+			Ok(())
+		);
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone())
+	verify {
+		let dao_id: DaoIdOf<T> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::VoteUndelegated { dao_id, delegator: caller }.into());
+	}
+
+	prune_proposal {
+		let v in 0 .. <T as pallet_dao_assets::Config>::RemoveItemsLimit::get() - 1;
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let proposal_id = setup_accepted_proposal::<T>(caller.clone(), dao_id);
+		for i in 0..v {
+			let voter: T::AccountId = account("voter", i, 0);
+			crate::Votes::<T>::insert(proposal_id, voter, Vote::InFavor);
+		}
+		let retention = T::ProposalRetentionPeriod::get();
+		System::<T>::set_block_number(System::<T>::block_number() + retention + 1_u32.into());
+	}: _(RawOrigin::Signed(caller), proposal_id)
+	verify {
+		assert_last_event::<T>(Event::ProposalPruned { proposal_id }.into());
+	}
+
+	set_calls {
+		let b in 0 .. 64 * 1024;
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let proposal_id = create_proposal_id::<T>(caller.clone(), dao_id);
+		// a single call carries the bytes, the others are as small as possible
+		let remark = |bytes: u32| -> CallOf<T> {
+			frame_system::Call::<T>::remark { remark: vec![0; bytes as usize] }.into()
+		};
+		let mut calls = vec![remark(b)];
+		for _ in 1..T::MaxCalls::get() {
+			calls.push(remark(0));
+		}
+	}: _(RawOrigin::Signed(caller), proposal_id, calls)
+	verify {
+		assert_last_event::<T>(Event::ProposalCallsSet { proposal_id }.into());
+	}
+
+	teardown {
+		let i in 0 .. <T as pallet_dao_assets::Config>::RemoveItemsLimit::get() - 1;
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		for _ in 0..i {
+			// running proposals hold deposits, ending in different blocks
+			setup_proposal::<T>(caller.clone(), dao_id.clone());
+			run_to_block::<T>(System::<T>::block_number() + 1_u32.into());
+		}
+		let dao_id: DaoIdOf<T> = dao_id.try_into().expect("fits");
+	}: {
+		assert!(<Votes<T> as DaoTeardown<_>>::teardown(&dao_id, i + 1));
+	}
+	verify {
+		assert_eq!(DaoProposals::<T>::iter_key_prefix(&dao_id).count(), 0);
+	}
+
 	impl_benchmark_test_suite!(Votes, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -1,22 +1,116 @@
 use codec::MaxEncodedLen;
-use frame_support::{codec::{Decode, Encode}, RuntimeDebug};
+use frame_support::{codec::{Decode, Encode}, weights::Weight, Parameter, RuntimeDebug};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub struct Governance<Balance> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Governance<Balance, AccountId> {
 	// the number of blocks a proposal is open for voting
 	pub proposal_duration: u32,
 	// the token deposit required to create a proposal
 	pub proposal_token_deposit: Balance,
 	// the rules for accepting proposals
-	pub voting: Voting,
+	pub voting: Voting<AccountId>,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub enum Voting {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Voting<AccountId> {
 	Majority {
 		// how many more ayes than nays there must be for proposal acceptance
 		// thus proposal acceptance requires: ayes >= nays + token_supply / 1024 * minimum_majority_per_1024
 		minimum_majority_per_1024: u8,
 	},
+	Quorum {
+		// how many tokens must have been cast (ayes, nays and abstentions) for the vote to be valid
+		// thus proposal acceptance requires: ayes + nays + abstentions >= token_supply / 1024 * min_turnout_per_1024
+		min_turnout_per_1024: u16,
+		// which share of the ayes and nays must be ayes for proposal acceptance
+		// thus proposal acceptance requires: ayes > (ayes + nays) / 1024 * approval_threshold_per_1024
+		approval_threshold_per_1024: u16,
+	},
+	Supermajority {
+		// which share of the whole token supply must be ayes for proposal acceptance
+		// thus proposal acceptance requires: ayes > nays and ayes >= token_supply / 1024 * min_approval_per_1024
+		min_approval_per_1024: u16,
+	},
+	Quadratic {
+		// which share of the ayes and nays must be ayes for proposal acceptance, where every voter
+		// weighs in with the integer square root of its token balance
+		// thus proposal acceptance requires: ayes > (ayes + nays) / 1024 * approval_threshold_per_1024
+		approval_threshold_per_1024: u16,
+		// whether only accounts verified to belong to a person can vote, one account per person
+		personhood: bool,
+	},
+	Contract {
+		// the ink! contract deciding on proposal acceptance given the tally, see `VotingStrategy`
+		address: AccountId,
+	},
+}
+
+/// The tally of a proposal as passed to a voting strategy contract.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Tally<Balance> {
+	pub in_favor: Balance,
+	pub against: Balance,
+	pub abstain: Balance,
+	// the token supply at the snapshot taken when the proposal has been created
+	pub token_supply: Balance,
+}
+
+/// Calls into the ink! contracts DAOs registered as their voting strategy.
+pub trait VotingStrategy<AccountId, Balance> {
+	/// Whether there is a contract at `address`.
+	fn is_contract(address: &AccountId) -> bool;
+
+	/// Whether the contract at `address` accepts a proposal with the given tally, `None` if the
+	/// call into the contract failed. The call must not change any state and must not use more
+	/// than `max_weight`.
+	fn accepts(address: &AccountId, tally: &Tally<Balance>) -> Option<bool>;
+
+	/// The maximum weight of a call into a contract, which is reserved for finalizing a proposal.
+	fn max_weight() -> Weight;
+}
+
+/// There are no contracts to call into.
+impl<AccountId, Balance> VotingStrategy<AccountId, Balance> for () {
+	fn is_contract(_: &AccountId) -> bool {
+		false
+	}
+
+	fn accepts(_: &AccountId, _: &Tally<Balance>) -> Option<bool> {
+		None
+	}
+
+	fn max_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Verifies that accounts belong to persons, for quadratic voting with one account per person.
+pub trait Personhood<AccountId> {
+	/// Identifies a person independent of its accounts.
+	type PersonId: Parameter + MaxEncodedLen;
+
+	/// Whether accounts can be verified at all, quadratic voting limited to persons is
+	/// rejected otherwise.
+	fn is_available() -> bool;
+
+	/// The person an account has been verified to belong to, `None` if it is not verified.
+	fn person_of(who: &AccountId) -> Option<Self::PersonId>;
+}
+
+/// No account is verified to belong to a person.
+impl<AccountId> Personhood<AccountId> for () {
+	type PersonId = ();
+
+	fn is_available() -> bool {
+		false
+	}
+
+	fn person_of(_: &AccountId) -> Option<()> {
+		None
+	}
 }
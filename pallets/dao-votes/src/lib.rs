@@ -1,12 +1,17 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use sp_std::prelude::*;
+use sp_std::{prelude::*, vec};
 
 use frame_support::{
-	sp_runtime::traits::{One, Saturating, Zero},
-	storage::bounded_vec::BoundedVec,
-	traits::ReservableCurrency,
+	dispatch::{
+		extract_actual_weight, DispatchError, DispatchResult, GetDispatchInfo, PostDispatchInfo,
+	},
+	sp_runtime::traits::{Dispatchable, Hash, IntegerSquareRoot, One, Saturating, Zero},
+	storage::{bounded_vec::BoundedVec, with_storage_layer},
+	traits::{BalanceStatus, ReservableCurrency},
+	weights::Weight,
 };
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -25,18 +30,27 @@ mod types;
 pub use types::*;
 
 mod governance_types;
-use governance_types::*;
+pub use governance_types::*;
 
-use pallet_dao_assets::{AssetBalanceOf, Pallet as Assets};
+use pallet_dao_assets::{
+	AssetBalanceOf, Error as AssetError, Pallet as Assets, SnapshotId, TransferFlags,
+};
 use pallet_dao_core::{
-	AccountIdOf, CurrencyOf, DaoIdOf, DepositBalanceOf, Error as DaoError, Pallet as Core,
+	AccountIdOf, CurrencyOf, DaoIdOf, DaoOf, DaoTeardown, DepositBalanceOf, Error as DaoError,
+	Pallet as Core,
 };
 
+pub mod migrations;
+
 pub mod weights;
 use weights::WeightInfo;
 
-type ProposalSlotOf<T> = ProposalSlot<DaoIdOf<T>, <T as frame_system::Config>::AccountId>;
-type ProposalOf<T> = Proposal<
+type ProposalSlotOf<T> = ProposalSlot<
+	DaoIdOf<T>,
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+>;
+pub type ProposalOf<T> = Proposal<
 	DaoIdOf<T>,
 	<T as frame_system::Config>::AccountId,
 	<T as frame_system::Config>::BlockNumber,
@@ -44,7 +58,15 @@ type ProposalOf<T> = Proposal<
 	pallet_dao_core::MetadataOf<T>,
 >;
 
-type GovernanceOf<T> = Governance<AssetBalanceOf<T>>;
+pub type GovernanceOf<T> = Governance<AssetBalanceOf<T>, AccountIdOf<T>>;
+pub type VotingOf<T> = Voting<AccountIdOf<T>>;
+pub type ProposalResultOf<T> = ProposalResult<DaoIdOf<T>, <T as frame_system::Config>::Hash>;
+
+type CallOf<T> = <T as Config>::RuntimeCall;
+type CallsOf<T> = BoundedVec<CallOf<T>, <T as Config>::MaxCalls>;
+type RankingOf<T> = BoundedVec<OptionIndex, <T as Config>::MaxOptions>;
+type TalliesOf<T> = BoundedVec<AssetBalanceOf<T>, <T as Config>::MaxOptions>;
+type PersonIdOf<T> = <<T as Config>::Personhood as Personhood<AccountIdOf<T>>>::PersonId;
 
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
@@ -65,16 +87,188 @@ pub mod pallet {
 	pub(super) type Proposals<T: Config> =
 		StorageMap<_, Twox64Concat, T::ProposalId, ProposalOf<T>>;
 
+	/// The results of pruned proposals.
+	#[pallet::storage]
+	pub(super) type ProposalResults<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, ProposalResultOf<T>>;
+
+	/// The pruned proposals whose votes have not all been removed yet.
+	#[pallet::storage]
+	pub(super) type PruningProposals<T: Config> = StorageMap<_, Twox64Concat, T::ProposalId, ()>;
+
+	/// The part of the deposits of finalized proposals kept reserved to reward their pruning.
+	#[pallet::storage]
+	pub(super) type PruningRewards<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, DepositBalanceOf<T>>;
+
+	/// The proposals and proposal slots of each DAO.
+	#[pallet::storage]
+	pub(super) type DaoProposals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, DaoIdOf<T>, Twox64Concat, T::ProposalId, ()>;
+
 	#[pallet::storage]
 	pub(super) type Votes<T: Config> =
-		StorageDoubleMap<_, Twox64Concat, T::ProposalId, Twox64Concat, AccountIdOf<T>, bool>;
+		StorageDoubleMap<_, Twox64Concat, T::ProposalId, Twox64Concat, AccountIdOf<T>, Vote>;
+
+	/// The conviction of votes that have been cast with one.
+	#[pallet::storage]
+	pub(super) type VoteConvictions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::ProposalId, Twox64Concat, AccountIdOf<T>, Conviction>;
+
+	/// The DAO tokens of an account locked by conviction votes in a DAO.
+	#[pallet::storage]
+	pub(super) type VotingLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DaoIdOf<T>,
+		Twox64Concat,
+		AccountIdOf<T>,
+		VotingLock<AssetBalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// The calls to be dispatched by the DAO once a proposal has been accepted.
+	#[pallet::storage]
+	pub(super) type ProposalCalls<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, CallsOf<T>>;
+
+	/// The results of the dispatched calls of an accepted proposal, in order of the calls.
+	#[pallet::storage]
+	pub(super) type ProposalCallResults<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, BoundedVec<DispatchResult, T::MaxCalls>>;
+
+	/// The DAO token deposits reserved from the creators of proposals.
+	#[pallet::storage]
+	pub(super) type ProposalTokenDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, AssetBalanceOf<T>>;
+
+	/// The snapshot of the DAO token taken at the creation of a proposal, which determines the
+	/// voting power.
+	#[pallet::storage]
+	pub(super) type ProposalSnapshots<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, SnapshotId>;
+
+	/// The proposals created under quadratic voting, and whether they count verified persons only.
+	#[pallet::storage]
+	pub(super) type QuadraticProposals<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, bool>;
+
+	/// The account a person has voted with on a proposal counting verified persons only.
+	#[pallet::storage]
+	pub(super) type PersonVotes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::ProposalId,
+		Blake2_128Concat,
+		PersonIdOf<T>,
+		AccountIdOf<T>,
+	>;
+
+	/// The options of multiple-choice proposals.
+	#[pallet::storage]
+	pub(super) type ProposalOptions<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, MultipleChoice>;
+
+	/// The ranking of the options an account voted for on a multiple-choice proposal, alongside
+	/// with its voting power.
+	#[pallet::storage]
+	pub(super) type OptionVotes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::ProposalId,
+		Twox64Concat,
+		AccountIdOf<T>,
+		(RankingOf<T>, AssetBalanceOf<T>),
+	>;
+
+	/// The voting power per option of a multiple-choice proposal. While the proposal is running
+	/// these are the first preferences, once finalized the tallies of the final round.
+	#[pallet::storage]
+	pub(super) type OptionTallies<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, TalliesOf<T>, ValueQuery>;
+
+	/// The voting power per distinct ranking of a ranked-choice proposal, counted again in every
+	/// round of the runoff.
+	#[pallet::storage]
+	pub(super) type RankingTallies<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::ProposalId,
+		Blake2_128Concat,
+		RankingOf<T>,
+		AssetBalanceOf<T>,
+	>;
+
+	/// The winning option of a finalized multiple-choice proposal.
+	#[pallet::storage]
+	pub(super) type ProposalWinners<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, OptionIndex>;
+
+	/// The running proposals indexed by the first block they can be finalized at.
+	#[pallet::storage]
+	pub(super) type ProposalEnds<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::ProposalId, T::MaxProposalsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The first block whose ending proposals have not all been finalized automatically yet.
+	#[pallet::storage]
+	pub(super) type NextFinalizationBlock<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+	/// The account each account has delegated its voting power in a DAO to.
+	#[pallet::storage]
+	pub(super) type Delegations<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, DaoIdOf<T>, Twox64Concat, AccountIdOf<T>, AccountIdOf<T>>;
+
+	/// The accounts that have delegated their voting power in a DAO to an account, alongside
+	/// with the latest snapshot of the DAO token taken before they delegated.
+	#[pallet::storage]
+	pub(super) type Delegators<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DaoIdOf<T>,
+		Twox64Concat,
+		AccountIdOf<T>,
+		BoundedVec<(AccountIdOf<T>, SnapshotId), T::MaxDelegators>,
+		ValueQuery,
+	>;
+
+	/// The delegators whose voting power has been counted for the vote of a delegate on a
+	/// proposal, alongside with their voting power.
+	#[pallet::storage]
+	pub(super) type DelegatedVotes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::ProposalId,
+		Twox64Concat,
+		AccountIdOf<T>,
+		BoundedVec<(AccountIdOf<T>, AssetBalanceOf<T>), T::MaxDelegators>,
+		ValueQuery,
+	>;
+
+	/// The delegate whose vote on a proposal counts the voting power of a delegator.
+	#[pallet::storage]
+	pub(super) type DelegatedTo<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::ProposalId,
+		Twox64Concat,
+		AccountIdOf<T>,
+		AccountIdOf<T>,
+	>;
 
 	/// Internal incrementor of all proposals created by this module.
 	#[pallet::storage]
 	#[pallet::getter(fn get_current_proposal_id)]
 	pub type CurrentProposalId<T: Config> = StorageValue<_, T::ProposalId, ValueQuery>;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -93,6 +287,66 @@ pub mod pallet {
 			+ One
 			+ Saturating;
 
+		/// The aggregated origin type, able to represent a DAO acting collectively.
+		type RuntimeOrigin: From<pallet_dao_core::Origin<Self>>;
+
+		/// The aggregated call type proposals may carry.
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// The maximum number of calls a proposal may carry.
+		#[pallet::constant]
+		type MaxCalls: Get<u32>;
+
+		/// The maximum total weight of the calls of a proposal.
+		#[pallet::constant]
+		type MaxProposalWeight: Get<Weight>;
+
+		/// The number of blocks after which a proposal slot without metadata can be expired.
+		#[pallet::constant]
+		type ProposalSlotExpiry: Get<Self::BlockNumber>;
+
+		/// The number of blocks after its creation a finalized proposal can be pruned.
+		#[pallet::constant]
+		type ProposalRetentionPeriod: Get<Self::BlockNumber>;
+
+		/// The part of the proposal deposit paid to whoever prunes the proposal.
+		#[pallet::constant]
+		type PruningReward: Get<DepositBalanceOf<Self>>;
+
+		/// What happens to the DAO token deposit of a faulty proposal.
+		#[pallet::constant]
+		type FaultyDepositSlash: Get<DepositSlash>;
+
+		/// The maximum number of proposals that may end in the same block.
+		#[pallet::constant]
+		type MaxProposalsPerBlock: Get<u32>;
+
+		/// The maximum number of accounts that may delegate their voting power in a DAO to the
+		/// same account.
+		#[pallet::constant]
+		type MaxDelegators: Get<u32>;
+
+		/// The deposit reserved from an account for as long as it delegates its voting power in
+		/// a DAO.
+		#[pallet::constant]
+		type DelegationDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// The maximum number of options of a multiple-choice proposal. Ranked-choice votes are
+		/// tallied per distinct ranking, whose number grows factorially with the options.
+		#[pallet::constant]
+		type MaxOptions: Get<u32>;
+
+		/// Verifies the persons behind accounts for quadratic voting with one account per person.
+		type Personhood: Personhood<AccountIdOf<Self>>;
+
+		/// Calls into the contracts DAOs registered as their voting strategy.
+		type VotingStrategy: VotingStrategy<AccountIdOf<Self>, AssetBalanceOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -108,26 +362,75 @@ pub mod pallet {
 		ProposalMetadataSet {
 			proposal_id: T::ProposalId,
 		},
+		ProposalCallsSet {
+			proposal_id: T::ProposalId,
+		},
+		ProposalCallDispatched {
+			proposal_id: T::ProposalId,
+			index: u32,
+			result: DispatchResult,
+		},
+		ProposalCancelled {
+			proposal_id: T::ProposalId,
+		},
+		ProposalSlotExpired {
+			proposal_id: T::ProposalId,
+		},
 		ProposalFaulted {
 			proposal_id: T::ProposalId,
 			reason: Vec<u8>,
 		},
+		/// A council member approved faulting a proposal, which needs further approvals.
+		ProposalFaultApproved {
+			proposal_id: T::ProposalId,
+			member: T::AccountId,
+		},
 		ProposalAccepted {
 			proposal_id: T::ProposalId,
 		},
 		ProposalRejected {
 			proposal_id: T::ProposalId,
 		},
+		ProposalTokenDepositReturned {
+			proposal_id: T::ProposalId,
+			creator: AccountIdOf<T>,
+			amount: AssetBalanceOf<T>,
+		},
+		ProposalTokenDepositBurned {
+			proposal_id: T::ProposalId,
+			creator: AccountIdOf<T>,
+			amount: AssetBalanceOf<T>,
+		},
+		ProposalTokenDepositSentToTreasury {
+			proposal_id: T::ProposalId,
+			creator: AccountIdOf<T>,
+			amount: AssetBalanceOf<T>,
+		},
 		ProposalCounting {
 			proposal_id: T::ProposalId,
 		},
 		ProposalImplemented {
 			proposal_id: T::ProposalId,
 		},
+		/// A council member approved marking a proposal as implemented, which needs further
+		/// approvals.
+		ProposalImplementationApproved {
+			proposal_id: T::ProposalId,
+			member: T::AccountId,
+		},
+		ProposalPruned {
+			proposal_id: T::ProposalId,
+		},
 		VoteCast {
 			proposal_id: T::ProposalId,
 			voter: AccountIdOf<T>,
-			in_favor: Option<bool>,
+			vote: Option<Vote>,
+			conviction: Option<Conviction>,
+		},
+		VotingTokensUnlocked {
+			dao_id: DaoIdOf<T>,
+			who: AccountIdOf<T>,
+			amount: AssetBalanceOf<T>,
 		},
 		SetGovernanceMajorityVote {
 			dao_id: DaoIdOf<T>,
@@ -135,6 +438,56 @@ pub mod pallet {
 			proposal_token_deposit: T::Balance,
 			minimum_majority_per_1024: u8,
 		},
+		SetGovernanceQuorumVote {
+			dao_id: DaoIdOf<T>,
+			proposal_duration: u32,
+			proposal_token_deposit: T::Balance,
+			min_turnout_per_1024: u16,
+			approval_threshold_per_1024: u16,
+		},
+		SetGovernanceSupermajorityVote {
+			dao_id: DaoIdOf<T>,
+			proposal_duration: u32,
+			proposal_token_deposit: T::Balance,
+			min_approval_per_1024: u16,
+		},
+		SetGovernanceQuadraticVote {
+			dao_id: DaoIdOf<T>,
+			proposal_duration: u32,
+			proposal_token_deposit: T::Balance,
+			approval_threshold_per_1024: u16,
+			personhood: bool,
+		},
+		SetGovernanceContractVote {
+			dao_id: DaoIdOf<T>,
+			proposal_duration: u32,
+			proposal_token_deposit: T::Balance,
+			contract: AccountIdOf<T>,
+		},
+		VoteDelegated {
+			dao_id: DaoIdOf<T>,
+			delegator: AccountIdOf<T>,
+			delegate: AccountIdOf<T>,
+		},
+		VoteUndelegated {
+			dao_id: DaoIdOf<T>,
+			delegator: AccountIdOf<T>,
+		},
+		ProposalOptionsSet {
+			proposal_id: T::ProposalId,
+			options: OptionIndex,
+			tallying: Tallying,
+		},
+		OptionVoteCast {
+			proposal_id: T::ProposalId,
+			voter: AccountIdOf<T>,
+			ranking: Vec<OptionIndex>,
+		},
+		OptionProposalDecided {
+			proposal_id: T::ProposalId,
+			winner: Option<OptionIndex>,
+			tallies: Vec<AssetBalanceOf<T>>,
+		},
 	}
 
 	#[pallet::error]
@@ -148,13 +501,52 @@ pub mod pallet {
 		ProposalDurationHasPassed,
 		SenderIsNotDaoOwner,
 		SenderIsNotProposalCreator,
+		TooManyCalls,
+		CallsTooHeavy,
+		ThresholdAbove1024,
+		CannotDelegateToSelf,
+		TooManyDelegators,
+		NotDelegating,
+		TooManyProposalsEnding,
+		ProposalSlotNotExpired,
+		NoVotingLock,
+		VotingLockNotExpired,
+		InvalidOptions,
+		InvalidRanking,
+		ProposalHasOptions,
+		ProposalHasNoOptions,
+		ProposalHasCalls,
+		VoterNotVerified,
+		PersonHasVoted,
+		ProposalStillRunning,
+		ProposalRetentionHasNotPassed,
+		ProposalAlreadyPruned,
+		NotAContract,
+		PersonhoodUnavailable,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::finalize_ended_proposals(n, remaining_weight)
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_proposal())]
-		pub fn create_proposal(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::create_proposal().saturating_add(
+				metadata.as_ref().map_or(Weight::zero(), |(meta, _)| {
+					<T as pallet::Config>::WeightInfo::set_metadata(meta.len() as u32)
+				})
+			)
+		)]
+		pub fn create_proposal(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			metadata: Option<(Vec<u8>, Vec<u8>)>,
+		) -> DispatchResult {
 			let sender = ensure_signed(origin.clone())?;
 			let dao = pallet_dao_core::Pallet::<T>::load_dao(dao_id)?;
 			let dao_id = dao.id;
@@ -168,34 +560,52 @@ pub mod pallet {
 			CurrencyOf::<T>::reserve(&sender, deposit)?;
 
 			// reserve DAO token, but unreserve currency if that fails
-			if let Err(error) = pallet_dao_assets::Pallet::<T>::do_reserve(
+			let token_deposit = match pallet_dao_assets::Pallet::<T>::do_reserve(
 				asset_id.into(),
 				&sender,
 				governance.proposal_token_deposit,
 			) {
-				CurrencyOf::<T>::unreserve(&sender, deposit);
-				Err(error)?;
+				Ok(token_deposit) => token_deposit,
+				Err(error) => {
+					CurrencyOf::<T>::unreserve(&sender, deposit);
+					Err(error)?
+				},
 			};
 			// increase proposal counter
 			<CurrentProposalId<T>>::mutate(|id| id.saturating_inc());
+			let proposal_id = Self::get_current_proposal_id();
+			<ProposalTokenDeposits<T>>::insert(proposal_id, token_deposit);
+			<ProposalSnapshots<T>>::insert(proposal_id, Assets::<T>::snapshot(asset_id.into())?);
+			// the weighting of votes is fixed for the lifetime of the proposal
+			if let Voting::Quadratic { personhood, .. } = governance.voting {
+				<QuadraticProposals<T>>::insert(proposal_id, personhood);
+			}
 
 			// store a proposal slot
-			<ProposalSlots<T>>::insert(
-				Self::get_current_proposal_id(),
-				ProposalSlot { dao_id: dao_id.clone(), creator: sender.clone() },
-			);
+			let slot = ProposalSlot {
+				dao_id: dao_id.clone(),
+				creator: sender.clone(),
+				birth_block: <frame_system::Pallet<T>>::block_number(),
+			};
+			<ProposalSlots<T>>::insert(proposal_id, slot.clone());
+			<DaoProposals<T>>::insert(&dao_id, proposal_id, ());
 			// emit an event
 			Self::deposit_event(Event::<T>::ProposalCreated {
 				dao_id,
 				creator: sender,
-				proposal_id: Self::get_current_proposal_id(),
+				proposal_id,
 			});
 
+			// turn the slot into a proposal right away if the metadata is given
+			if let Some((meta, hash)) = metadata {
+				Self::do_set_metadata(proposal_id, slot, meta, hash)?;
+			}
+
 			Ok(())
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(0)] //<T as pallet::Config>::WeightInfo::create_proposal())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_metadata(meta.len() as u32))]
 		pub fn set_metadata(
 			origin: OriginFor<T>,
 			proposal_id: T::ProposalId,
@@ -208,30 +618,45 @@ pub mod pallet {
 				ProposalSlots::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
 			ensure!(sender == slot.creator, Error::<T>::SenderIsNotProposalCreator);
 
-			let meta: BoundedVec<_, _> =
-				meta.try_into().map_err(|_| DaoError::<T>::MetadataInvalidLengthTooLong)?;
-			let hash: BoundedVec<_, _> =
-				hash.try_into().map_err(|_| DaoError::<T>::HashInvalidWrongLength)?;
+			Self::do_set_metadata(proposal_id, slot, meta, hash)
+		}
 
-			let birth_block = <frame_system::Pallet<T>>::block_number();
-			// store the proposal
-			ProposalSlots::<T>::remove(proposal_id);
-			Proposals::<T>::insert(
-				proposal_id,
-				Proposal {
-					dao_id: slot.dao_id,
-					creator: sender,
-					birth_block,
-					status: ProposalStatus::Running,
-					in_favor: Zero::zero(),
-					against: Zero::zero(),
-					meta,
-					meta_hash: hash,
-				},
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_proposal())]
+		pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: T::ProposalId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let slot =
+				ProposalSlots::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(sender == slot.creator, Error::<T>::SenderIsNotProposalCreator);
+
+			Self::remove_proposal_slot(proposal_id, slot)?;
+
+			Self::deposit_event(Event::<T>::ProposalCancelled { proposal_id });
+			Ok(())
+		}
+
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::expire_proposal_slot())]
+		pub fn expire_proposal_slot(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let slot =
+				ProposalSlots::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+			// check that the metadata has not been set in time
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() - slot.birth_block >
+					T::ProposalSlotExpiry::get(),
+				Error::<T>::ProposalSlotNotExpired
 			);
 
-			// emit an event
-			Self::deposit_event(Event::<T>::ProposalMetadataSet { proposal_id });
+			Self::remove_proposal_slot(proposal_id, slot)?;
+
+			Self::deposit_event(Event::<T>::ProposalSlotExpired { proposal_id });
 			Ok(())
 		}
 
@@ -245,162 +670,226 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 
 			// check that a proposal exists with the given id
-			let mut proposal =
+			let proposal =
 				<Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
 
-			// check that sender is owner of the DAO
-			ensure!(
-				sender == Core::<T>::get_dao(&proposal.dao_id).expect("DAO exists").owner,
-				Error::<T>::SenderIsNotDaoOwner
-			);
-
-			proposal.status = ProposalStatus::Faulty;
-			<Proposals<T>>::insert(proposal_id, proposal.clone());
-
-			// unreserve currency
-			CurrencyOf::<T>::unreserve(&proposal.creator, <T as Config>::ProposalDeposit::get());
-
-			Self::deposit_event(Event::<T>::ProposalFaulted { proposal_id, reason });
+			// check that sender is owner of the DAO, or its council approved
+			let dao = Core::<T>::get_dao(&proposal.dao_id).ok_or(DaoError::<T>::DaoDoesNotExist)?;
+			let action = Call::<T>::fault_proposal { proposal_id, reason: reason.clone() };
+			if !Self::authorize_owner_action(&sender, &dao, &action)? {
+				Self::deposit_event(Event::<T>::ProposalFaultApproved {
+					proposal_id,
+					member: sender,
+				});
+				return Ok(())
+			}
 
+			Self::do_fault_proposal(proposal_id, proposal, reason, true);
 			Ok(())
 		}
 
+		/// Finalize a proposal whose voting period has ended: the votes are counted and the
+		/// proposal is accepted or rejected accordingly, dispatching its calls if it is accepted.
+		///
+		/// - `proposal_id`: the id of the proposal
+		///
+		/// Anyone can finalize a proposal, proposals that haven't been finalized by a TX are
+		/// finalized when blocks have weight to spare. The signer of this TX pays for the
+		/// dispatched calls, which are dispatched by the DAO regardless of the signer.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::finalize_proposal(T::HistoryHorizon::get()))]
+		#[pallet::weight(
+			Pallet::<T>::max_finalize_weight().saturating_add(T::MaxProposalWeight::get())
+		)]
 		pub fn finalize_proposal(
 			origin: OriginFor<T>,
 			proposal_id: T::ProposalId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let weight = Self::do_finalize_proposal(proposal_id)?;
+			Ok(Some(weight).into())
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::vote(T::MaxDelegators::get()))]
+		pub fn vote(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			vote: Option<Vote>,
+			conviction: Option<Conviction>,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
+			let voter = ensure_signed(origin)?;
 
 			// check that a proposal exists with the given id
 			let mut proposal =
 				<Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
-
-			// check that the proposal is currently running
+			let governance = Self::ensure_voting_open(&proposal)?;
 			ensure!(
-				proposal.status == ProposalStatus::Running,
-				Error::<T>::ProposalStatusNotRunning
+				!<ProposalOptions<T>>::contains_key(proposal_id),
+				Error::<T>::ProposalHasOptions
 			);
-			let governance =
-				<Governances<T>>::get(&proposal.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
-			let current_block = <frame_system::Pallet<T>>::block_number();
 
-			// check that the proposal has run for its entire duration
-			ensure!(
-				current_block - proposal.birth_block > governance.proposal_duration.into(),
-				Error::<T>::ProposalDurationHasNotPassed
-			);
+			let quadratic = <QuadraticProposals<T>>::get(proposal_id);
+			if quadratic == Some(true) {
+				Self::record_person_vote(proposal_id, &voter, vote.is_some())?;
+			}
 
-			let asset_id = Core::<T>::get_dao(&proposal.dao_id)
-				.expect("DAO exists")
-				.asset_id
-				.expect("asset has been issued");
+			let previous_vote = <Votes<T>>::get(proposal_id, &voter);
+			let vote_conviction = <VoteConvictions<T>>::get(proposal_id, &voter);
+			// a conviction without a vote in favor or against has no effect
+			let conviction = vote.filter(|vote| *vote != Vote::Abstain).and(conviction);
+			if previous_vote != vote || vote_conviction != conviction {
+				<Votes<T>>::set(proposal_id, &voter, vote);
+				<VoteConvictions<T>>::set(proposal_id, &voter, conviction);
+				let asset_id = Core::<T>::get_dao(&proposal.dao_id)
+					.ok_or(DaoError::<T>::DaoDoesNotExist)?
+					.asset_id
+					.ok_or(Error::<T>::DaoTokenNotYetIssued)?;
+				let birth_block = proposal.birth_block;
+				let snapshot_id = <ProposalSnapshots<T>>::get(proposal_id)
+					.ok_or(AssetError::<T>::SnapshotUnavailable)?;
+				// the balance at the snapshot includes reserved tokens and tokens locked by vesting
+				let token_balance_of = |who: &AccountIdOf<T>| {
+					Assets::<T>::total_balance_at(asset_id.into(), who, snapshot_id)
+						.ok_or(AssetError::<T>::SnapshotUnavailable)
+				};
+				let voting_power = |who: &AccountIdOf<T>| {
+					token_balance_of(who)
+						.map(|balance| Self::weigh_voting_power(quadratic.is_some(), balance))
+				};
+				let token_balance = token_balance_of(&voter)?;
+				let power = voting_power(&voter)?;
+				let with_conviction = |conviction: Option<Conviction>| {
+					conviction.map_or(power, |conviction| {
+						power.saturating_mul(conviction.multiplier().into())
+					})
+				};
+
+				// lock the voting tokens until the end of the conviction period
+				if let Some(conviction) = conviction {
+					let proposal_end = birth_block + governance.proposal_duration.into();
+					let lock_duration =
+						governance.proposal_duration.saturating_mul(conviction.lock_periods());
+					Self::lock_voting_tokens(
+						&proposal.dao_id,
+						asset_id.into(),
+						&voter,
+						token_balance,
+						proposal_end + lock_duration.into(),
+					)?;
+				}
 
-			// determine whether proposal has required votes and set status accordingly
-			match governance.voting {
-				Voting::Majority { minimum_majority_per_1024 } => {
-					if proposal.in_favor > proposal.against && {
-						let token_supply = Assets::<T>::total_historical_supply(
-							asset_id.into(),
-							proposal.birth_block,
-						)
-						.expect("History exists (horizon checked above)");
-						let required_majority = token_supply /
-							Into::<AssetBalanceOf<T>>::into(1024_u32) *
-							minimum_majority_per_1024.into();
-						// check for the required majority
-						proposal.in_favor - proposal.against >= required_majority
-					} {
-						proposal.status = ProposalStatus::Accepted;
-					} else {
-						proposal.status = ProposalStatus::Rejected;
-					}
-				},
-			}
-			// unreserve proposal deposit
-			CurrencyOf::<T>::unreserve(&sender, <T as Config>::ProposalDeposit::get());
+				// a direct vote replaces the vote of the delegate on behalf of the voter
+				if let Some(delegate) = <DelegatedTo<T>>::take(proposal_id, &voter) {
+					<DelegatedVotes<T>>::mutate(proposal_id, &delegate, |delegated| {
+						delegated.retain(|(delegator, _)| *delegator != voter)
+					});
+					let delegate_vote = <Votes<T>>::get(proposal_id, &delegate);
+					Self::uncount_vote(&mut proposal, delegate_vote, power);
+				}
 
-			// record updated proposal status
-			<Proposals<T>>::insert(proposal_id, proposal.clone());
+				// undo old vote, including the voting power delegated to the voter
+				let delegated = <DelegatedVotes<T>>::take(proposal_id, &voter);
+				for (delegator, power) in delegated {
+					<DelegatedTo<T>>::remove(proposal_id, &delegator);
+					Self::uncount_vote(&mut proposal, previous_vote, power);
+				}
+				Self::uncount_vote(&mut proposal, previous_vote, with_conviction(vote_conviction));
+
+				// count new vote, including the voting power of delegators that did not vote,
+				// unless only the votes of verified persons count
+				if vote.is_some() && quadratic != Some(true) {
+					let mut delegated = BoundedVec::<_, T::MaxDelegators>::default();
+					for (delegator, since) in <Delegators<T>>::get(&proposal.dao_id, &voter) {
+						// delegations made after the snapshot of the proposal do not count
+						if since >= snapshot_id ||
+							<Votes<T>>::contains_key(proposal_id, &delegator) ||
+							<DelegatedTo<T>>::contains_key(proposal_id, &delegator)
+						{
+							continue
+						}
+						let power = voting_power(&delegator)?;
+						// cannot fail, there are never more delegations than delegators
+						let _ = delegated.try_push((delegator.clone(), power));
+						<DelegatedTo<T>>::insert(proposal_id, &delegator, &voter);
+						Self::count_vote(&mut proposal, vote, power);
+					}
+					<DelegatedVotes<T>>::insert(proposal_id, &voter, delegated);
+				}
+				Self::count_vote(&mut proposal, vote, with_conviction(conviction));
 
-			// emit event
-			Self::deposit_event(match proposal.status {
-				ProposalStatus::Accepted => Event::ProposalAccepted { proposal_id },
-				ProposalStatus::Rejected => Event::ProposalRejected { proposal_id },
-				_ => unreachable!(),
-			});
+				// record updated proposal counts
+				<Proposals<T>>::insert(proposal_id, proposal);
+			}
 
+			Self::deposit_event(Event::<T>::VoteCast { proposal_id, voter, vote, conviction });
 			Ok(())
 		}
 
-		#[pallet::call_index(4)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::vote())]
-		pub fn vote(
+		/// Vote on a multiple-choice proposal by ranking its options, most preferred first.
+		///
+		/// The voting power is counted for the first ranked option that has not been eliminated.
+		/// Delegations and convictions do not apply to votes on multiple-choice proposals.
+		///
+		/// - `proposal_id`: the id of the proposal
+		/// - `ranking`: distinct options, a single one for plurality tallying, empty to remove
+		///   the vote
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::vote_options())]
+		pub fn vote_options(
 			origin: OriginFor<T>,
 			proposal_id: T::ProposalId,
-			in_favor: Option<bool>,
+			ranking: Vec<OptionIndex>,
 		) -> DispatchResult {
 			let voter = ensure_signed(origin)?;
 
-			// check that a proposal exists with the given id
-			let mut proposal =
+			let proposal =
 				<Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			Self::ensure_voting_open(&proposal)?;
+			let choice =
+				<ProposalOptions<T>>::get(proposal_id).ok_or(Error::<T>::ProposalHasNoOptions)?;
+			let ranking = Self::validate_ranking(choice, ranking)?;
+			if <QuadraticProposals<T>>::get(proposal_id) == Some(true) {
+				Self::record_person_vote(proposal_id, &voter, !ranking.is_empty())?;
+			}
 
-			// check that the proposal is running
-			ensure!(
-				proposal.status == ProposalStatus::Running,
-				Error::<T>::ProposalStatusNotRunning
-			);
+			// undo old vote, then count the new one
+			if let Some((previous, power)) = <OptionVotes<T>>::take(proposal_id, &voter) {
+				Self::uncount_ranking(proposal_id, choice, &previous, power);
+			}
+			if !ranking.is_empty() {
+				let power = Self::voting_power(proposal_id, &voter)
+					.ok_or(AssetError::<T>::SnapshotUnavailable)?;
+				Self::count_ranking(proposal_id, choice, &ranking, power);
+				<OptionVotes<T>>::insert(proposal_id, &voter, (ranking.clone(), power));
+			}
 
-			let governance =
-				<Governances<T>>::get(&proposal.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
+			Self::deposit_event(Event::<T>::OptionVoteCast {
+				proposal_id,
+				voter,
+				ranking: ranking.into_inner(),
+			});
+			Ok(())
+		}
+
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unlock())]
+		pub fn unlock(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let dao = Core::<T>::load_dao(dao_id)?;
+			let dao_id = dao.id;
 
-			// check that the proposal has not yet run for its entire duration
+			let lock = <VotingLocks<T>>::get(&dao_id, &who).ok_or(Error::<T>::NoVotingLock)?;
 			ensure!(
-				<frame_system::Pallet<T>>::block_number() - proposal.birth_block <=
-					governance.proposal_duration.into(),
-				Error::<T>::ProposalDurationHasPassed
+				<frame_system::Pallet<T>>::block_number() > lock.until,
+				Error::<T>::VotingLockNotExpired
 			);
 
-			let vote = <Votes<T>>::get(proposal_id, &voter);
-			if vote != in_favor {
-				<Votes<T>>::set(proposal_id, &voter, in_favor);
-				let asset_id = Core::<T>::get_dao(&proposal.dao_id)
-					.expect("DAO exists")
-					.asset_id
-					.expect("asset has been issued");
-				let token_balance = Assets::<T>::total_historical_balance(
-					asset_id.into(),
-					&voter,
-					proposal.birth_block,
-				)
-				.expect("history exists");
-				// undo old vote
-				match vote {
-					Some(true) => {
-						proposal.in_favor -= token_balance;
-					},
-					Some(false) => {
-						proposal.against -= token_balance;
-					},
-					None => {},
-				}
-				// count new vote
-				match in_favor {
-					Some(true) => {
-						proposal.in_favor += token_balance;
-					},
-					Some(false) => {
-						proposal.against += token_balance;
-					},
-					None => {},
-				}
-				// record updated proposal counts
-				<Proposals<T>>::insert(proposal_id, proposal);
-			}
+			let asset_id = dao.asset_id.ok_or(Error::<T>::DaoTokenNotYetIssued)?;
+			let amount = Assets::<T>::do_unreserve(asset_id.into(), &who, lock.amount)?;
+			<VotingLocks<T>>::remove(&dao_id, &who);
 
-			Self::deposit_event(Event::<T>::VoteCast { proposal_id, voter, in_favor });
+			Self::deposit_event(Event::<T>::VotingTokensUnlocked { dao_id, who, amount });
 			Ok(())
 		}
 
@@ -413,13 +902,14 @@ pub mod pallet {
 			proposal_token_deposit: T::Balance,
 			minimum_majority_per_1024: u8,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-			let dao = pallet_dao_core::Pallet::<T>::load_dao(dao_id)?;
-			let dao_id = dao.id;
-			ensure!(dao.owner == sender, DaoError::<T>::DaoSignerNotOwner);
 			let voting = Voting::Majority { minimum_majority_per_1024 };
-			let gov = GovernanceOf::<T> { proposal_duration, proposal_token_deposit, voting };
-			<Governances<T>>::set(dao_id.clone(), Some(gov));
+			let dao_id = Self::set_governance(
+				origin,
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				voting,
+			)?;
 			Self::deposit_event(Event::<T>::SetGovernanceMajorityVote {
 				dao_id,
 				proposal_duration,
@@ -429,32 +919,1363 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::call_index(7)]
-		//#[pallet::weight(<T as pallet::Config>::WeightInfo::mark_implemented())]
-		pub fn mark_implemented(
+		/// Configure quorum voting: a proposal needs a minimum turnout and a share of ayes
+		/// among the cast votes.
+		///
+		/// - `min_turnout_per_1024`: the share of the token supply that needs to vote
+		/// - `approval_threshold_per_1024`: the share of the cast votes that needs to be
+		///   exceeded by the ayes
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_governance_quorum_vote())]
+		pub fn set_governance_quorum_vote(
 			origin: OriginFor<T>,
-			proposal_id: T::ProposalId,
+			dao_id: Vec<u8>,
+			proposal_duration: u32,
+			proposal_token_deposit: T::Balance,
+			min_turnout_per_1024: u16,
+			approval_threshold_per_1024: u16,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-
-			<Proposals<T>>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
-				let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalDoesNotExist)?;
-				let dao = pallet_dao_core::Daos::<T>::get(&proposal.dao_id)
-					.ok_or(DaoError::<T>::DaoDoesNotExist)?;
-				ensure!(dao.owner == sender, DaoError::<T>::DaoSignerNotOwner);
-
-				// check that the proposal has been accepted
-				ensure!(
-					proposal.status == ProposalStatus::Accepted,
-					Error::<T>::ProposalStatusNotAccepted
-				);
+			ensure!(
+				min_turnout_per_1024 <= 1024 && approval_threshold_per_1024 <= 1024,
+				Error::<T>::ThresholdAbove1024
+			);
+			let voting = Voting::Quorum { min_turnout_per_1024, approval_threshold_per_1024 };
+			let dao_id = Self::set_governance(
+				origin,
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				voting,
+			)?;
+			Self::deposit_event(Event::<T>::SetGovernanceQuorumVote {
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				min_turnout_per_1024,
+				approval_threshold_per_1024,
+			});
+			Ok(())
+		}
 
-				proposal.status = ProposalStatus::Implemented;
-				Ok(())
-			})?;
+		/// Configure supermajority voting: the ayes of a proposal need to reach a share of the
+		/// whole token supply.
+		///
+		/// - `min_approval_per_1024`: the share of the token supply that needs to vote aye
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_governance_supermajority_vote())]
+		pub fn set_governance_supermajority_vote(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			proposal_duration: u32,
+			proposal_token_deposit: T::Balance,
+			min_approval_per_1024: u16,
+		) -> DispatchResult {
+			ensure!(min_approval_per_1024 <= 1024, Error::<T>::ThresholdAbove1024);
+			let voting = Voting::Supermajority { min_approval_per_1024 };
+			let dao_id = Self::set_governance(
+				origin,
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				voting,
+			)?;
+			Self::deposit_event(Event::<T>::SetGovernanceSupermajorityVote {
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				min_approval_per_1024,
+			});
+			Ok(())
+		}
+
+		/// Configure quadratic voting: every voter weighs in with the integer square root of its
+		/// token balance and the ayes of a proposal need to reach a share of the cast votes.
+		///
+		/// - `approval_threshold_per_1024`: the share of the cast votes that needs to be
+		///   exceeded by the ayes
+		/// - `personhood`: whether only accounts verified to belong to a person can vote, one
+		///   account per person, which requires the runtime to configure a _Personhood_
+		///
+		/// Without personhood, a holder can split its balance B across N accounts and weigh in
+		/// with √(N·B) instead of √B, so the weighting only favors small holders among voters that
+		/// don't split their balance.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_governance_quadratic_vote())]
+		pub fn set_governance_quadratic_vote(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			proposal_duration: u32,
+			proposal_token_deposit: T::Balance,
+			approval_threshold_per_1024: u16,
+			personhood: bool,
+		) -> DispatchResult {
+			ensure!(approval_threshold_per_1024 <= 1024, Error::<T>::ThresholdAbove1024);
+			ensure!(
+				!personhood || T::Personhood::is_available(),
+				Error::<T>::PersonhoodUnavailable
+			);
+			let voting = Voting::Quadratic { approval_threshold_per_1024, personhood };
+			let dao_id = Self::set_governance(
+				origin,
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				voting,
+			)?;
+			Self::deposit_event(Event::<T>::SetGovernanceQuadraticVote {
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				approval_threshold_per_1024,
+				personhood,
+			});
+			Ok(())
+		}
+
+		/// Configure contract voting: an ink! contract decides whether a proposal is accepted
+		/// given its ayes, nays and abstentions alongside with the token supply. A proposal is
+		/// rejected if the call into the contract fails.
+		///
+		/// - `contract`: the address of the contract, see `VotingStrategy`
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_governance_contract_vote())]
+		pub fn set_governance_contract_vote(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			proposal_duration: u32,
+			proposal_token_deposit: T::Balance,
+			contract: AccountIdOf<T>,
+		) -> DispatchResult {
+			ensure!(T::VotingStrategy::is_contract(&contract), Error::<T>::NotAContract);
+			let voting = Voting::Contract { address: contract.clone() };
+			let dao_id = Self::set_governance(
+				origin,
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				voting,
+			)?;
+			Self::deposit_event(Event::<T>::SetGovernanceContractVote {
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				contract,
+			});
+			Ok(())
+		}
+
+		/// Delegate the voting power in a DAO to another account.
+		///
+		/// The voting power of the delegator is counted for the vote of the delegate, unless the
+		/// delegator votes directly. Delegations are taken into account when the delegate votes
+		/// on proposals created after the delegation, like the voting power is taken at the
+		/// creation of a proposal.
+		///
+		/// The _DelegationDeposit_ is reserved from the delegator until the delegation is revoked,
+		/// moving a delegation keeps the deposit reserved.
+		///
+		/// - `dao_id`: the unique identifier for the DAO
+		/// - `delegate`: the account to delegate the voting power to
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			delegate: AccountIdOf<T>,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			ensure!(delegator != delegate, Error::<T>::CannotDelegateToSelf);
+			let dao = Core::<T>::load_dao(dao_id)?;
+			let dao_id = dao.id;
+			ensure!(
+				!pallet_dao_core::DestroyingDaos::<T>::contains_key(&dao_id),
+				DaoError::<T>::DaoIsBeingDestroyed
+			);
+
+			let previous = <Delegations<T>>::get(&dao_id, &delegator);
+			if let Some(previous) = &previous {
+				Self::remove_delegator(&dao_id, previous, &delegator);
+			}
+			let since = dao
+				.asset_id
+				.map_or(0, |asset_id| Assets::<T>::current_snapshot_id(asset_id.into()));
+			<Delegators<T>>::try_mutate(&dao_id, &delegate, |delegators| {
+				delegators.try_push((delegator.clone(), since))
+			})
+			.map_err(|_| Error::<T>::TooManyDelegators)?;
+			if previous.is_none() {
+				CurrencyOf::<T>::reserve(&delegator, T::DelegationDeposit::get())?;
+			}
+			<Delegations<T>>::insert(&dao_id, &delegator, &delegate);
+
+			Self::deposit_event(Event::<T>::VoteDelegated { dao_id, delegator, delegate });
+			Ok(())
+		}
+
+		/// Revoke the delegation of the voting power in a DAO, returning the _DelegationDeposit_.
+		///
+		/// Votes of the delegate that already counted the voting power remain untouched until the
+		/// delegator votes directly.
+		///
+		/// - `dao_id`: the unique identifier for the DAO
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let dao_id = Core::<T>::load_dao(dao_id)?.id;
+
+			let delegate =
+				<Delegations<T>>::take(&dao_id, &delegator).ok_or(Error::<T>::NotDelegating)?;
+			Self::remove_delegator(&dao_id, &delegate, &delegator);
+			CurrencyOf::<T>::unreserve(&delegator, T::DelegationDeposit::get());
+
+			Self::deposit_event(Event::<T>::VoteUndelegated { dao_id, delegator });
+			Ok(())
+		}
+
+		#[pallet::call_index(7)]
+		//#[pallet::weight(<T as pallet::Config>::WeightInfo::mark_implemented())]
+		pub fn mark_implemented(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mut proposal =
+				<Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			let dao = pallet_dao_core::Daos::<T>::get(&proposal.dao_id)
+				.ok_or(DaoError::<T>::DaoDoesNotExist)?;
+
+			// check that the proposal has been accepted
+			ensure!(
+				proposal.status == ProposalStatus::Accepted,
+				Error::<T>::ProposalStatusNotAccepted
+			);
+
+			// check that sender is owner of the DAO, or its council approved
+			let action = Call::<T>::mark_implemented { proposal_id };
+			if !Self::authorize_owner_action(&sender, &dao, &action)? {
+				Self::deposit_event(Event::<T>::ProposalImplementationApproved {
+					proposal_id,
+					member: sender,
+				});
+				return Ok(())
+			}
+
+			proposal.status = ProposalStatus::Implemented;
+			<Proposals<T>>::insert(proposal_id, proposal);
 
 			Self::deposit_event(Event::<T>::ProposalImplemented { proposal_id });
 			Ok(())
 		}
+
+		/// Prune a finalized proposal once _ProposalRetentionPeriod_ blocks have passed since its
+		/// creation: the proposal is collapsed to its result, including a hash of its final tally,
+		/// and its votes are removed.
+		///
+		/// Up to _RemoveItemsLimit_ items are removed at a time, pruning a proposal with more votes
+		/// needs to be continued with further calls until `ProposalPruned` is emitted.
+		///
+		/// - `proposal_id`: the id of the proposal
+		///
+		/// Anyone can prune a proposal, the signer of this TX is rewarded with the _PruningReward_
+		/// kept from the deposit of the proposal.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::prune_proposal(
+			<T as pallet_dao_assets::Config>::RemoveItemsLimit::get()
+		))]
+		pub fn prune_proposal(origin: OriginFor<T>, proposal_id: T::ProposalId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let dao_id = match <Proposals<T>>::get(proposal_id) {
+				Some(proposal) => {
+					ensure!(
+						proposal.status != ProposalStatus::Running,
+						Error::<T>::ProposalStillRunning
+					);
+					ensure!(
+						<frame_system::Pallet<T>>::block_number() - proposal.birth_block >
+							T::ProposalRetentionPeriod::get(),
+						Error::<T>::ProposalRetentionHasNotPassed
+					);
+
+					let tallies = <OptionTallies<T>>::get(proposal_id);
+					let tally = (proposal.in_favor, proposal.against, proposal.abstain, tallies);
+					let result = ProposalResult {
+						dao_id: proposal.dao_id.clone(),
+						status: proposal.status,
+						tally_hash: T::Hashing::hash_of(&tally),
+					};
+					<ProposalResults<T>>::insert(proposal_id, result);
+					<Proposals<T>>::remove(proposal_id);
+					<PruningProposals<T>>::insert(proposal_id, ());
+
+					if let Some(reward) = <PruningRewards<T>>::take(proposal_id) {
+						CurrencyOf::<T>::repatriate_reserved(
+							&proposal.creator,
+							&who,
+							reward,
+							BalanceStatus::Free,
+						)?;
+					}
+					proposal.dao_id
+				},
+				// continue pruning a proposal with votes left
+				None => {
+					let result = <ProposalResults<T>>::get(proposal_id)
+						.ok_or(Error::<T>::ProposalDoesNotExist)?;
+					ensure!(
+						<PruningProposals<T>>::contains_key(proposal_id),
+						Error::<T>::ProposalAlreadyPruned
+					);
+					result.dao_id
+				},
+			};
+
+			let mut budget = <T as pallet_dao_assets::Config>::RemoveItemsLimit::get();
+			if Self::clear_proposal(proposal_id, &mut budget) {
+				<DaoProposals<T>>::remove(&dao_id, proposal_id);
+				Self::deposit_event(Event::<T>::ProposalPruned { proposal_id });
+			}
+			Ok(())
+		}
+
+		/// Set the calls to be dispatched by the DAO once the proposal is accepted.
+		///
+		/// - `proposal_id`: the proposal, which must not have its metadata set yet
+		/// - `calls`: the calls, bounded by _MaxCalls_ and _MaxProposalWeight_
+		///
+		/// Signer of this TX needs to be the creator of the proposal.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_calls(calls.encoded_size() as u32))]
+		pub fn set_calls(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			calls: Vec<CallOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let slot =
+				ProposalSlots::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(sender == slot.creator, Error::<T>::SenderIsNotProposalCreator);
+
+			ensure!(
+				!ProposalOptions::<T>::contains_key(proposal_id),
+				Error::<T>::ProposalHasOptions
+			);
+
+			let calls: CallsOf<T> = calls.try_into().map_err(|_| Error::<T>::TooManyCalls)?;
+			let weight = calls.iter().fold(Weight::zero(), |weight, call| {
+				weight.saturating_add(call.get_dispatch_info().weight)
+			});
+			ensure!(weight.all_lte(T::MaxProposalWeight::get()), Error::<T>::CallsTooHeavy);
+
+			ProposalCalls::<T>::insert(proposal_id, calls);
+
+			Self::deposit_event(Event::<T>::ProposalCallsSet { proposal_id });
+			Ok(())
+		}
+
+		/// Turn a proposal into a multiple-choice proposal, whose votes choose one of `options`
+		/// options instead of accepting or rejecting it. The options are described in the
+		/// metadata of the proposal.
+		///
+		/// - `proposal_id`: the proposal, which must not have its metadata or calls set yet
+		/// - `options`: the number of options, at least 2 and at most _MaxOptions_
+		/// - `tallying`: how the votes are tallied
+		///
+		/// Signer of this TX needs to be the creator of the proposal.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_options())]
+		pub fn set_options(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			options: OptionIndex,
+			tallying: Tallying,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let slot =
+				ProposalSlots::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(sender == slot.creator, Error::<T>::SenderIsNotProposalCreator);
+			ensure!(!ProposalCalls::<T>::contains_key(proposal_id), Error::<T>::ProposalHasCalls);
+			ensure!(
+				options >= 2 && u32::from(options) <= T::MaxOptions::get(),
+				Error::<T>::InvalidOptions
+			);
+
+			ProposalOptions::<T>::insert(proposal_id, MultipleChoice { options, tallying });
+			let tallies = vec![Zero::zero(); options.into()];
+			OptionTallies::<T>::insert(proposal_id, TalliesOf::<T>::truncate_from(tallies));
+
+			Self::deposit_event(Event::<T>::ProposalOptionsSet { proposal_id, options, tallying });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Set the governance of a DAO, returns the id of the DAO.
+	///
+	/// Origin needs to be the owner of the DAO or the DAO itself.
+	fn set_governance(
+		origin: OriginFor<T>,
+		dao_id: Vec<u8>,
+		proposal_duration: u32,
+		proposal_token_deposit: T::Balance,
+		voting: VotingOf<T>,
+	) -> Result<DaoIdOf<T>, DispatchError> {
+		let dao = Core::<T>::load_dao(dao_id)?;
+		Core::<T>::ensure_owner_or_dao(origin, &dao)?;
+		let gov = GovernanceOf::<T> { proposal_duration, proposal_token_deposit, voting };
+		<Governances<T>>::set(dao.id.clone(), Some(gov));
+		Ok(dao.id)
+	}
+
+	/// Authorize an action on a proposal restricted to the owner of its DAO, which its council can
+	/// take as well, see `authorize_owner_action` of the DAO core pallet. Returns whether the
+	/// action is authorized, `false` if approvals of the council are missing.
+	///
+	/// Fails with `SenderIsNotDaoOwner` if the sender is neither the owner nor a council member.
+	fn authorize_owner_action(
+		sender: &AccountIdOf<T>,
+		dao: &DaoOf<T>,
+		action: &Call<T>,
+	) -> Result<bool, DispatchError> {
+		Core::<T>::authorize_owner_action(sender, dao, action).map_err(|error| {
+			if error == DaoError::<T>::DaoSignerNotOwner.into() {
+				Error::<T>::SenderIsNotDaoOwner.into()
+			} else {
+				error
+			}
+		})
+	}
+
+	/// Finalize a proposal whose duration has passed: accept or reject it according to the
+	/// governance of its DAO, return the deposit to its creator and dispatch its calls if
+	/// accepted. A proposal whose snapshot is unavailable is faulted instead, without slashing its
+	/// creator. Returns the consumed weight.
+	///
+	/// - `proposal_id`: the id of the proposal
+	fn do_finalize_proposal(proposal_id: T::ProposalId) -> Result<Weight, DispatchError> {
+		// check that a proposal exists with the given id
+		let mut proposal =
+			<Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+		// check that the proposal is currently running
+		ensure!(proposal.status == ProposalStatus::Running, Error::<T>::ProposalStatusNotRunning);
+		let Some(governance) = <Governances<T>>::get(&proposal.dao_id) else {
+			// the duration of the proposal is unknown without the governance
+			let reason: &'static str = Error::<T>::GovernanceNotSet.into();
+			Self::do_fault_proposal(proposal_id, proposal, reason.as_bytes().to_vec(), false);
+			return Ok(<T as pallet::Config>::WeightInfo::fault_proposal())
+		};
+		let current_block = <frame_system::Pallet<T>>::block_number();
+
+		// check that the proposal has run for its entire duration
+		ensure!(
+			current_block - proposal.birth_block > governance.proposal_duration.into(),
+			Error::<T>::ProposalDurationHasNotPassed
+		);
+
+		let token_supply = match Self::snapshot_supply(proposal_id, &proposal.dao_id) {
+			Ok(token_supply) => token_supply,
+			// the votes cannot be weighed without the snapshot
+			Err(error) => {
+				let reason: &'static str = error.into();
+				Self::do_fault_proposal(proposal_id, proposal, reason.as_bytes().to_vec(), false);
+				return Ok(<T as pallet::Config>::WeightInfo::fault_proposal())
+			},
+		};
+		// return the DAO token deposit to the creator, the proposal cannot be decided otherwise
+		if let Err(error) =
+			Self::return_token_deposit(proposal_id, &proposal.dao_id, &proposal.creator)
+		{
+			let reason: &'static str = error.into();
+			Self::do_fault_proposal(proposal_id, proposal, reason.as_bytes().to_vec(), false);
+			return Ok(<T as pallet::Config>::WeightInfo::fault_proposal())
+		}
+
+		let mut weight = <T as pallet::Config>::WeightInfo::finalize_proposal(0);
+		let decision = <ProposalOptions<T>>::get(proposal_id).map(|choice| {
+			// the voting power cast, before ranked-choice tallying eliminates options
+			let turnout = <OptionTallies<T>>::get(proposal_id)
+				.iter()
+				.fold(AssetBalanceOf::<T>::zero(), |turnout, tally| turnout.saturating_add(*tally));
+			let (winner, tallies, tally_weight) = Self::tally_options(proposal_id, choice);
+			weight.saturating_accrue(tally_weight);
+			let winner = winner.filter(|winner| {
+				Self::has_participation(
+					&governance.voting,
+					token_supply,
+					turnout,
+					&tallies,
+					*winner,
+				)
+			});
+			if let Some(winner) = winner {
+				<ProposalWinners<T>>::insert(proposal_id, winner);
+			}
+			(winner, tallies)
+		});
+
+		// determine whether proposal has required votes and set status accordingly
+		let accepted = match &decision {
+			// a multiple-choice proposal is accepted if an option has won with the required
+			// participation
+			Some((winner, _)) => winner.is_some(),
+			None => match governance.voting {
+				Voting::Majority { minimum_majority_per_1024 } => {
+					proposal.in_favor > proposal.against && {
+						let required_majority = token_supply /
+							Into::<AssetBalanceOf<T>>::into(1024_u32) *
+							minimum_majority_per_1024.into();
+						// check for the required majority
+						proposal.in_favor - proposal.against >= required_majority
+					}
+				},
+				Voting::Quorum { min_turnout_per_1024, approval_threshold_per_1024 } => {
+					let cast = proposal.in_favor.saturating_add(proposal.against);
+					let turnout = cast.saturating_add(proposal.abstain);
+					// check for the required turnout and share of ayes among ayes and nays
+					turnout >= Self::per_1024(token_supply, min_turnout_per_1024) &&
+						proposal.in_favor > Self::per_1024(cast, approval_threshold_per_1024)
+				},
+				Voting::Supermajority { min_approval_per_1024 } => {
+					// check for the required share of the whole supply
+					proposal.in_favor > proposal.against &&
+						proposal.in_favor >= Self::per_1024(token_supply, min_approval_per_1024)
+				},
+				Voting::Quadratic { approval_threshold_per_1024, .. } => {
+					let cast = proposal.in_favor.saturating_add(proposal.against);
+					// check for the required share of ayes among ayes and nays
+					proposal.in_favor > Self::per_1024(cast, approval_threshold_per_1024)
+				},
+				Voting::Contract { address } => {
+					weight.saturating_accrue(T::VotingStrategy::max_weight());
+					let tally = Tally {
+						in_favor: proposal.in_favor,
+						against: proposal.against,
+						abstain: proposal.abstain,
+						token_supply,
+					};
+					// a failing contract rejects the proposal
+					T::VotingStrategy::accepts(&address, &tally).unwrap_or(false)
+				},
+			},
+		};
+		proposal.status =
+			if accepted { ProposalStatus::Accepted } else { ProposalStatus::Rejected };
+		Self::release_snapshot(proposal_id, &proposal.dao_id);
+		// return the proposal deposit to the creator
+		Self::release_deposit(proposal_id, &proposal.creator);
+
+		// record updated proposal status
+		<Proposals<T>>::insert(proposal_id, proposal.clone());
+
+		// emit event
+		Self::deposit_event(match (decision, proposal.status) {
+			(Some((winner, tallies)), _) => {
+				Event::OptionProposalDecided { proposal_id, winner, tallies: tallies.into_inner() }
+			},
+			(None, ProposalStatus::Accepted) => Event::ProposalAccepted { proposal_id },
+			(None, ProposalStatus::Rejected) => Event::ProposalRejected { proposal_id },
+			_ => unreachable!(),
+		});
+
+		if proposal.status == ProposalStatus::Accepted {
+			weight.saturating_accrue(Self::dispatch_calls(proposal_id, &proposal.dao_id));
+		}
+
+		Ok(weight)
+	}
+
+	/// The total supply of the DAO token at the snapshot taken for a proposal.
+	fn snapshot_supply(
+		proposal_id: T::ProposalId,
+		dao_id: &DaoIdOf<T>,
+	) -> Result<AssetBalanceOf<T>, DispatchError> {
+		let asset_id = Core::<T>::get_dao(dao_id)
+			.ok_or(DaoError::<T>::DaoDoesNotExist)?
+			.asset_id
+			.ok_or(Error::<T>::DaoTokenNotYetIssued)?;
+		let snapshot_id =
+			<ProposalSnapshots<T>>::get(proposal_id).ok_or(AssetError::<T>::SnapshotUnavailable)?;
+		Ok(Assets::<T>::total_supply_at(asset_id.into(), snapshot_id)
+			.ok_or(AssetError::<T>::SnapshotUnavailable)?)
+	}
+
+	/// Mark a running proposal as faulty, so it can neither be voted on nor finalized anymore.
+	/// The deposit is returned to its creator, the DAO token deposit is slashed if `slash` is set
+	/// and returned otherwise. The DAO token deposit stays recorded if that fails, e.g. because
+	/// the DAO token is gone, which does not prevent the fault.
+	///
+	/// - `proposal_id`: the id of the proposal
+	/// - `proposal`: the proposal
+	/// - `reason`: why the proposal is faulty
+	/// - `slash`: whether the creator is to blame for the fault
+	fn do_fault_proposal(
+		proposal_id: T::ProposalId,
+		mut proposal: ProposalOf<T>,
+		reason: Vec<u8>,
+		slash: bool,
+	) {
+		proposal.status = ProposalStatus::Faulty;
+		<Proposals<T>>::insert(proposal_id, &proposal);
+		Self::release_snapshot(proposal_id, &proposal.dao_id);
+
+		// unreserve currency
+		Self::release_deposit(proposal_id, &proposal.creator);
+		// slash or return DAO token
+		let _ = if slash {
+			Self::slash_token_deposit(proposal_id, &proposal.dao_id, &proposal.creator)
+		} else {
+			Self::return_token_deposit(proposal_id, &proposal.dao_id, &proposal.creator)
+		};
+
+		Self::deposit_event(Event::<T>::ProposalFaulted { proposal_id, reason });
+	}
+
+	/// Finalize the proposals that have ended until block `now`, as long as `remaining_weight`
+	/// suffices to finalize another proposal in the worst case. Proposals that do not fit are
+	/// finalized in a later block. Returns the consumed weight.
+	fn finalize_ended_proposals(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let max_finalize_weight = Self::max_finalize_weight()
+			.saturating_add(T::MaxProposalWeight::get())
+			.saturating_add(db_weight.reads_writes(1, 1));
+
+		let mut consumed = db_weight.reads_writes(1, 1);
+		if consumed.any_gt(remaining_weight) {
+			return Weight::zero()
+		}
+		let mut block = NextFinalizationBlock::<T>::get().unwrap_or(now);
+		while block <= now {
+			consumed.saturating_accrue(db_weight.reads_writes(1, 1));
+			if consumed.any_gt(remaining_weight) {
+				break
+			}
+			let mut ended = ProposalEnds::<T>::get(block);
+			while let Some(proposal_id) = ended.last().copied() {
+				if consumed.saturating_add(max_finalize_weight).any_gt(remaining_weight) {
+					ProposalEnds::<T>::insert(block, ended);
+					NextFinalizationBlock::<T>::put(block);
+					return consumed
+				}
+				ended.pop();
+				consumed.saturating_accrue(Self::finalize_ended_proposal(proposal_id));
+			}
+			ProposalEnds::<T>::remove(block);
+			block.saturating_inc();
+		}
+		NextFinalizationBlock::<T>::put(block);
+		consumed
+	}
+
+	/// Finalize a proposal that has been scheduled to end, returns the consumed weight.
+	///
+	/// Proposals that are no longer running are skipped. Proposals whose duration has been
+	/// extended by a change of governance are scheduled again. The changes of a failed
+	/// finalization are discarded, as they are for a failed TX.
+	fn finalize_ended_proposal(proposal_id: T::ProposalId) -> Weight {
+		match with_storage_layer(|| Self::do_finalize_proposal(proposal_id)) {
+			Ok(weight) => weight,
+			Err(error) if error == Error::<T>::ProposalDurationHasNotPassed.into() => {
+				let Some(proposal) = <Proposals<T>>::get(proposal_id) else {
+					return T::DbWeight::get().reads(3)
+				};
+				let Some(governance) = <Governances<T>>::get(&proposal.dao_id) else {
+					return T::DbWeight::get().reads(4)
+				};
+				// the proposal stays finalizable by hand if it cannot be scheduled again
+				let _ = Self::schedule_finalization(
+					proposal_id,
+					proposal.birth_block,
+					governance.proposal_duration,
+				);
+				T::DbWeight::get().reads_writes(4, 1)
+			},
+			Err(_) => T::DbWeight::get().reads(2),
+		}
+	}
+
+	/// Turn a proposal slot into a running proposal by setting its metadata.
+	///
+	/// - `proposal_id`: the id of the proposal
+	/// - `slot`: the slot of the proposal
+	/// - `meta`: the metadata of the proposal
+	/// - `hash`: the hash of the metadata
+	fn do_set_metadata(
+		proposal_id: T::ProposalId,
+		slot: ProposalSlotOf<T>,
+		meta: Vec<u8>,
+		hash: Vec<u8>,
+	) -> DispatchResult {
+		let meta: BoundedVec<_, _> =
+			meta.try_into().map_err(|_| DaoError::<T>::MetadataInvalidLengthTooLong)?;
+		let hash: BoundedVec<_, _> =
+			hash.try_into().map_err(|_| DaoError::<T>::HashInvalidWrongLength)?;
+		ensure!(
+			Core::<T>::metadata_is_valid(&slot.dao_id, &meta, &hash),
+			DaoError::<T>::MetadataInvalid
+		);
+
+		let governance = <Governances<T>>::get(&slot.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
+
+		let birth_block = <frame_system::Pallet<T>>::block_number();
+		Self::schedule_finalization(proposal_id, birth_block, governance.proposal_duration)?;
+
+		// store the proposal
+		ProposalSlots::<T>::remove(proposal_id);
+		Proposals::<T>::insert(
+			proposal_id,
+			Proposal {
+				dao_id: slot.dao_id,
+				creator: slot.creator,
+				birth_block,
+				status: ProposalStatus::Running,
+				in_favor: Zero::zero(),
+				against: Zero::zero(),
+				abstain: Zero::zero(),
+				meta,
+				meta_hash: hash,
+			},
+		);
+
+		// emit an event
+		Self::deposit_event(Event::<T>::ProposalMetadataSet { proposal_id });
+		Ok(())
+	}
+
+	/// Remove a proposal slot whose metadata has never been set and return the deposits to its
+	/// creator.
+	fn remove_proposal_slot(proposal_id: T::ProposalId, slot: ProposalSlotOf<T>) -> DispatchResult {
+		Self::return_token_deposit(proposal_id, &slot.dao_id, &slot.creator)?;
+		CurrencyOf::<T>::unreserve(&slot.creator, <T as Config>::ProposalDeposit::get());
+		Self::release_snapshot(proposal_id, &slot.dao_id);
+		ProposalSlots::<T>::remove(proposal_id);
+		DaoProposals::<T>::remove(&slot.dao_id, proposal_id);
+		ProposalCalls::<T>::remove(proposal_id);
+		QuadraticProposals::<T>::remove(proposal_id);
+		ProposalOptions::<T>::remove(proposal_id);
+		OptionTallies::<T>::remove(proposal_id);
+		Ok(())
+	}
+
+	/// Remove a proposal or proposal slot alongside with its votes, returning the deposits that
+	/// are still held for it. Every removed item counts against `budget`. Returns whether the
+	/// proposal has been removed completely, it needs to be removed again otherwise.
+	fn remove_proposal(proposal_id: T::ProposalId, budget: &mut u32) -> bool {
+		if *budget == 0 {
+			return false
+		}
+		*budget -= 1;
+
+		// the deposits are held until a proposal is finalized or faulted
+		let deposit = <T as Config>::ProposalDeposit::get();
+		if let Some(slot) = ProposalSlots::<T>::take(proposal_id) {
+			Self::release_snapshot(proposal_id, &slot.dao_id);
+			CurrencyOf::<T>::unreserve(&slot.creator, deposit);
+			// the token deposit is gone if the token has already been destroyed
+			let _ = Self::return_token_deposit(proposal_id, &slot.dao_id, &slot.creator);
+		}
+		if let Some(proposal) = Proposals::<T>::take(proposal_id) {
+			if proposal.status == ProposalStatus::Running {
+				Self::release_snapshot(proposal_id, &proposal.dao_id);
+				CurrencyOf::<T>::unreserve(&proposal.creator, deposit);
+				let _ =
+					Self::return_token_deposit(proposal_id, &proposal.dao_id, &proposal.creator);
+			} else if let Some(reward) = PruningRewards::<T>::take(proposal_id) {
+				CurrencyOf::<T>::unreserve(&proposal.creator, reward);
+			}
+		}
+		ProposalResults::<T>::remove(proposal_id);
+
+		Self::clear_proposal(proposal_id, budget)
+	}
+
+	/// Remove the votes of a proposal and everything else kept for it besides the proposal itself
+	/// or its result. Every removed item counts against `budget`. Returns whether everything has
+	/// been removed, it needs to be cleared again otherwise.
+	fn clear_proposal(proposal_id: T::ProposalId, budget: &mut u32) -> bool {
+		let removed = Self::drain_limited(Votes::<T>::drain_prefix(proposal_id), budget) &&
+			Self::drain_limited(VoteConvictions::<T>::drain_prefix(proposal_id), budget) &&
+			Self::drain_limited(DelegatedVotes::<T>::drain_prefix(proposal_id), budget) &&
+			Self::drain_limited(DelegatedTo::<T>::drain_prefix(proposal_id), budget) &&
+			Self::drain_limited(PersonVotes::<T>::drain_prefix(proposal_id), budget) &&
+			Self::drain_limited(OptionVotes::<T>::drain_prefix(proposal_id), budget) &&
+			Self::drain_limited(RankingTallies::<T>::drain_prefix(proposal_id), budget);
+		if !removed {
+			return false
+		}
+
+		ProposalCalls::<T>::remove(proposal_id);
+		ProposalCallResults::<T>::remove(proposal_id);
+		ProposalTokenDeposits::<T>::remove(proposal_id);
+		ProposalSnapshots::<T>::remove(proposal_id);
+		QuadraticProposals::<T>::remove(proposal_id);
+		ProposalOptions::<T>::remove(proposal_id);
+		OptionTallies::<T>::remove(proposal_id);
+		ProposalWinners::<T>::remove(proposal_id);
+		PruningProposals::<T>::remove(proposal_id);
+		true
+	}
+
+	/// Remove up to `budget` items drained from storage, reducing `budget` accordingly. Returns
+	/// whether budget is left, in which case all items have been removed.
+	fn drain_limited<I: Iterator>(drained: I, budget: &mut u32) -> bool {
+		let removed = drained.take(*budget as usize).count() as u32;
+		*budget -= removed;
+		*budget > 0
+	}
+
+	/// Release the snapshot of a proposal that is no longer running, its checkpoints of the DAO
+	/// token can be pruned then.
+	fn release_snapshot(proposal_id: T::ProposalId, dao_id: &DaoIdOf<T>) {
+		let Some(snapshot_id) = <ProposalSnapshots<T>>::take(proposal_id) else { return };
+		if let Some(asset_id) = Core::<T>::get_dao(dao_id).and_then(|dao| dao.asset_id) {
+			Assets::<T>::release_snapshot(asset_id.into(), snapshot_id);
+		}
+	}
+
+	/// Return the deposit of a finalized proposal to its creator, keeping the _PruningReward_
+	/// reserved for whoever prunes the proposal.
+	fn release_deposit(proposal_id: T::ProposalId, creator: &AccountIdOf<T>) {
+		let deposit = <T as Config>::ProposalDeposit::get();
+		let reward = T::PruningReward::get().min(deposit);
+		CurrencyOf::<T>::unreserve(creator, deposit - reward);
+		if !reward.is_zero() {
+			<PruningRewards<T>>::insert(proposal_id, reward);
+		}
+	}
+
+	/// Return the DAO token deposit of a proposal to its creator.
+	fn return_token_deposit(
+		proposal_id: T::ProposalId,
+		dao_id: &DaoIdOf<T>,
+		creator: &AccountIdOf<T>,
+	) -> DispatchResult {
+		let Some(deposit) = <ProposalTokenDeposits<T>>::get(proposal_id) else { return Ok(()) };
+		let asset_id = Core::<T>::get_dao(dao_id)
+			.and_then(|dao| dao.asset_id)
+			.ok_or(Error::<T>::DaoTokenNotYetIssued)?;
+		let amount = Assets::<T>::do_unreserve(asset_id.into(), creator, deposit)?;
+		<ProposalTokenDeposits<T>>::remove(proposal_id);
+		Self::deposit_event(Event::<T>::ProposalTokenDepositReturned {
+			proposal_id,
+			creator: creator.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Slash the DAO token deposit of a faulty proposal, it is either burned or sent to the
+	/// treasury of the DAO depending on `FaultyDepositSlash`.
+	fn slash_token_deposit(
+		proposal_id: T::ProposalId,
+		dao_id: &DaoIdOf<T>,
+		creator: &AccountIdOf<T>,
+	) -> DispatchResult {
+		let Some(deposit) = <ProposalTokenDeposits<T>>::get(proposal_id) else { return Ok(()) };
+		let asset_id = Core::<T>::get_dao(dao_id)
+			.and_then(|dao| dao.asset_id)
+			.ok_or(Error::<T>::DaoTokenNotYetIssued)?
+			.into();
+		<ProposalTokenDeposits<T>>::remove(proposal_id);
+		let creator = creator.clone();
+		let event = match T::FaultyDepositSlash::get() {
+			DepositSlash::Burn => {
+				let amount = Assets::<T>::do_slash_reserved(asset_id, &creator, deposit)?;
+				Event::<T>::ProposalTokenDepositBurned { proposal_id, creator, amount }
+			},
+			DepositSlash::Treasury => {
+				let treasury = Core::<T>::dao_account_id(dao_id);
+				let amount = Assets::<T>::do_unreserve(asset_id, &creator, deposit)?;
+				let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+				let amount = Assets::<T>::do_transfer(asset_id, &creator, &treasury, amount, f)?;
+				Event::<T>::ProposalTokenDepositSentToTreasury { proposal_id, creator, amount }
+			},
+		};
+		Self::deposit_event(event);
+		Ok(())
+	}
+
+	/// Schedule the automatic finalization of a proposal at the first block after its duration.
+	fn schedule_finalization(
+		proposal_id: T::ProposalId,
+		birth_block: BlockNumberFor<T>,
+		proposal_duration: u32,
+	) -> DispatchResult {
+		let end = birth_block + proposal_duration.into() + One::one();
+		ProposalEnds::<T>::try_mutate(end, |ended| ended.try_push(proposal_id))
+			.map_err(|_| Error::<T>::TooManyProposalsEnding)?;
+		if !NextFinalizationBlock::<T>::exists() {
+			NextFinalizationBlock::<T>::put(end);
+		}
+		Ok(())
+	}
+
+	/// Add voting power to the tally of a proposal.
+	fn count_vote(proposal: &mut ProposalOf<T>, vote: Option<Vote>, power: AssetBalanceOf<T>) {
+		match vote {
+			Some(Vote::InFavor) => {
+				proposal.in_favor += power;
+			},
+			Some(Vote::Against) => {
+				proposal.against += power;
+			},
+			Some(Vote::Abstain) => {
+				proposal.abstain += power;
+			},
+			None => {},
+		}
+	}
+
+	/// Remove voting power from the tally of a proposal.
+	fn uncount_vote(
+		proposal: &mut ProposalOf<T>,
+		vote: Option<Vote>,
+		power: AssetBalanceOf<T>,
+	) {
+		match vote {
+			Some(Vote::InFavor) => {
+				proposal.in_favor -= power;
+			},
+			Some(Vote::Against) => {
+				proposal.against -= power;
+			},
+			Some(Vote::Abstain) => {
+				proposal.abstain -= power;
+			},
+			None => {},
+		}
+	}
+
+	/// Ensure that votes can be cast on a proposal: it is running and has not yet run for its
+	/// entire duration. Returns the governance of its DAO.
+	fn ensure_voting_open(proposal: &ProposalOf<T>) -> Result<GovernanceOf<T>, DispatchError> {
+		// check that the proposal is running
+		ensure!(proposal.status == ProposalStatus::Running, Error::<T>::ProposalStatusNotRunning);
+
+		let governance =
+			<Governances<T>>::get(&proposal.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
+
+		// check that the proposal has not yet run for its entire duration
+		ensure!(
+			<frame_system::Pallet<T>>::block_number() - proposal.birth_block <=
+				governance.proposal_duration.into(),
+			Error::<T>::ProposalDurationHasPassed
+		);
+		Ok(governance)
+	}
+
+	/// Check that a ranking consists of distinct options of a multiple-choice proposal, at most
+	/// one for plurality tallying.
+	fn validate_ranking(
+		choice: MultipleChoice,
+		ranking: Vec<OptionIndex>,
+	) -> Result<RankingOf<T>, DispatchError> {
+		let max_length = match choice.tallying {
+			Tallying::Plurality => 1,
+			Tallying::RankedChoice => choice.options.into(),
+		};
+		ensure!(ranking.len() <= max_length, Error::<T>::InvalidRanking);
+		for (index, option) in ranking.iter().enumerate() {
+			ensure!(
+				*option < choice.options && !ranking[..index].contains(option),
+				Error::<T>::InvalidRanking
+			);
+		}
+		Ok(ranking.try_into().map_err(|_| Error::<T>::InvalidRanking)?)
+	}
+
+	/// Add voting power to the tallies of a multiple-choice proposal.
+	fn count_ranking(
+		proposal_id: T::ProposalId,
+		choice: MultipleChoice,
+		ranking: &RankingOf<T>,
+		power: AssetBalanceOf<T>,
+	) {
+		let Some(first) = ranking.first() else { return };
+		<OptionTallies<T>>::mutate(proposal_id, |tallies| {
+			if let Some(tally) = tallies.get_mut(usize::from(*first)) {
+				*tally += power;
+			}
+		});
+		if choice.tallying == Tallying::RankedChoice {
+			<RankingTallies<T>>::mutate(proposal_id, ranking, |tally| {
+				*tally = Some(tally.unwrap_or_default() + power);
+			});
+		}
+	}
+
+	/// Remove voting power from the tallies of a multiple-choice proposal.
+	fn uncount_ranking(
+		proposal_id: T::ProposalId,
+		choice: MultipleChoice,
+		ranking: &RankingOf<T>,
+		power: AssetBalanceOf<T>,
+	) {
+		let Some(first) = ranking.first() else { return };
+		<OptionTallies<T>>::mutate(proposal_id, |tallies| {
+			if let Some(tally) = tallies.get_mut(usize::from(*first)) {
+				*tally -= power;
+			}
+		});
+		if choice.tallying == Tallying::RankedChoice {
+			<RankingTallies<T>>::mutate_exists(proposal_id, ranking, |tally| {
+				let remaining = tally.unwrap_or_default() - power;
+				*tally = if remaining.is_zero() { None } else { Some(remaining) };
+			});
+		}
+	}
+
+	/// Determine the winning option of a multiple-choice proposal and record the tallies of
+	/// the final round. Returns the winner, the tallies and the weight of reading the rankings.
+	fn tally_options(
+		proposal_id: T::ProposalId,
+		choice: MultipleChoice,
+	) -> (Option<OptionIndex>, TalliesOf<T>, Weight) {
+		let (winner, tallies, weight) = match choice.tallying {
+			Tallying::Plurality => {
+				let tallies = <OptionTallies<T>>::get(proposal_id);
+				(Self::plurality_winner(&tallies), tallies, Weight::zero())
+			},
+			Tallying::RankedChoice => {
+				let rankings: Vec<_> = <RankingTallies<T>>::iter_prefix(proposal_id).collect();
+				let weight = T::DbWeight::get().reads(rankings.len() as u64);
+				let (winner, tallies) = Self::instant_runoff(choice.options, &rankings);
+				(winner, tallies, weight)
+			},
+		};
+		<OptionTallies<T>>::insert(proposal_id, &tallies);
+		(winner, tallies, weight)
+	}
+
+	/// Whether the winning option of a multiple-choice proposal has the participation the
+	/// governance requires of a binary proposal to be accepted: the turnout of quorum votes, and
+	/// for majority and supermajority votes the final tally of the winner counts as the ayes,
+	/// the one of the strongest other option as the nays. Quadratic and contract votes do not
+	/// require any participation.
+	fn has_participation(
+		voting: &VotingOf<T>,
+		token_supply: AssetBalanceOf<T>,
+		turnout: AssetBalanceOf<T>,
+		tallies: &[AssetBalanceOf<T>],
+		winner: OptionIndex,
+	) -> bool {
+		let winner = usize::from(winner);
+		let in_favor = tallies.get(winner).copied().unwrap_or_else(Zero::zero);
+		let against = tallies
+			.iter()
+			.enumerate()
+			.filter(|(option, _)| *option != winner)
+			.map(|(_, tally)| *tally)
+			.max()
+			.unwrap_or_else(Zero::zero);
+		match voting {
+			Voting::Majority { minimum_majority_per_1024 } => {
+				let required_majority = token_supply /
+					Into::<AssetBalanceOf<T>>::into(1024_u32) *
+					(*minimum_majority_per_1024).into();
+				in_favor.saturating_sub(against) >= required_majority
+			},
+			Voting::Quorum { min_turnout_per_1024, .. } =>
+				turnout >= Self::per_1024(token_supply, *min_turnout_per_1024),
+			Voting::Supermajority { min_approval_per_1024 } =>
+				in_favor >= Self::per_1024(token_supply, *min_approval_per_1024),
+			Voting::Quadratic { .. } | Voting::Contract { .. } => true,
+		}
+	}
+
+	/// The option with the most voting power, `None` without votes or if the most voted options
+	/// are tied.
+	fn plurality_winner(tallies: &[AssetBalanceOf<T>]) -> Option<OptionIndex> {
+		let most = tallies.iter().max().copied().filter(|most| !most.is_zero())?;
+		let mut most_voted = tallies.iter().enumerate().filter(|(_, tally)| **tally == most);
+		match (most_voted.next(), most_voted.next()) {
+			(Some((option, _)), None) => Some(option as OptionIndex),
+			_ => None,
+		}
+	}
+
+	/// Tally ranked votes by instant-runoff: as long as no option has a majority of the voting
+	/// power counted in a round, the options with the least voting power are eliminated and their
+	/// votes count for the next ranked options. There is no winner if all remaining options are
+	/// tied. Returns the winner and the tallies of the final round.
+	///
+	/// - `options`: the number of options
+	/// - `rankings`: the distinct rankings alongside with their voting power
+	fn instant_runoff(
+		options: OptionIndex,
+		rankings: &[(RankingOf<T>, AssetBalanceOf<T>)],
+	) -> (Option<OptionIndex>, TalliesOf<T>) {
+		let mut eliminated = vec![false; options.into()];
+		loop {
+			// count every ranking for its first option that has not been eliminated
+			let mut tallies = vec![AssetBalanceOf::<T>::zero(); options.into()];
+			for (ranking, power) in rankings {
+				let option = ranking.iter().find(|option| !eliminated[usize::from(**option)]);
+				if let Some(option) = option {
+					tallies[usize::from(*option)] += *power;
+				}
+			}
+			let counted = tallies
+				.iter()
+				.fold(Zero::zero(), |sum: AssetBalanceOf<T>, tally| sum.saturating_add(*tally));
+
+			let remaining: Vec<usize> =
+				(0..tallies.len()).filter(|option| !eliminated[*option]).collect();
+			let majority =
+				remaining.iter().find(|option| tallies[**option] > counted - tallies[**option]);
+			let least = remaining.iter().map(|option| tallies[*option]).min().unwrap_or_default();
+			let last: Vec<usize> =
+				remaining.iter().copied().filter(|option| tallies[*option] == least).collect();
+			if majority.is_some() || last.len() == remaining.len() {
+				let winner = majority.map(|option| *option as OptionIndex);
+				return (winner, TalliesOf::<T>::truncate_from(tallies))
+			}
+			for option in last {
+				eliminated[option] = true;
+			}
+		}
+	}
+
+	/// The number of distinct rankings of up to _MaxOptions_ options, which bounds the number of
+	/// ranking tallies of a ranked-choice proposal.
+	fn max_rankings() -> u64 {
+		let options = u64::from(T::MaxOptions::get());
+		// there are options! / (options - length)! rankings of each length
+		(0..options)
+			.scan(1_u64, |rankings, taken| {
+				*rankings = rankings.saturating_mul(options - taken);
+				Some(*rankings)
+			})
+			.fold(0, u64::saturating_add)
+	}
+
+	/// The weight of finalizing a proposal in the worst case, not including its calls.
+	fn max_finalize_weight() -> Weight {
+		<T as Config>::WeightInfo::finalize_proposal(0)
+			.saturating_add(T::DbWeight::get().reads(Self::max_rankings()))
+			.saturating_add(T::VotingStrategy::max_weight())
+	}
+
+	/// Locks of several votes in a DAO overlap, the larger amount and later block are kept.
+	fn lock_voting_tokens(
+		dao_id: &DaoIdOf<T>,
+		asset_id: <T as pallet_dao_assets::Config>::AssetId,
+		who: &AccountIdOf<T>,
+		amount: AssetBalanceOf<T>,
+		until: BlockNumberFor<T>,
+	) -> DispatchResult {
+		<VotingLocks<T>>::try_mutate(dao_id, who, |maybe_lock| {
+			let lock = maybe_lock.get_or_insert_with(Default::default);
+			if amount > lock.amount {
+				Assets::<T>::do_reserve(asset_id, who, amount - lock.amount)?;
+				lock.amount = amount;
+			}
+			lock.until = lock.until.max(until);
+			Ok(())
+		})
+	}
+
+	/// Remove a delegator from the delegators of a delegate.
+	fn remove_delegator(
+		dao_id: &DaoIdOf<T>,
+		delegate: &AccountIdOf<T>,
+		delegator: &AccountIdOf<T>,
+	) {
+		<Delegators<T>>::mutate_exists(dao_id, delegate, |maybe_delegators| {
+			if let Some(delegators) = maybe_delegators {
+				delegators.retain(|(account, _)| account != delegator);
+				if delegators.is_empty() {
+					*maybe_delegators = None;
+				}
+			}
+		});
+	}
+
+	/// The share `per_1024` of `amount`, without overflowing for large amounts.
+	fn per_1024(amount: AssetBalanceOf<T>, per_1024: u16) -> AssetBalanceOf<T> {
+		let base: AssetBalanceOf<T> = 1024_u32.into();
+		let per_1024: AssetBalanceOf<T> = per_1024.into();
+		amount / base * per_1024 + amount % base * per_1024 / base
+	}
+
+	/// The governance of a DAO, if it has been set.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn governance(dao_id: Vec<u8>) -> Option<GovernanceOf<T>> {
+		let dao_id: DaoIdOf<T> = dao_id.try_into().ok()?;
+		<Governances<T>>::get(dao_id)
+	}
+
+	/// A proposal including its current tallies.
+	///
+	/// - `proposal_id`: the id of the proposal
+	pub fn proposal(proposal_id: T::ProposalId) -> Option<ProposalOf<T>> {
+		<Proposals<T>>::get(proposal_id)
+	}
+
+	/// The result of a pruned proposal.
+	///
+	/// - `proposal_id`: the id of the proposal
+	pub fn proposal_result(proposal_id: T::ProposalId) -> Option<ProposalResultOf<T>> {
+		<ProposalResults<T>>::get(proposal_id)
+	}
+
+	/// The vote of an account on a proposal, `None` if it has not voted.
+	///
+	/// - `proposal_id`: the id of the proposal
+	/// - `voter`: the account of the voter
+	pub fn vote_of(proposal_id: T::ProposalId, voter: &AccountIdOf<T>) -> Option<Vote> {
+		<Votes<T>>::get(proposal_id, voter)
+	}
+
+	/// The ranking of the options an account voted for on a multiple-choice proposal, `None` if
+	/// it has not voted.
+	///
+	/// - `proposal_id`: the id of the proposal
+	/// - `voter`: the account of the voter
+	pub fn option_vote_of(
+		proposal_id: T::ProposalId,
+		voter: &AccountIdOf<T>,
+	) -> Option<Vec<OptionIndex>> {
+		<OptionVotes<T>>::get(proposal_id, voter).map(|(ranking, _)| ranking.into_inner())
+	}
+
+	/// The voting power per option of a multiple-choice proposal: the first preferences while it
+	/// is running, the tallies of the final round once finalized.
+	///
+	/// - `proposal_id`: the id of the proposal
+	pub fn option_tallies(proposal_id: T::ProposalId) -> Vec<AssetBalanceOf<T>> {
+		<OptionTallies<T>>::get(proposal_id).into_inner()
+	}
+
+	/// The voting power of an account on a proposal, i.e. its DAO token balance at the snapshot
+	/// taken when the proposal slot has been created, or the integer square root of it for
+	/// quadratic voting. `None` if the proposal does not exist or is no longer running, as the
+	/// snapshot is released then.
+	///
+	/// - `proposal_id`: the id of the proposal
+	/// - `voter`: the account of the voter
+	pub fn voting_power(
+		proposal_id: T::ProposalId,
+		voter: &AccountIdOf<T>,
+	) -> Option<AssetBalanceOf<T>> {
+		let proposal = <Proposals<T>>::get(proposal_id)?;
+		let asset_id = Core::<T>::get_dao(&proposal.dao_id)?.asset_id?;
+		let snapshot_id = <ProposalSnapshots<T>>::get(proposal_id)?;
+		let token_balance = Assets::<T>::total_balance_at(asset_id.into(), voter, snapshot_id)?;
+		let quadratic = <QuadraticProposals<T>>::contains_key(proposal_id);
+		Some(Self::weigh_voting_power(quadratic, token_balance))
+	}
+
+	/// The voting power of a token balance, its integer square root for quadratic voting.
+	fn weigh_voting_power(quadratic: bool, token_balance: AssetBalanceOf<T>) -> AssetBalanceOf<T> {
+		if quadratic {
+			token_balance.integer_sqrt()
+		} else {
+			token_balance
+		}
+	}
+
+	/// Record the account a verified person votes with on a proposal, so the person cannot vote
+	/// with another account as well.
+	///
+	/// - `proposal_id`: the id of the proposal
+	/// - `voter`: the account of the voter
+	/// - `voting`: whether the voter casts a vote or removes it
+	fn record_person_vote(
+		proposal_id: T::ProposalId,
+		voter: &AccountIdOf<T>,
+		voting: bool,
+	) -> DispatchResult {
+		let person = T::Personhood::person_of(voter).ok_or(Error::<T>::VoterNotVerified)?;
+		match <PersonVotes<T>>::get(proposal_id, &person) {
+			Some(account) if account != *voter => Err(Error::<T>::PersonHasVoted.into()),
+			_ if voting => {
+				<PersonVotes<T>>::insert(proposal_id, &person, voter);
+				Ok(())
+			},
+			_ => {
+				<PersonVotes<T>>::remove(proposal_id, &person);
+				Ok(())
+			},
+		}
+	}
+
+	/// Dispatch the calls of an accepted proposal from the origin of its DAO acting collectively.
+	///
+	/// Every call is dispatched on its own, a failing call does not stop the remaining ones.
+	/// Returns the weight consumed by the calls.
+	fn dispatch_calls(proposal_id: T::ProposalId, dao_id: &DaoIdOf<T>) -> Weight {
+		let calls = match ProposalCalls::<T>::get(proposal_id) {
+			Some(calls) => calls,
+			None => return Weight::zero(),
+		};
+
+		let mut weight = Weight::zero();
+		let mut results = BoundedVec::<DispatchResult, T::MaxCalls>::default();
+		for (index, call) in calls.into_iter().enumerate() {
+			let info = call.get_dispatch_info();
+			let origin = pallet_dao_core::RawOrigin::Dao(dao_id.clone()).into();
+			let result = call.dispatch(origin);
+			weight.saturating_accrue(extract_actual_weight(&result, &info));
+
+			let result = result.map(|_| ()).map_err(|e| e.error);
+			// cannot fail, there are never more results than calls
+			let _ = results.try_push(result);
+			Self::deposit_event(Event::<T>::ProposalCallDispatched {
+				proposal_id,
+				index: index as u32,
+				result,
+			});
+		}
+		ProposalCallResults::<T>::insert(proposal_id, results);
+		weight
+	}
+}
+
+impl<T: Config> DaoTeardown<DaoIdOf<T>> for Pallet<T> {
+	/// Remove the governance of a DAO first, so no proposals can be created anymore, then its
+	/// proposals alongside with their votes, returning the deposits still held for them, and
+	/// finally the voting locks and delegations in the DAO.
+	fn teardown(dao_id: &DaoIdOf<T>, limit: u32) -> bool {
+		let mut budget = limit;
+		<Governances<T>>::remove(dao_id);
+
+		while let Some(proposal_id) = <DaoProposals<T>>::iter_key_prefix(dao_id).next() {
+			if !Self::remove_proposal(proposal_id, &mut budget) {
+				return false
+			}
+			<DaoProposals<T>>::remove(dao_id, proposal_id);
+		}
+
+		let asset_id = Core::<T>::get_dao(dao_id).and_then(|dao| dao.asset_id);
+		let locks: Vec<_> = <VotingLocks<T>>::drain_prefix(dao_id).take(budget as usize).collect();
+		budget -= locks.len() as u32;
+		for (who, lock) in locks {
+			if let Some(asset_id) = asset_id {
+				// the locked tokens are gone if the token has already been destroyed
+				let _ = Assets::<T>::do_unreserve(asset_id.into(), &who, lock.amount);
+			}
+		}
+
+		let delegations: Vec<_> =
+			<Delegations<T>>::drain_prefix(dao_id).take(budget as usize).collect();
+		budget -= delegations.len() as u32;
+		for (delegator, _) in delegations {
+			CurrencyOf::<T>::unreserve(&delegator, T::DelegationDeposit::get());
+		}
+
+		budget > 0 && Self::drain_limited(<Delegators<T>>::drain_prefix(dao_id), &mut budget)
+	}
+
+	/// Charged as if every item were a running proposal still holding its deposits, the most
+	/// expensive item to remove.
+	fn teardown_weight(limit: u32) -> Weight {
+		<T as Config>::WeightInfo::teardown(limit)
 	}
 }
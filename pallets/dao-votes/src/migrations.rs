@@ -0,0 +1,297 @@
+//! Storage migrations for the dao-votes pallet.
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+pub mod v1 {
+	use super::*;
+	use pallet_dao_assets::migrations::v2::snapshot_at_block;
+
+	/// The layouts of proposal slots and proposals before version 1.
+	pub(crate) mod v0 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct ProposalSlot<DaoId, AccountId> {
+			pub dao_id: DaoId,
+			pub creator: AccountId,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct Proposal<DaoId, AccountId, BlockId, Balance, Metadata> {
+			pub dao_id: DaoId,
+			pub creator: AccountId,
+			pub birth_block: BlockId,
+			pub meta: Metadata,
+			pub meta_hash: BoundedVec<u8, ConstU32<64>>,
+			pub status: ProposalStatus,
+			pub in_favor: Balance,
+			pub against: Balance,
+		}
+
+		pub type ProposalSlotOf<T> = ProposalSlot<DaoIdOf<T>, AccountIdOf<T>>;
+		pub type ProposalOf<T> = Proposal<
+			DaoIdOf<T>,
+			AccountIdOf<T>,
+			BlockNumberFor<T>,
+			AssetBalanceOf<T>,
+			pallet_dao_core::MetadataOf<T>,
+		>;
+
+		#[storage_alias]
+		pub type ProposalSlots<T: Config> =
+			StorageMap<Pallet<T>, Twox64Concat, <T as Config>::ProposalId, ProposalSlotOf<T>>;
+
+		#[storage_alias]
+		pub type Proposals<T: Config> =
+			StorageMap<Pallet<T>, Twox64Concat, <T as Config>::ProposalId, ProposalOf<T>>;
+	}
+
+	/// Migrates the proposal slots and proposals created before version 1, whose voting power was
+	/// read from the history of the DAO token by block:
+	///
+	/// - Running proposals and proposal slots get their DAO token deposit recorded, which is the
+	///   _proposal_token_deposit_ of the governance of their DAO as far as their creator still
+	///   reserves it.
+	/// - Running proposals get the snapshot `MigrateToV2` of the assets pallet took for the block
+	///   they were created at, or a snapshot of the current balances if the history does not
+	///   reach back that far. Running proposals whose DAO token is gone are faulted, returning
+	///   their deposits.
+	/// - Proposal slots get a snapshot of the current balances.
+	/// - Proposals and proposal slots are indexed by DAO in `DaoProposals`, so that they are
+	///   removed when their DAO is destroyed.
+	/// - Proposals are translated into the layout with abstentions, none of which have been cast.
+	/// - Proposal slots are translated into the layout with the block they were created at, which
+	///   is set to the current block, so that they can be expired _ProposalSlotExpiry_ blocks
+	///   after the upgrade.
+	///
+	/// Needs to run after `MigrateToV2` of the assets pallet and before its `ClearSnapshotBlocks`.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			weight.saturating_accrue(Self::record_token_deposits());
+			weight.saturating_accrue(Self::take_snapshots());
+			weight.saturating_accrue(Self::index_dao_proposals());
+			weight.saturating_accrue(Self::translate_proposals());
+			weight.saturating_accrue(Self::translate_proposal_slots());
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let proposals = v0::Proposals::<T>::iter_keys().count() as u32;
+			let running = v0::Proposals::<T>::iter_values()
+				.filter(|proposal| proposal.status == ProposalStatus::Running)
+				.count() as u32;
+			let slots = v0::ProposalSlots::<T>::iter_keys().count() as u32;
+			Ok((proposals, running, slots).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+
+			let (proposals, running, slots): (u32, u32, u32) =
+				Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			let mut translated = 0_u32;
+			let mut snapshotted = 0_u32;
+			for (proposal_id, proposal) in Proposals::<T>::iter() {
+				ensure!(proposal.abstain.is_zero(), "abstentions counted");
+				if proposal.status == ProposalStatus::Running {
+					ensure!(
+						ProposalSnapshots::<T>::contains_key(proposal_id),
+						"running proposal without snapshot"
+					);
+					snapshotted += 1;
+				}
+				translated += 1;
+			}
+			ensure!(translated == proposals, "proposals not translated");
+			ensure!(
+				DaoProposals::<T>::iter_keys().count() as u32 == proposals + slots,
+				"proposals not indexed by DAO"
+			);
+			ensure!(snapshotted <= running, "proposals running again");
+			ensure!(
+				ProposalSlots::<T>::iter_keys().count() as u32 == slots,
+				"proposal slots not translated"
+			);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> MigrateToV1<T> {
+		/// Record the DAO token deposits of running proposals and proposal slots, returns the
+		/// consumed weight.
+		fn record_token_deposits() -> Weight {
+			let db_weight = T::DbWeight::get();
+			let mut weight = Weight::zero();
+
+			let running = v0::Proposals::<T>::iter()
+				.filter(|(_, proposal)| proposal.status == ProposalStatus::Running)
+				.map(|(proposal_id, proposal)| (proposal_id, proposal.dao_id, proposal.creator));
+			let slots = v0::ProposalSlots::<T>::iter()
+				.map(|(proposal_id, slot)| (proposal_id, slot.dao_id, slot.creator));
+			let created: Vec<_> = running.chain(slots).collect();
+
+			// the tokens each creator reserves in a DAO, shared by all of its proposals there
+			let mut reserved = BTreeMap::<(DaoIdOf<T>, AccountIdOf<T>), AssetBalanceOf<T>>::new();
+			for (proposal_id, dao_id, creator) in created {
+				weight.saturating_accrue(db_weight.reads_writes(5, 1));
+				let Ok(asset_id) = Self::asset_of(&dao_id) else { continue };
+				let Some(governance) = Governances::<T>::get(&dao_id) else { continue };
+				let available = reserved
+					.entry((dao_id, creator.clone()))
+					.or_insert_with(|| Assets::<T>::reserved(asset_id, &creator));
+				let deposit = governance.proposal_token_deposit.min(*available);
+				*available -= deposit;
+				if !deposit.is_zero() {
+					ProposalTokenDeposits::<T>::insert(proposal_id, deposit);
+				}
+			}
+			weight
+		}
+
+		/// Take the snapshots of running proposals and proposal slots, returns the consumed
+		/// weight.
+		fn take_snapshots() -> Weight {
+			let db_weight = T::DbWeight::get();
+			let mut weight = Weight::zero();
+
+			let mut historic = Vec::new();
+			let mut current = Vec::new();
+			let proposals: Vec<_> = v0::Proposals::<T>::iter().collect();
+			for (proposal_id, proposal) in proposals {
+				weight.saturating_accrue(db_weight.reads(3));
+				if proposal.status != ProposalStatus::Running {
+					continue
+				}
+				match Self::asset_of(&proposal.dao_id) {
+					Ok(asset_id) => match snapshot_at_block::<T>(asset_id, proposal.birth_block) {
+						Some(snapshot_id) => historic.push((snapshot_id, proposal_id, asset_id)),
+						None => current.push((proposal_id, asset_id)),
+					},
+					Err(error) => Self::fault(proposal_id, proposal, error),
+				}
+			}
+
+			// older snapshots cannot be retained once a newer one is in use
+			historic.sort_by_key(|(snapshot_id, ..)| *snapshot_id);
+			for (snapshot_id, proposal_id, asset_id) in historic {
+				weight.saturating_accrue(db_weight.reads_writes(3, 3));
+				match Assets::<T>::retain_snapshot(asset_id, snapshot_id) {
+					Ok(()) => ProposalSnapshots::<T>::insert(proposal_id, snapshot_id),
+					Err(_) => current.push((proposal_id, asset_id)),
+				}
+			}
+			for (proposal_id, asset_id) in current {
+				weight.saturating_accrue(db_weight.reads_writes(4, 5));
+				match Assets::<T>::snapshot(asset_id) {
+					Ok(snapshot_id) => ProposalSnapshots::<T>::insert(proposal_id, snapshot_id),
+					Err(error) => {
+						if let Some(proposal) = v0::Proposals::<T>::get(proposal_id) {
+							Self::fault(proposal_id, proposal, error);
+						}
+					},
+				}
+			}
+
+			// slots whose DAO token is gone can only be cancelled
+			let slots: Vec<_> = v0::ProposalSlots::<T>::iter().collect();
+			for (proposal_id, slot) in slots {
+				weight.saturating_accrue(db_weight.reads_writes(4, 5));
+				let snapshot = Self::asset_of(&slot.dao_id).and_then(Assets::<T>::snapshot);
+				if let Ok(snapshot_id) = snapshot {
+					ProposalSnapshots::<T>::insert(proposal_id, snapshot_id);
+				}
+			}
+			weight
+		}
+
+		/// Index the proposals and proposal slots by DAO, returns the consumed weight.
+		fn index_dao_proposals() -> Weight {
+			let mut indexed = 0_u64;
+			for (proposal_id, proposal) in v0::Proposals::<T>::iter() {
+				DaoProposals::<T>::insert(&proposal.dao_id, proposal_id, ());
+				indexed += 1;
+			}
+			for (proposal_id, slot) in v0::ProposalSlots::<T>::iter() {
+				DaoProposals::<T>::insert(&slot.dao_id, proposal_id, ());
+				indexed += 1;
+			}
+			T::DbWeight::get().reads_writes(indexed, indexed)
+		}
+
+		/// Translate the proposals into the layout with abstentions, returns the consumed weight.
+		fn translate_proposals() -> Weight {
+			let mut translated = 0_u64;
+			Proposals::<T>::translate::<v0::ProposalOf<T>, _>(|_, proposal| {
+				translated += 1;
+				Some(Proposal {
+					dao_id: proposal.dao_id,
+					creator: proposal.creator,
+					birth_block: proposal.birth_block,
+					meta: proposal.meta,
+					meta_hash: proposal.meta_hash,
+					status: proposal.status,
+					in_favor: proposal.in_favor,
+					against: proposal.against,
+					abstain: Zero::zero(),
+				})
+			});
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		/// Translate the proposal slots into the layout with the block they were created at,
+		/// returns the consumed weight.
+		fn translate_proposal_slots() -> Weight {
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut translated = 0_u64;
+			ProposalSlots::<T>::translate::<v0::ProposalSlotOf<T>, _>(|_, slot| {
+				translated += 1;
+				Some(ProposalSlot { dao_id: slot.dao_id, creator: slot.creator, birth_block: now })
+			});
+			T::DbWeight::get().reads_writes(translated + 1, translated)
+		}
+
+		/// The DAO token of a DAO.
+		fn asset_of(
+			dao_id: &DaoIdOf<T>,
+		) -> Result<<T as pallet_dao_assets::Config>::AssetId, DispatchError> {
+			let dao = Core::<T>::get_dao(dao_id).ok_or(DaoError::<T>::DaoDoesNotExist)?;
+			Ok(dao.asset_id.ok_or(Error::<T>::DaoTokenNotYetIssued)?.into())
+		}
+
+		/// Fault a running proposal that cannot be migrated, returning its deposits.
+		fn fault(
+			proposal_id: T::ProposalId,
+			mut proposal: v0::ProposalOf<T>,
+			error: DispatchError,
+		) {
+			CurrencyOf::<T>::unreserve(&proposal.creator, <T as Config>::ProposalDeposit::get());
+			// the DAO token deposit stays recorded if the DAO token is gone
+			let _ =
+				Pallet::<T>::return_token_deposit(proposal_id, &proposal.dao_id, &proposal.creator);
+			proposal.status = ProposalStatus::Faulty;
+			v0::Proposals::<T>::insert(proposal_id, proposal);
+
+			let reason: &'static str = error.into();
+			Pallet::<T>::deposit_event(Event::<T>::ProposalFaulted {
+				proposal_id,
+				reason: reason.as_bytes().to_vec(),
+			});
+		}
+	}
+}
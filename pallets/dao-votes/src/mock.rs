@@ -1,9 +1,13 @@
 use crate as pallet_dao_votes;
+use crate::{DepositSlash, Personhood, Tally, VotingStrategy};
 use frame_support::{
 	parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, ConstU8},
+	weights::Weight,
+	PalletId,
 };
 use frame_system as system;
+use pallet_dao_core::metadata::{HashAlgorithm, HashLength, IpfsCid, IpfsOnly, UrlScheme};
 use sp_core::H256;
 
 use sp_runtime::{
@@ -18,6 +22,8 @@ pub(crate) type Balance = u128;
 
 parameter_types! {
 	pub const ExistentialDeposit: Balance = 1;
+	pub const DaoPalletId: PalletId = PalletId(*b"gdao/acc");
+	pub const MetadataHashAlgorithm: HashAlgorithm = HashAlgorithm::Sha3_256;
 }
 
 frame_support::construct_runtime!(
@@ -91,11 +97,14 @@ impl pallet_dao_assets::Config for Test {
 	type AssetIdParameter = u32;
 	type Currency = Balances;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AdminOrigin = pallet_dao_core::EnsureDaoToken<Self>;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
 	type ApprovalDeposit = ApprovalDeposit;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type StringLimit = AssetsStringLimit;
-	type HistoryHorizon = ConstU32<4200>;
+	type MaxVestingSchedules = ConstU32<3>;
+	type MinVestedTransfer = ConstU128<10>;
+	type MaxVestingDuration = ConstU64<1000>;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -110,14 +119,73 @@ impl pallet_dao_core::Config for Test {
 	type Currency = Balances;
 	type DaoDeposit = ConstU128<10>;
 	type TokenUnits = ConstU8<10>;
+	type MaxCouncilMembers = ConstU32<4>;
 	type AssetId = u32;
+	type PalletId = DaoPalletId;
+	type DaoOrigin = pallet_dao_core::EnsureDao<Self>;
+	type DaoTeardown = DaoVotes;
+	type MetadataValidator = (UrlScheme, IpfsCid, HashLength<MetadataHashAlgorithm>);
+	type StrictMetadataValidator = IpfsOnly;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub MaxProposalWeight: Weight = Weight::from_parts(1_000_000_000, 0);
+	pub static FaultyDepositSlash: DepositSlash = DepositSlash::Burn;
+	pub static PersonhoodAvailable: bool = true;
+}
+
+/// Accounts below 100 are verified unless _PersonhoodAvailable_ is unset, accounts with the same last digit belong to the same person.
+pub struct TestPersonhood;
+
+impl Personhood<u32> for TestPersonhood {
+	type PersonId = u32;
+
+	fn is_available() -> bool {
+		PersonhoodAvailable::get()
+	}
+
+	fn person_of(who: &u32) -> Option<u32> {
+		(*who < 100).then_some(who % 10)
+	}
+}
+
+/// Account 42 is a contract accepting proposals with twice as many ayes as nays, the calls into
+/// account 43 fail.
+pub struct TestVotingStrategy;
+
+impl VotingStrategy<u32, u128> for TestVotingStrategy {
+	fn is_contract(address: &u32) -> bool {
+		*address == 42 || *address == 43
+	}
+
+	fn accepts(address: &u32, tally: &Tally<u128>) -> Option<bool> {
+		(*address == 42).then_some(tally.in_favor >= tally.against * 2)
+	}
+
+	fn max_weight() -> Weight {
+		Weight::from_parts(1_000, 0)
+	}
+}
+
 impl pallet_dao_votes::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type MaxCalls = ConstU32<4>;
+	type MaxProposalWeight = MaxProposalWeight;
+	type MaxDelegators = ConstU32<4>;
+	type DelegationDeposit = ConstU128<5>;
+	type MaxOptions = ConstU32<4>;
+	type MaxProposalsPerBlock = ConstU32<4>;
+	type FaultyDepositSlash = FaultyDepositSlash;
+	type ProposalSlotExpiry = ConstU64<10>;
 	type ProposalDeposit = ConstU128<10>;
+	type ProposalRetentionPeriod = ConstU64<100>;
+	type PruningReward = ConstU128<1>;
 	type ProposalId = u32;
+	type Personhood = TestPersonhood;
+	type VotingStrategy = TestVotingStrategy;
 	type WeightInfo = ();
 }
 
@@ -42,7 +42,7 @@ pub fn setup_dao_with_governance<T: Config>(caller: T::AccountId) -> Vec<u8> {
 /// - `caller`: AccountId of the dao creator
 /// - `dao_id`: id of the dao
 pub fn create_proposal_id<T: Config>(caller: T::AccountId, dao_id: Vec<u8>) -> T::ProposalId {
-	assert_eq!(Votes::<T>::create_proposal(RawOrigin::Signed(caller).into(), dao_id,), Ok(()));
+	assert_eq!(Votes::<T>::create_proposal(RawOrigin::Signed(caller).into(), dao_id, None), Ok(()));
 	Votes::<T>::get_current_proposal_id()
 }
 
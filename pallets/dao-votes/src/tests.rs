@@ -1,7 +1,19 @@
-use crate::{mock::*, test_utils::*, types::*, Config, Error, ProposalSlots, Proposals, Votes};
-use frame_support::{assert_noop, assert_ok, traits::TypedGet};
+use crate::{
+	mock::*, test_utils::*, types::*, weights::WeightInfo, Config, DaoProposals, Delegations,
+	Delegators, Error, Event, Governances, OptionTallies, ProposalCallResults, ProposalCalls,
+	ProposalSlots, ProposalSnapshots, ProposalTokenDeposits, ProposalWinners, Proposals,
+	PruningProposals, Votes, VotingLocks,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, Hooks, TypedGet},
+	weights::Weight,
+};
 use frame_system::ensure_signed;
-use pallet_dao_core::{CurrencyOf, Error as DaoError};
+use pallet_balances::Error as BalancesError;
+use pallet_dao_assets::{AssetStatus, Error as AssetError, VestingSchedule};
+use pallet_dao_core::{CurrencyOf, DaoIdOf, DaoTeardown, Error as DaoError};
+use sp_runtime::{traits::Hash, DispatchError};
 
 #[test]
 fn can_create_a_proposal() {
@@ -13,7 +25,7 @@ fn can_create_a_proposal() {
 
 		// cannot create a proposal without a DAO
 		assert_noop!(
-			DaoVotes::create_proposal(origin.clone(), dao_id.clone(),),
+			DaoVotes::create_proposal(origin.clone(), dao_id.clone(), None),
 			DaoError::<Test>::DaoDoesNotExist
 		);
 
@@ -22,7 +34,7 @@ fn can_create_a_proposal() {
 
 		// cannot create a proposal without DAO tokens existing (because they need to be reserved)
 		assert_noop!(
-			DaoVotes::create_proposal(origin.clone(), dao_id.clone(),),
+			DaoVotes::create_proposal(origin.clone(), dao_id.clone(), None),
 			Error::<Test>::DaoTokenNotYetIssued
 		);
 
@@ -42,7 +54,7 @@ fn can_create_a_proposal() {
 
 		// cannot create a proposal without a governance set
 		assert_noop!(
-			DaoVotes::create_proposal(origin.clone(), dao_id.clone()),
+			DaoVotes::create_proposal(origin.clone(), dao_id.clone(), None),
 			Error::<Test>::GovernanceNotSet
 		);
 
@@ -62,7 +74,7 @@ fn can_create_a_proposal() {
 		assert!(!<ProposalSlots<Test>>::contains_key(DaoVotes::get_current_proposal_id()));
 
 		// test creating a proposal
-		assert_ok!(DaoVotes::create_proposal(origin.clone(), dao_id.clone()));
+		assert_ok!(DaoVotes::create_proposal(origin.clone(), dao_id.clone(), None));
 
 		// check that a proposal exists
 		assert!(<ProposalSlots<Test>>::contains_key(DaoVotes::get_current_proposal_id()));
@@ -111,21 +123,225 @@ fn can_cast_and_remove_a_vote() {
 		let prop_id = setup_proposal::<Test>(sender, dao_id);
 
 		// cannot create a vote without a proposal
-		assert_noop!(DaoVotes::vote(origin.clone(), 0, None), Error::<Test>::ProposalDoesNotExist);
+		assert_noop!(
+			DaoVotes::vote(origin.clone(), 0, None, None),
+			Error::<Test>::ProposalDoesNotExist
+		);
 
 		let voter = 1;
-		let vote = true;
+		let vote = Vote::InFavor;
 		// test creating a vote
 		assert!(!<Votes<Test>>::contains_key(prop_id, voter));
-		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(voter), prop_id, Some(vote)));
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(voter), prop_id, Some(vote), None));
 		assert_eq!(<Votes<Test>>::get(prop_id, voter), Some(vote));
 
 		// test removing the same vote
-		assert_ok!(DaoVotes::vote(origin, prop_id, None));
+		assert_ok!(DaoVotes::vote(origin, prop_id, None, None));
 		assert!(!<Votes<Test>>::contains_key(prop_id, voter));
 	});
 }
 
+#[test]
+fn can_query_governance_proposals_and_votes() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let sender = ensure_signed(origin.clone()).unwrap();
+		assert_eq!(DaoVotes::governance(b"GDAO".to_vec()), None);
+		let dao_id = setup_dao_with_governance::<Test>(sender);
+		let prop_id = setup_proposal::<Test>(sender, dao_id.clone());
+
+		assert_eq!(DaoVotes::governance(dao_id).unwrap().proposal_duration, 0);
+		assert_eq!(DaoVotes::proposal(prop_id), <Proposals<Test>>::get(prop_id));
+		assert_eq!(DaoVotes::proposal(prop_id + 1), None);
+
+		assert_ok!(DaoVotes::vote(origin, prop_id, Some(Vote::Against), None));
+		assert_eq!(DaoVotes::vote_of(prop_id, &sender), Some(Vote::Against));
+		assert_eq!(DaoVotes::vote_of(prop_id, &2), None);
+
+		let power = DaoVotes::voting_power(prop_id, &sender).unwrap();
+		assert!(power > 0);
+		assert_eq!(DaoVotes::proposal(prop_id).unwrap().against, power);
+		assert_eq!(DaoVotes::voting_power(prop_id, &2), Some(0));
+		assert_eq!(DaoVotes::voting_power(prop_id + 1, &sender), None);
+	});
+}
+
+#[test]
+fn can_delegate_and_undelegate() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+
+		assert_noop!(
+			DaoVotes::delegate(origin.clone(), b"NONE".to_vec(), 2),
+			DaoError::<Test>::DaoDoesNotExist
+		);
+		assert_noop!(
+			DaoVotes::delegate(origin.clone(), dao_id.clone(), 1),
+			Error::<Test>::CannotDelegateToSelf
+		);
+		assert_noop!(
+			DaoVotes::undelegate(origin.clone(), dao_id.clone()),
+			Error::<Test>::NotDelegating
+		);
+		// delegating requires the delegation deposit
+		assert_noop!(
+			DaoVotes::delegate(RuntimeOrigin::signed(4), dao_id.clone(), 2),
+			BalancesError::<Test>::InsufficientBalance
+		);
+
+		// the DAO deposit is reserved already
+		assert_ok!(DaoVotes::delegate(origin.clone(), dao_id.clone(), 2));
+		assert_eq!(Balances::reserved_balance(1), 10 + 5);
+		assert_eq!(Delegations::<Test>::get(&bounded_dao_id, 1), Some(2));
+		assert_eq!(Delegators::<Test>::get(&bounded_dao_id, 2).to_vec(), vec![(1, 0)]);
+
+		// delegating again moves the delegation, keeping the deposit
+		assert_ok!(DaoVotes::delegate(origin.clone(), dao_id.clone(), 3));
+		assert_eq!(Balances::reserved_balance(1), 10 + 5);
+		assert_eq!(Delegations::<Test>::get(&bounded_dao_id, 1), Some(3));
+		assert!(!Delegators::<Test>::contains_key(&bounded_dao_id, 2));
+		assert_eq!(Delegators::<Test>::get(&bounded_dao_id, 3).to_vec(), vec![(1, 0)]);
+
+		for delegator in 4..8 {
+			Balances::make_free_balance_be(&delegator, 10);
+		}
+		for delegator in 4..7 {
+			assert_ok!(DaoVotes::delegate(RuntimeOrigin::signed(delegator), dao_id.clone(), 3));
+		}
+		assert_noop!(
+			DaoVotes::delegate(RuntimeOrigin::signed(7), dao_id.clone(), 3),
+			Error::<Test>::TooManyDelegators
+		);
+
+		assert_ok!(DaoVotes::undelegate(origin, dao_id));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Delegations::<Test>::get(&bounded_dao_id, 1), None);
+		assert_eq!(
+			Delegators::<Test>::get(&bounded_dao_id, 3).to_vec(),
+			vec![(4, 0), (5, 0), (6, 0)]
+		);
+	});
+}
+
+#[test]
+fn voting_power_is_taken_at_proposal_creation() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 300));
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		assert_eq!(ProposalSnapshots::<Test>::get(prop_id), Some(1));
+
+		// tokens received after the creation of a proposal do not count
+		assert_ok!(Assets::transfer(origin, 1, 3, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 1, 3, 100));
+		assert_eq!(DaoVotes::voting_power(prop_id, &1), Some(700));
+		assert_eq!(DaoVotes::voting_power(prop_id, &2), Some(300));
+		assert_eq!(DaoVotes::voting_power(prop_id, &3), Some(0));
+
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(2), prop_id, Some(Vote::InFavor), None));
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(3), prop_id, Some(Vote::Against), None));
+		let proposal = Proposals::<Test>::get(prop_id).unwrap();
+		assert_eq!((proposal.in_favor, proposal.against), (300, 0));
+	})
+}
+
+#[test]
+fn delegated_voting_power_is_counted() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 300));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 3, 100));
+		Balances::make_free_balance_be(&2, 10);
+		Balances::make_free_balance_be(&3, 10);
+		assert_ok!(DaoVotes::delegate(RuntimeOrigin::signed(2), dao_id.clone(), 1));
+		assert_ok!(DaoVotes::delegate(RuntimeOrigin::signed(3), dao_id.clone(), 1));
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		let tally = || {
+			let proposal = Proposals::<Test>::get(prop_id).unwrap();
+			(proposal.in_favor, proposal.against)
+		};
+
+		// the delegate votes with the voting power of its delegators
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id, Some(Vote::InFavor), None));
+		assert_eq!(tally(), (1000, 0));
+
+		// a direct vote replaces the vote of the delegate
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(3), prop_id, Some(Vote::Against), None));
+		assert_eq!(tally(), (900, 100));
+
+		// changing the vote of the delegate does not count delegators that voted directly
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id, Some(Vote::Against), None));
+		assert_eq!(tally(), (0, 1000));
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(2), prop_id, Some(Vote::InFavor), None));
+		assert_eq!(tally(), (300, 700));
+
+		// a removed direct vote is not given back to the delegate
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(2), prop_id, None, None));
+		assert_eq!(tally(), (0, 700));
+
+		// removing the vote of the delegate removes delegated voting power as well
+		assert_ok!(DaoVotes::vote(origin, prop_id, None, None));
+		assert_eq!(tally(), (0, 100));
+	});
+}
+
+#[test]
+fn delegations_count_for_proposals_created_afterwards() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 300));
+		Balances::make_free_balance_be(&2, 10);
+		let earlier = setup_proposal::<Test>(1, dao_id.clone());
+		assert_ok!(DaoVotes::delegate(RuntimeOrigin::signed(2), dao_id.clone(), 1));
+		let later = setup_proposal::<Test>(1, dao_id);
+		let in_favor = |prop_id| Proposals::<Test>::get(prop_id).unwrap().in_favor;
+
+		// the delegation has been made after the snapshot of the earlier proposal
+		assert_ok!(DaoVotes::vote(origin.clone(), earlier, Some(Vote::InFavor), None));
+		assert_eq!(in_favor(earlier), 700);
+		assert_ok!(DaoVotes::vote(origin, later, Some(Vote::InFavor), None));
+		assert_eq!(in_favor(later), 1000);
+	});
+}
+
+#[test]
+fn abstentions_are_counted_separately() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 300));
+		Balances::make_free_balance_be(&2, 10);
+		assert_ok!(DaoVotes::delegate(RuntimeOrigin::signed(2), dao_id.clone(), 1));
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		let tally = || {
+			let proposal = Proposals::<Test>::get(prop_id).unwrap();
+			(proposal.in_favor, proposal.against, proposal.abstain)
+		};
+
+		// the conviction of an abstention is ignored
+		assert_ok!(DaoVotes::vote(
+			origin.clone(),
+			prop_id,
+			Some(Vote::Abstain),
+			Some(Conviction::Locked2x)
+		));
+		assert_eq!(tally(), (0, 0, 1000));
+		assert_eq!(DaoVotes::vote_of(prop_id, &1), Some(Vote::Abstain));
+		assert_eq!(crate::VoteConvictions::<Test>::get(prop_id, 1), None);
+
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(2), prop_id, Some(Vote::InFavor), None));
+		assert_eq!(tally(), (300, 0, 700));
+
+		assert_ok!(DaoVotes::vote(origin, prop_id, None, None));
+		assert_eq!(tally(), (300, 0, 0));
+	});
+}
+
 #[test]
 fn can_fault_a_proposal() {
 	new_test_ext().execute_with(|| {
@@ -213,8 +429,13 @@ fn voting_outcome_unsuccessful_proposal() {
 
 		let voter = 2;
 		assert_ok!(Assets::transfer(origin.clone(), 1, voter, 500));
-		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(voter), prop_id.clone(), Some(true)));
-		assert_ok!(DaoVotes::vote(origin.clone(), prop_id.clone(), Some(false)));
+		assert_ok!(DaoVotes::vote(
+			RuntimeOrigin::signed(voter),
+			prop_id.clone(),
+			Some(Vote::InFavor),
+			None
+		));
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id.clone(), Some(Vote::Against), None));
 
 		let block = System::block_number() + 1 + duration as u64;
 		run_to_block::<Test>(block);
@@ -267,8 +488,13 @@ fn voting_outcome_successful_proposal_and_mark_implemented() {
 
 		let voter = 2;
 		assert_ok!(Assets::transfer(origin.clone(), 1, voter, 501));
-		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(voter), prop_id.clone(), Some(true)));
-		assert_ok!(DaoVotes::vote(origin.clone(), prop_id.clone(), Some(false)));
+		assert_ok!(DaoVotes::vote(
+			RuntimeOrigin::signed(voter),
+			prop_id.clone(),
+			Some(Vote::InFavor),
+			None
+		));
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id.clone(), Some(Vote::Against), None));
 
 		let block = System::block_number() + 1 + duration as u64;
 		run_to_block::<Test>(block);
@@ -282,3 +508,1251 @@ fn voting_outcome_successful_proposal_and_mark_implemented() {
 		assert_eq!(proposal.status, ProposalStatus::Implemented);
 	})
 }
+
+#[test]
+fn council_can_fault_and_mark_proposals_implemented() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		assert_ok!(DaoCore::set_council(origin.clone(), dao_id.clone(), vec![2, 3], 2));
+		let status = |prop_id| Proposals::<Test>::get(prop_id).unwrap().status;
+
+		let prop_id = setup_proposal::<Test>(1, dao_id.clone());
+		let reason = b"Bad".to_vec();
+		assert_noop!(
+			DaoVotes::fault_proposal(RuntimeOrigin::signed(4), prop_id, reason.clone()),
+			Error::<Test>::SenderIsNotDaoOwner
+		);
+		assert_ok!(DaoVotes::fault_proposal(RuntimeOrigin::signed(2), prop_id, reason.clone()));
+		System::assert_last_event(RuntimeEvent::DaoVotes(Event::ProposalFaultApproved {
+			proposal_id: prop_id,
+			member: 2,
+		}));
+		assert_eq!(status(prop_id), ProposalStatus::Running);
+		assert_ok!(DaoVotes::fault_proposal(RuntimeOrigin::signed(3), prop_id, reason.clone()));
+		System::assert_last_event(RuntimeEvent::DaoVotes(Event::ProposalFaulted {
+			proposal_id: prop_id,
+			reason,
+		}));
+		assert_eq!(status(prop_id), ProposalStatus::Faulty);
+
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		assert_ok!(DaoVotes::vote(origin, prop_id, Some(Vote::InFavor), None));
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		assert_noop!(
+			DaoVotes::mark_implemented(RuntimeOrigin::signed(4), prop_id),
+			Error::<Test>::SenderIsNotDaoOwner
+		);
+		assert_ok!(DaoVotes::mark_implemented(RuntimeOrigin::signed(3), prop_id));
+		System::assert_last_event(RuntimeEvent::DaoVotes(Event::ProposalImplementationApproved {
+			proposal_id: prop_id,
+			member: 3,
+		}));
+		assert_eq!(status(prop_id), ProposalStatus::Accepted);
+		assert_ok!(DaoVotes::mark_implemented(RuntimeOrigin::signed(2), prop_id));
+		System::assert_last_event(RuntimeEvent::DaoVotes(Event::ProposalImplemented {
+			proposal_id: prop_id,
+		}));
+		assert_eq!(status(prop_id), ProposalStatus::Implemented);
+	})
+}
+
+/// Create a proposal in the DAO created by account 1, cast the given votes, finalize it and
+/// return its status.
+fn vote_on_proposal(dao_id: Vec<u8>, votes: &[(u32, Vote)]) -> ProposalStatus {
+	let prop_id = setup_proposal::<Test>(1, dao_id);
+	for (voter, vote) in votes {
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(*voter), prop_id, Some(*vote), None));
+	}
+	run_to_block::<Test>(System::block_number() + 1);
+	assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+	Proposals::<Test>::get(prop_id).unwrap().status
+}
+
+#[test]
+fn voting_outcome_quorum() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"DAO".to_vec();
+		let origin = RuntimeOrigin::signed(1);
+
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"TEST DAO".to_vec()));
+		assert_ok!(DaoCore::issue_token(origin.clone(), dao_id.clone(), 1000));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 300));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 3, 100));
+
+		assert_noop!(
+			DaoVotes::set_governance_quorum_vote(origin.clone(), dao_id.clone(), 0, 10, 1025, 0),
+			Error::<Test>::ThresholdAbove1024
+		);
+		// at least half of the supply needs to vote, two thirds of the votes need to be ayes
+		assert_ok!(DaoVotes::set_governance_quorum_vote(origin, dao_id.clone(), 0, 10, 512, 680));
+
+		// the turnout is too low
+		assert_eq!(
+			vote_on_proposal(dao_id.clone(), &[(2, Vote::InFavor), (3, Vote::InFavor)]),
+			ProposalStatus::Rejected
+		);
+		// 600 of 900 votes are ayes
+		assert_eq!(
+			vote_on_proposal(dao_id.clone(), &[(1, Vote::InFavor), (2, Vote::Against)]),
+			ProposalStatus::Accepted
+		);
+		// 600 of 1000 votes are ayes
+		assert_eq!(
+			vote_on_proposal(
+				dao_id.clone(),
+				&[(1, Vote::InFavor), (2, Vote::Against), (3, Vote::Against)]
+			),
+			ProposalStatus::Rejected
+		);
+		// abstentions count towards the turnout, but not towards the share of ayes
+		assert_eq!(
+			vote_on_proposal(dao_id, &[(1, Vote::Abstain), (2, Vote::InFavor)]),
+			ProposalStatus::Accepted
+		);
+	})
+}
+
+#[test]
+fn voting_outcome_supermajority() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"DAO".to_vec();
+		let origin = RuntimeOrigin::signed(1);
+
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"TEST DAO".to_vec()));
+		assert_ok!(DaoCore::issue_token(origin.clone(), dao_id.clone(), 1000));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 300));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 3, 100));
+
+		assert_noop!(
+			DaoVotes::set_governance_supermajority_vote(
+				origin.clone(),
+				dao_id.clone(),
+				0,
+				10,
+				1025
+			),
+			Error::<Test>::ThresholdAbove1024
+		);
+		// two thirds of the supply need to vote aye
+		assert_ok!(DaoVotes::set_governance_supermajority_vote(origin, dao_id.clone(), 0, 10, 683));
+
+		// 600 ayes are a clear majority of the votes, but not of the supply
+		assert_eq!(
+			vote_on_proposal(dao_id.clone(), &[(1, Vote::InFavor)]),
+			ProposalStatus::Rejected
+		);
+		assert_eq!(
+			vote_on_proposal(dao_id, &[(1, Vote::InFavor), (2, Vote::Against), (3, Vote::InFavor)]),
+			ProposalStatus::Accepted
+		);
+	})
+}
+
+#[test]
+fn voting_outcome_contract() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"DAO".to_vec();
+		let origin = RuntimeOrigin::signed(1);
+
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"TEST DAO".to_vec()));
+		assert_ok!(DaoCore::issue_token(origin.clone(), dao_id.clone(), 1000));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 300));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 3, 100));
+
+		assert_noop!(
+			DaoVotes::set_governance_contract_vote(origin.clone(), dao_id.clone(), 0, 10, 7),
+			Error::<Test>::NotAContract
+		);
+		// the contract requires twice as many ayes as nays
+		assert_ok!(DaoVotes::set_governance_contract_vote(
+			origin.clone(),
+			dao_id.clone(),
+			0,
+			10,
+			42
+		));
+		System::assert_last_event(RuntimeEvent::DaoVotes(Event::SetGovernanceContractVote {
+			dao_id: dao_id.clone().try_into().unwrap(),
+			proposal_duration: 0,
+			proposal_token_deposit: 10,
+			contract: 42,
+		}));
+
+		assert_eq!(
+			vote_on_proposal(dao_id.clone(), &[(1, Vote::InFavor), (2, Vote::Against)]),
+			ProposalStatus::Accepted
+		);
+		assert_eq!(
+			vote_on_proposal(
+				dao_id.clone(),
+				&[(1, Vote::Against), (2, Vote::InFavor), (3, Vote::InFavor)]
+			),
+			ProposalStatus::Rejected
+		);
+
+		// a failing contract rejects proposals
+		assert_ok!(DaoVotes::set_governance_contract_vote(origin, dao_id.clone(), 0, 10, 43));
+		assert_eq!(vote_on_proposal(dao_id, &[(1, Vote::InFavor)]), ProposalStatus::Rejected);
+	})
+}
+
+#[test]
+fn voting_outcome_quadratic() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 64));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 3, 36));
+
+		assert_noop!(
+			DaoVotes::set_governance_quadratic_vote(
+				origin.clone(),
+				dao_id.clone(),
+				0,
+				1,
+				1025,
+				false
+			),
+			Error::<Test>::ThresholdAbove1024
+		);
+		// more than half of the cast votes need to be ayes
+		assert_ok!(DaoVotes::set_governance_quadratic_vote(
+			origin,
+			dao_id.clone(),
+			0,
+			1,
+			512,
+			false
+		));
+		let prop_id = setup_proposal::<Test>(1, dao_id.clone());
+		assert_eq!(DaoVotes::voting_power(prop_id, &1), Some(30));
+		assert_eq!(DaoVotes::voting_power(prop_id, &2), Some(8));
+
+		// the conviction multiplies the voting power, but locks the token balance
+		assert_ok!(DaoVotes::vote(
+			RuntimeOrigin::signed(2),
+			prop_id,
+			Some(Vote::InFavor),
+			Some(Conviction::Locked2x)
+		));
+		assert_eq!(VotingLocks::<Test>::get(&bounded_dao_id, 2).unwrap().amount, 64);
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(3), prop_id, Some(Vote::InFavor), None));
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(1), prop_id, Some(Vote::Against), None));
+		// 22 ayes of 100 tokens against 30 nays of 900 tokens
+		let proposal = Proposals::<Test>::get(prop_id).unwrap();
+		assert_eq!((proposal.in_favor, proposal.against), (22, 30));
+
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Rejected);
+
+		// 30 ayes of 900 tokens against 14 nays of 100 tokens
+		assert_eq!(
+			vote_on_proposal(dao_id, &[(1, Vote::InFavor), (2, Vote::Against), (3, Vote::Against)]),
+			ProposalStatus::Accepted
+		);
+	})
+}
+
+#[test]
+fn quadratic_voting_counts_verified_persons_once() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 100));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 11, 100));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 100, 100));
+		Balances::make_free_balance_be(&2, 10);
+		assert_ok!(DaoVotes::delegate(RuntimeOrigin::signed(2), dao_id.clone(), 11));
+
+		// persons cannot be told apart without a personhood provider
+		PersonhoodAvailable::set(false);
+		assert_noop!(
+			DaoVotes::set_governance_quadratic_vote(
+				origin.clone(),
+				dao_id.clone(),
+				0,
+				1,
+				512,
+				true
+			),
+			Error::<Test>::PersonhoodUnavailable
+		);
+		PersonhoodAvailable::set(true);
+		assert_ok!(DaoVotes::set_governance_quadratic_vote(
+			origin.clone(),
+			dao_id.clone(),
+			0,
+			1,
+			512,
+			true
+		));
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+
+		assert_noop!(
+			DaoVotes::vote(RuntimeOrigin::signed(100), prop_id, Some(Vote::InFavor), None),
+			Error::<Test>::VoterNotVerified
+		);
+
+		// accounts 1 and 11 belong to the same person
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id, Some(Vote::Against), None));
+		assert_noop!(
+			DaoVotes::vote(RuntimeOrigin::signed(11), prop_id, Some(Vote::InFavor), None),
+			Error::<Test>::PersonHasVoted
+		);
+		assert_ok!(DaoVotes::vote(origin, prop_id, None, None));
+
+		// delegations do not count when voting per person
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(11), prop_id, Some(Vote::InFavor), None));
+		let proposal = Proposals::<Test>::get(prop_id).unwrap();
+		assert_eq!((proposal.in_favor, proposal.against), (10, 0));
+	})
+}
+
+#[test]
+fn accepted_proposal_dispatches_calls() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"DAO".to_vec();
+		let dao_name = b"TEST DAO".to_vec();
+		let origin = RuntimeOrigin::signed(1);
+		let sender = ensure_signed(origin.clone()).unwrap();
+
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), dao_name));
+		assert_ok!(DaoCore::issue_token(origin.clone(), dao_id.clone(), 1000));
+		assert_ok!(DaoVotes::set_governance_majority_vote(
+			origin.clone(),
+			dao_id.clone(),
+			0,
+			100,
+			0
+		));
+
+		let prop_id = create_proposal_id::<Test>(sender, dao_id.clone());
+		let set_governance: RuntimeCall = crate::Call::set_governance_majority_vote {
+			dao_id: dao_id.clone(),
+			proposal_duration: 10,
+			proposal_token_deposit: 100,
+			minimum_majority_per_1024: 0,
+		}
+		.into();
+		// the DAO treasury has no funds, so this call fails
+		let spend: RuntimeCall =
+			pallet_dao_core::Call::spend { dao_id: dao_id.clone(), beneficiary: 2, amount: 5 }
+				.into();
+
+		assert_noop!(
+			DaoVotes::set_calls(RuntimeOrigin::signed(2), prop_id, vec![spend.clone()]),
+			Error::<Test>::SenderIsNotProposalCreator
+		);
+		assert_noop!(
+			DaoVotes::set_calls(origin.clone(), prop_id, vec![spend.clone(); 5]),
+			Error::<Test>::TooManyCalls
+		);
+		assert_ok!(DaoVotes::set_calls(origin.clone(), prop_id, vec![set_governance, spend]));
+
+		setup_proposal_with_id::<Test>(sender, prop_id);
+		// calls are fixed once voting started
+		assert_noop!(
+			DaoVotes::set_calls(origin.clone(), prop_id, vec![]),
+			Error::<Test>::ProposalDoesNotExist
+		);
+
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id, Some(Vote::InFavor), None));
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(origin, prop_id));
+
+		let dao = pallet_dao_core::Pallet::<Test>::load_dao(dao_id).unwrap();
+		assert_eq!(Governances::<Test>::get(&dao.id).unwrap().proposal_duration, 10);
+		let results = ProposalCallResults::<Test>::get(prop_id).unwrap();
+		assert_eq!(results.len(), 2);
+		assert_ok!(results[0]);
+		assert!(results[1].is_err());
+	})
+}
+
+#[test]
+fn dao_origin_can_set_governance() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"DAO".to_vec();
+		let origin = RuntimeOrigin::signed(1);
+
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"TEST DAO".to_vec()));
+		assert_ok!(DaoCore::create_dao(origin, b"OTHER".to_vec(), b"OTHER DAO".to_vec()));
+
+		// neither other accounts nor other DAOs may configure the governance
+		assert_noop!(
+			DaoVotes::set_governance_majority_vote(
+				RuntimeOrigin::signed(2),
+				dao_id.clone(),
+				10,
+				100,
+				0
+			),
+			DaoError::<Test>::DaoSignerNotOwner
+		);
+		let other_dao_id: DaoIdOf<Test> = b"OTHER".to_vec().try_into().unwrap();
+		let other_dao: RuntimeOrigin = pallet_dao_core::RawOrigin::Dao(other_dao_id).into();
+		assert_noop!(
+			DaoVotes::set_governance_majority_vote(other_dao, dao_id.clone(), 10, 100, 0),
+			DaoError::<Test>::DaoOriginMismatch
+		);
+
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let dao_origin: RuntimeOrigin =
+			pallet_dao_core::RawOrigin::Dao(bounded_dao_id.clone()).into();
+		assert_ok!(DaoVotes::set_governance_majority_vote(
+			dao_origin.clone(),
+			dao_id.clone(),
+			10,
+			100,
+			0
+		));
+		assert_eq!(Governances::<Test>::get(&bounded_dao_id).unwrap().proposal_duration, 10);
+
+		// the DAO origin is not a signed origin
+		assert_noop!(DaoVotes::create_proposal(dao_origin, dao_id, None), DispatchError::BadOrigin);
+	})
+}
+
+#[test]
+fn ended_proposals_are_finalized_automatically() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let first = setup_proposal::<Test>(1, dao_id.clone());
+		let second = setup_proposal::<Test>(1, dao_id);
+		assert_eq!(Balances::reserved_balance(1), 10 + 2 * 10);
+
+		// proposals are still running in the block they have been created
+		let block = System::block_number();
+		DaoVotes::on_idle(block, Weight::MAX);
+		assert_eq!(Proposals::<Test>::get(first).unwrap().status, ProposalStatus::Running);
+
+		run_to_block::<Test>(block + 1);
+		DaoVotes::on_idle(block + 1, Weight::MAX);
+		for prop_id in [first, second] {
+			assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Rejected);
+		}
+		// the proposal deposits have been returned to the creator, except for the pruning rewards
+		assert_eq!(Balances::reserved_balance(1), 10 + 2 * 1);
+
+		assert_noop!(
+			DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), first),
+			Error::<Test>::ProposalStatusNotRunning
+		);
+	})
+}
+
+#[test]
+fn proposals_failing_to_finalize_are_faulted() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+
+		// the token deposit cannot be returned once the DAO token is being destroyed
+		let block = System::block_number() + 1;
+		run_to_block::<Test>(block);
+		pallet_dao_assets::Asset::<Test>::mutate(asset_id, |details| {
+			details.as_mut().unwrap().status = AssetStatus::Destroying
+		});
+		DaoVotes::on_idle(block, Weight::MAX);
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Faulty);
+		System::assert_last_event(RuntimeEvent::DaoVotes(Event::ProposalFaulted {
+			proposal_id: prop_id,
+			reason: b"AssetNotLive".to_vec(),
+		}));
+		// the deposit is returned, except for the pruning reward
+		assert_eq!(Balances::reserved_balance(1), 10 + 1);
+		assert_eq!(ProposalTokenDeposits::<Test>::get(prop_id), Some(1));
+	})
+}
+
+#[test]
+fn automatic_finalization_respects_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let first = setup_proposal::<Test>(1, dao_id.clone());
+		let second = setup_proposal::<Test>(1, dao_id);
+
+		let block = System::block_number() + 1;
+		run_to_block::<Test>(block);
+		// room for a single proposal only
+		let weight = <Test as Config>::WeightInfo::finalize_proposal(0)
+			.saturating_add(MaxProposalWeight::get());
+		DaoVotes::on_idle(block, weight);
+		assert_eq!(Proposals::<Test>::get(second).unwrap().status, ProposalStatus::Rejected);
+		assert_eq!(Proposals::<Test>::get(first).unwrap().status, ProposalStatus::Running);
+
+		// the remaining proposal is finalized in a later block
+		run_to_block::<Test>(block + 1);
+		DaoVotes::on_idle(block + 1, Weight::MAX);
+		assert_eq!(Proposals::<Test>::get(first).unwrap().status, ProposalStatus::Rejected);
+	})
+}
+
+#[test]
+fn finalizing_returns_the_deposit_to_the_creator() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 2, 10));
+
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(2), prop_id));
+		// the pruning reward is kept
+		assert_eq!(Balances::reserved_balance(1), 10 + 1);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	})
+}
+
+#[test]
+fn anyone_can_prune_a_finalized_proposal_after_the_retention_period() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), asset_id, 2, 300));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(1), 2, 10));
+
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(2), prop_id, Some(Vote::InFavor), None));
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(1), prop_id, Some(Vote::Against), None));
+		assert_noop!(
+			DaoVotes::prune_proposal(RuntimeOrigin::signed(2), prop_id),
+			Error::<Test>::ProposalStillRunning
+		);
+
+		let birth_block = System::block_number();
+		run_to_block::<Test>(birth_block + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		let proposal = Proposals::<Test>::get(prop_id).unwrap();
+		let tallies = OptionTallies::<Test>::get(prop_id);
+
+		let retention = <Test as Config>::ProposalRetentionPeriod::get();
+		run_to_block::<Test>(birth_block + retention);
+		assert_noop!(
+			DaoVotes::prune_proposal(RuntimeOrigin::signed(2), prop_id),
+			Error::<Test>::ProposalRetentionHasNotPassed
+		);
+
+		run_to_block::<Test>(birth_block + retention + 1);
+		assert_ok!(DaoVotes::prune_proposal(RuntimeOrigin::signed(2), prop_id));
+		System::assert_last_event(RuntimeEvent::DaoVotes(Event::ProposalPruned {
+			proposal_id: prop_id,
+		}));
+		assert_eq!(Proposals::<Test>::get(prop_id), None);
+		assert_eq!(Votes::<Test>::iter_prefix(prop_id).count(), 0);
+		assert!(!DaoProposals::<Test>::contains_key(&bounded_dao_id, prop_id));
+
+		// the proposal is collapsed to its result
+		let tally = (proposal.in_favor, proposal.against, proposal.abstain, tallies);
+		assert_eq!(
+			DaoVotes::proposal_result(prop_id),
+			Some(ProposalResult {
+				dao_id: bounded_dao_id,
+				status: proposal.status,
+				tally_hash: <Test as frame_system::Config>::Hashing::hash_of(&tally),
+			})
+		);
+
+		// the pruning reward has been paid from the proposal deposit
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10 + 1);
+
+		assert_noop!(
+			DaoVotes::prune_proposal(RuntimeOrigin::signed(2), prop_id),
+			Error::<Test>::ProposalAlreadyPruned
+		);
+		assert_noop!(
+			DaoVotes::prune_proposal(RuntimeOrigin::signed(2), prop_id + 1),
+			Error::<Test>::ProposalDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn pruning_is_continued_until_all_votes_are_removed() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		let birth_block = System::block_number();
+		run_to_block::<Test>(birth_block + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+
+		// more votes than can be removed at once
+		let limit = <Test as pallet_dao_assets::Config>::RemoveItemsLimit::get();
+		for voter in 0..u64::from(limit) + 10 {
+			Votes::<Test>::insert(prop_id, 100 + voter, Vote::InFavor);
+		}
+		// proposals created before DaoProposals was kept are pruned as well
+		DaoProposals::<Test>::remove(&bounded_dao_id, prop_id);
+
+		let retention = <Test as Config>::ProposalRetentionPeriod::get();
+		run_to_block::<Test>(birth_block + retention + 1);
+		assert_ok!(DaoVotes::prune_proposal(RuntimeOrigin::signed(2), prop_id));
+		assert!(DaoVotes::proposal_result(prop_id).is_some());
+		assert!(PruningProposals::<Test>::contains_key(prop_id));
+		assert_eq!(Votes::<Test>::iter_prefix(prop_id).count(), 10);
+		assert!(System::events().iter().all(|record| record.event !=
+			RuntimeEvent::DaoVotes(Event::ProposalPruned { proposal_id: prop_id })));
+
+		assert_ok!(DaoVotes::prune_proposal(RuntimeOrigin::signed(2), prop_id));
+		System::assert_last_event(RuntimeEvent::DaoVotes(Event::ProposalPruned {
+			proposal_id: prop_id,
+		}));
+		assert_eq!(Votes::<Test>::iter_prefix(prop_id).count(), 0);
+		assert!(!PruningProposals::<Test>::contains_key(prop_id));
+		// the reward is only paid once
+		assert_eq!(Balances::free_balance(2), 1);
+
+		assert_noop!(
+			DaoVotes::prune_proposal(RuntimeOrigin::signed(2), prop_id),
+			Error::<Test>::ProposalAlreadyPruned
+		);
+	})
+}
+
+#[test]
+fn token_deposit_is_returned_on_finalization() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		assert_eq!(Assets::reserved(asset_id, 1), 1);
+
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		assert_eq!(Assets::reserved(asset_id, 1), 0);
+		assert_eq!(Assets::balance(asset_id, 1), 1000);
+		System::assert_has_event(RuntimeEvent::DaoVotes(Event::ProposalTokenDepositReturned {
+			proposal_id: prop_id,
+			creator: 1,
+			amount: 1,
+		}));
+	})
+}
+
+#[test]
+fn token_deposit_of_faulty_proposal_is_burned() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+
+		assert_ok!(DaoVotes::fault_proposal(RuntimeOrigin::signed(1), prop_id, b"bad".to_vec()));
+		assert_eq!(Assets::reserved(asset_id, 1), 0);
+		assert_eq!(Assets::balance(asset_id, 1), 999);
+		assert_eq!(Assets::total_supply(asset_id), 999);
+		System::assert_has_event(RuntimeEvent::DaoVotes(Event::ProposalTokenDepositBurned {
+			proposal_id: prop_id,
+			creator: 1,
+			amount: 1,
+		}));
+	})
+}
+
+#[test]
+fn token_deposit_of_faulty_proposal_can_be_sent_to_treasury() {
+	new_test_ext().execute_with(|| {
+		FaultyDepositSlash::set(DepositSlash::Treasury);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+
+		assert_ok!(DaoVotes::fault_proposal(RuntimeOrigin::signed(1), prop_id, b"bad".to_vec()));
+		assert_eq!(Assets::reserved(asset_id, 1), 0);
+		assert_eq!(Assets::balance(asset_id, 1), 999);
+		assert_eq!(DaoCore::treasury_token_balance(&bounded_dao_id), 1);
+		assert_eq!(Assets::total_supply(asset_id), 1000);
+		System::assert_has_event(RuntimeEvent::DaoVotes(
+			Event::ProposalTokenDepositSentToTreasury {
+				proposal_id: prop_id,
+				creator: 1,
+				amount: 1,
+			},
+		));
+	})
+}
+
+#[test]
+fn can_cancel_a_proposal_slot() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		let prop_id = create_proposal_id::<Test>(1, dao_id);
+		assert_eq!(Balances::reserved_balance(1), 20);
+		assert_eq!(Assets::reserved(asset_id, 1), 1);
+		let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		assert_ok!(DaoVotes::set_calls(RuntimeOrigin::signed(1), prop_id, vec![remark]));
+
+		assert_noop!(
+			DaoVotes::cancel_proposal(RuntimeOrigin::signed(2), prop_id),
+			Error::<Test>::SenderIsNotProposalCreator
+		);
+		assert_ok!(DaoVotes::cancel_proposal(RuntimeOrigin::signed(1), prop_id));
+		assert!(!<ProposalSlots<Test>>::contains_key(prop_id));
+		assert!(!ProposalCalls::<Test>::contains_key(prop_id));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Assets::reserved(asset_id, 1), 0);
+
+		// a proposal with metadata cannot be cancelled
+		let prop_id = setup_proposal::<Test>(1, b"GDAO".to_vec());
+		assert_noop!(
+			DaoVotes::cancel_proposal(RuntimeOrigin::signed(1), prop_id),
+			Error::<Test>::ProposalDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn anyone_can_expire_a_proposal_slot() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let prop_id = create_proposal_id::<Test>(1, dao_id);
+
+		let expiry = <Test as Config>::ProposalSlotExpiry::get();
+		run_to_block::<Test>(System::block_number() + expiry);
+		assert_noop!(
+			DaoVotes::expire_proposal_slot(RuntimeOrigin::signed(2), prop_id),
+			Error::<Test>::ProposalSlotNotExpired
+		);
+
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::expire_proposal_slot(RuntimeOrigin::signed(2), prop_id));
+		assert!(!<ProposalSlots<Test>>::contains_key(prop_id));
+		// the deposit is returned to the creator
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_noop!(
+			DaoVotes::set_metadata(RuntimeOrigin::signed(1), prop_id, vec![], vec![]),
+			Error::<Test>::ProposalDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn can_create_a_proposal_with_metadata() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let metadata = b"http://my.cool.proposal".to_vec();
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+
+		assert_ok!(DaoVotes::create_proposal(
+			RuntimeOrigin::signed(1),
+			dao_id,
+			Some((metadata.clone(), hash))
+		));
+		let prop_id = DaoVotes::get_current_proposal_id();
+		assert!(!<ProposalSlots<Test>>::contains_key(prop_id));
+		let proposal = Proposals::<Test>::get(prop_id).unwrap();
+		assert_eq!(proposal.status, ProposalStatus::Running);
+		assert_eq!(proposal.meta.to_vec(), metadata);
+	})
+}
+
+#[test]
+fn proposal_metadata_of_strict_dao_needs_to_be_on_ipfs() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+		assert_ok!(DaoCore::set_strict_metadata(RuntimeOrigin::signed(1), dao_id.clone(), true));
+
+		assert_noop!(
+			DaoVotes::create_proposal(
+				RuntimeOrigin::signed(1),
+				dao_id.clone(),
+				Some((b"http://my.cool.proposal".to_vec(), hash.clone()))
+			),
+			DaoError::<Test>::MetadataInvalid
+		);
+		assert_ok!(DaoVotes::create_proposal(
+			RuntimeOrigin::signed(1),
+			dao_id,
+			Some((b"ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(), hash))
+		));
+	})
+}
+
+#[test]
+fn conviction_multiplies_voting_power_and_locks_tokens() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao::<Test>(1);
+		let proposal_duration = 2;
+		assert_ok!(DaoVotes::set_governance_majority_vote(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			proposal_duration,
+			1,
+			10
+		));
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), asset_id, 2, 300));
+		let prop_id = setup_proposal::<Test>(1, dao_id.clone());
+
+		assert_ok!(DaoVotes::vote(
+			RuntimeOrigin::signed(2),
+			prop_id,
+			Some(Vote::InFavor),
+			Some(Conviction::Locked3x)
+		));
+		let proposal = Proposals::<Test>::get(prop_id).unwrap();
+		assert_eq!(proposal.in_favor, 900);
+		assert_eq!(Assets::reserved(asset_id, 2), 300);
+		// locked until two proposal durations after the end of the proposal
+		let birth_block = proposal.birth_block;
+		let until = birth_block + 2 + 2 * 2;
+		assert_eq!(VotingLocks::<Test>::get(&bounded_dao_id, 2).unwrap().until, until);
+
+		// changing the conviction recounts the vote, the lock is kept
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(2), prop_id, Some(Vote::InFavor), None));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().in_favor, 300);
+		assert_eq!(Assets::reserved(asset_id, 2), 300);
+
+		run_to_block::<Test>(until);
+		assert_noop!(
+			DaoVotes::unlock(RuntimeOrigin::signed(2), dao_id.clone()),
+			Error::<Test>::VotingLockNotExpired
+		);
+		run_to_block::<Test>(until + 1);
+		assert_ok!(DaoVotes::unlock(RuntimeOrigin::signed(2), dao_id.clone()));
+		assert_eq!(Assets::reserved(asset_id, 2), 0);
+		assert_eq!(Assets::balance(asset_id, 2), 300);
+		assert_noop!(
+			DaoVotes::unlock(RuntimeOrigin::signed(2), dao_id),
+			Error::<Test>::NoVotingLock
+		);
+	})
+}
+
+#[test]
+fn conviction_requires_tokens_to_lock() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao::<Test>(1);
+		assert_ok!(DaoVotes::set_governance_majority_vote(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			2,
+			1,
+			10
+		));
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), asset_id, 2, 300));
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		run_to_block::<Test>(System::block_number() + 1);
+
+		// the voting power at proposal creation cannot be locked after selling the tokens
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), asset_id, 3, 300));
+		assert!(DaoVotes::vote(
+			RuntimeOrigin::signed(2),
+			prop_id,
+			Some(Vote::InFavor),
+			Some(Conviction::Locked2x)
+		)
+		.is_err());
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(2), prop_id, Some(Vote::InFavor), None));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().in_favor, 300);
+	})
+}
+
+#[test]
+fn unvested_tokens_count_towards_voting_power() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		let schedule = VestingSchedule { locked: 300, per_block: 1, start: 1, cliff: 100 };
+		assert_ok!(Assets::vested_transfer(RuntimeOrigin::signed(1), asset_id, 2, schedule));
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+
+		// locked tokens cannot back a conviction
+		assert!(DaoVotes::vote(
+			RuntimeOrigin::signed(2),
+			prop_id,
+			Some(Vote::InFavor),
+			Some(Conviction::Locked2x)
+		)
+		.is_err());
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(2), prop_id, Some(Vote::InFavor), None));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().in_favor, 300);
+	})
+}
+
+#[test]
+fn options_can_be_set_on_proposal_slots() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let prop_id = create_proposal_id::<Test>(1, dao_id.clone());
+
+		assert_noop!(
+			DaoVotes::set_options(RuntimeOrigin::signed(2), prop_id, 3, Tallying::Plurality),
+			Error::<Test>::SenderIsNotProposalCreator
+		);
+		assert_noop!(
+			DaoVotes::set_options(origin.clone(), prop_id, 1, Tallying::Plurality),
+			Error::<Test>::InvalidOptions
+		);
+		assert_noop!(
+			DaoVotes::set_options(origin.clone(), prop_id, 5, Tallying::Plurality),
+			Error::<Test>::InvalidOptions
+		);
+		assert_ok!(DaoVotes::set_options(origin.clone(), prop_id, 4, Tallying::RankedChoice));
+		System::assert_has_event(RuntimeEvent::DaoVotes(Event::ProposalOptionsSet {
+			proposal_id: prop_id,
+			options: 4,
+			tallying: Tallying::RankedChoice,
+		}));
+		assert_eq!(DaoVotes::option_tallies(prop_id), vec![0; 4]);
+
+		// a multiple-choice proposal has no calls to dispatch
+		let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		assert_noop!(
+			DaoVotes::set_calls(origin.clone(), prop_id, vec![remark.clone()]),
+			Error::<Test>::ProposalHasOptions
+		);
+		let other_id = create_proposal_id::<Test>(1, dao_id);
+		assert_ok!(DaoVotes::set_calls(origin.clone(), other_id, vec![remark]));
+		assert_noop!(
+			DaoVotes::set_options(origin, other_id, 2, Tallying::Plurality),
+			Error::<Test>::ProposalHasCalls
+		);
+	})
+}
+
+#[test]
+fn plurality_proposal_is_won_by_most_voted_option() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 300));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 3, 200));
+		let prop_id = create_proposal_id::<Test>(1, dao_id);
+		assert_ok!(DaoVotes::set_options(origin.clone(), prop_id, 3, Tallying::Plurality));
+		setup_proposal_with_id::<Test>(1, prop_id);
+
+		assert_noop!(
+			DaoVotes::vote(origin.clone(), prop_id, Some(Vote::InFavor), None),
+			Error::<Test>::ProposalHasOptions
+		);
+		assert_noop!(
+			DaoVotes::vote_options(origin.clone(), prop_id, vec![0, 1]),
+			Error::<Test>::InvalidRanking
+		);
+		assert_noop!(
+			DaoVotes::vote_options(origin.clone(), prop_id, vec![3]),
+			Error::<Test>::InvalidRanking
+		);
+
+		assert_ok!(DaoVotes::vote_options(origin, prop_id, vec![0]));
+		assert_ok!(DaoVotes::vote_options(RuntimeOrigin::signed(2), prop_id, vec![1]));
+		assert_ok!(DaoVotes::vote_options(RuntimeOrigin::signed(3), prop_id, vec![1]));
+		assert_eq!(DaoVotes::option_tallies(prop_id), vec![500, 500, 0]);
+
+		// changing a vote moves its voting power
+		assert_ok!(DaoVotes::vote_options(RuntimeOrigin::signed(3), prop_id, vec![2]));
+		assert_eq!(DaoVotes::option_vote_of(prop_id, &3), Some(vec![2]));
+		assert_eq!(DaoVotes::option_tallies(prop_id), vec![500, 300, 200]);
+
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		System::assert_has_event(RuntimeEvent::DaoVotes(Event::OptionProposalDecided {
+			proposal_id: prop_id,
+			winner: Some(0),
+			tallies: vec![500, 300, 200],
+		}));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Accepted);
+		assert_eq!(ProposalWinners::<Test>::get(prop_id), Some(0));
+	})
+}
+
+#[test]
+fn multiple_choice_proposal_requires_the_participation_of_the_governance() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 300));
+		// half of the supply needs to vote
+		assert_ok!(DaoVotes::set_governance_quorum_vote(
+			origin.clone(),
+			dao_id.clone(),
+			0,
+			1,
+			512,
+			512
+		));
+		let options_proposal = || {
+			let prop_id = create_proposal_id::<Test>(1, dao_id.clone());
+			assert_ok!(DaoVotes::set_options(origin.clone(), prop_id, 2, Tallying::Plurality));
+			setup_proposal_with_id::<Test>(1, prop_id);
+			prop_id
+		};
+
+		let prop_id = options_proposal();
+		assert_ok!(DaoVotes::vote_options(RuntimeOrigin::signed(2), prop_id, vec![1]));
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		System::assert_has_event(RuntimeEvent::DaoVotes(Event::OptionProposalDecided {
+			proposal_id: prop_id,
+			winner: None,
+			tallies: vec![0, 300],
+		}));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Rejected);
+		assert_eq!(ProposalWinners::<Test>::get(prop_id), None);
+
+		let prop_id = options_proposal();
+		assert_ok!(DaoVotes::vote_options(RuntimeOrigin::signed(1), prop_id, vec![0]));
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Accepted);
+		assert_eq!(ProposalWinners::<Test>::get(prop_id), Some(0));
+	})
+}
+
+#[test]
+fn ranked_choice_proposal_is_decided_by_instant_runoff() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		assert_ok!(Assets::transfer(origin.clone(), 1, 2, 350));
+		assert_ok!(Assets::transfer(origin.clone(), 1, 3, 250));
+		let prop_id = create_proposal_id::<Test>(1, dao_id.clone());
+		assert_ok!(DaoVotes::set_options(origin.clone(), prop_id, 3, Tallying::RankedChoice));
+		setup_proposal_with_id::<Test>(1, prop_id);
+
+		assert_noop!(
+			DaoVotes::vote_options(origin.clone(), prop_id, vec![1, 1]),
+			Error::<Test>::InvalidRanking
+		);
+		assert_ok!(DaoVotes::vote_options(origin.clone(), prop_id, vec![0]));
+		assert_ok!(DaoVotes::vote_options(RuntimeOrigin::signed(2), prop_id, vec![1, 0]));
+		assert_ok!(DaoVotes::vote_options(RuntimeOrigin::signed(3), prop_id, vec![2, 1]));
+		assert_eq!(DaoVotes::option_tallies(prop_id), vec![400, 350, 250]);
+
+		// a removed vote is no longer counted
+		assert_ok!(DaoVotes::vote_options(origin.clone(), prop_id, vec![]));
+		assert_eq!(DaoVotes::option_vote_of(prop_id, &1), None);
+		assert_eq!(DaoVotes::option_tallies(prop_id), vec![0, 350, 250]);
+		assert_ok!(DaoVotes::vote_options(origin.clone(), prop_id, vec![0]));
+
+		// the least voted option is eliminated, its votes go to the next ranked option
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		System::assert_has_event(RuntimeEvent::DaoVotes(Event::OptionProposalDecided {
+			proposal_id: prop_id,
+			winner: Some(1),
+			tallies: vec![400, 600, 0],
+		}));
+		assert_eq!(DaoVotes::option_tallies(prop_id), vec![400, 600, 0]);
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Accepted);
+
+		// without votes there is no winner
+		let prop_id = create_proposal_id::<Test>(1, dao_id);
+		assert_ok!(DaoVotes::set_options(origin, prop_id, 2, Tallying::RankedChoice));
+		setup_proposal_with_id::<Test>(1, prop_id);
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Rejected);
+		assert_eq!(ProposalWinners::<Test>::get(prop_id), None);
+	})
+}
+
+#[test]
+fn destroying_a_dao_removes_its_proposals_and_returns_deposits() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), asset_id, 2, 300));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), asset_id, 3, 100));
+
+		// a running proposal with votes, a proposal slot, a voting lock and a delegation
+		let running = setup_proposal::<Test>(1, dao_id.clone());
+		let slot = create_proposal_id::<Test>(1, dao_id.clone());
+		assert_ok!(DaoVotes::vote(
+			RuntimeOrigin::signed(2),
+			running,
+			Some(Vote::InFavor),
+			Some(Conviction::Locked2x)
+		));
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(1), running, Some(Vote::Against), None));
+		Balances::make_free_balance_be(&3, 10);
+		assert_ok!(DaoVotes::delegate(RuntimeOrigin::signed(3), dao_id.clone(), 2));
+		assert_eq!(Balances::reserved_balance(1), 30);
+		assert_eq!(Balances::reserved_balance(3), 5);
+		assert_eq!(Assets::reserved(asset_id, 1), 2);
+		assert_eq!(Assets::reserved(asset_id, 2), 300);
+
+		// the items are removed in pages
+		assert!(!<DaoVotes as DaoTeardown<_>>::teardown(&bounded_dao_id, 2));
+		assert!(!Governances::<Test>::contains_key(&bounded_dao_id));
+
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(1), dao_id.clone()));
+		assert_eq!(DaoCore::get_dao(&bounded_dao_id), None);
+		assert_eq!(Proposals::<Test>::get(running), None);
+		assert_eq!(ProposalSlots::<Test>::get(slot), None);
+		assert_eq!(Votes::<Test>::iter_prefix(running).count(), 0);
+		assert_eq!(VotingLocks::<Test>::iter_prefix(&bounded_dao_id).count(), 0);
+		assert_eq!(Delegations::<Test>::iter_prefix(&bounded_dao_id).count(), 0);
+		assert_eq!(Delegators::<Test>::iter_prefix(&bounded_dao_id).count(), 0);
+
+		// the deposits of the proposals, the delegation and the DAO have been returned, the
+		// token is destroyed
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		let asset = pallet_dao_assets::Asset::<Test>::get(asset_id).unwrap();
+		assert_eq!(asset.status, pallet_dao_assets::AssetStatus::Destroyed);
+	})
+}
+
+#[test]
+fn proposal_without_snapshot_is_faulted_on_finalization() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+		ProposalSnapshots::<Test>::remove(prop_id);
+
+		assert_noop!(
+			DaoVotes::vote(RuntimeOrigin::signed(1), prop_id, Some(Vote::InFavor), None),
+			AssetError::<Test>::SnapshotUnavailable
+		);
+
+		// the creator is not to blame, the token deposit is returned
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(2), prop_id));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Faulty);
+		assert_eq!(Assets::reserved(asset_id, 1), 0);
+		System::assert_last_event(RuntimeEvent::DaoVotes(Event::ProposalFaulted {
+			proposal_id: prop_id,
+			reason: b"SnapshotUnavailable".to_vec(),
+		}));
+	})
+}
+
+#[test]
+fn dao_token_cannot_be_destroyed_while_proposals_are_running() {
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		let prop_id = setup_proposal::<Test>(1, dao_id);
+
+		assert_noop!(Assets::do_start_destroy(asset_id, None), AssetError::<Test>::SnapshotInUse);
+
+		// the snapshot is released once the proposal is finalized
+		run_to_block::<Test>(System::block_number() + 1);
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), prop_id));
+		assert_eq!(ProposalSnapshots::<Test>::get(prop_id), None);
+		assert_ok!(Assets::do_start_destroy(asset_id, None));
+	})
+}
+
+#[test]
+fn migration_snapshots_and_translates_proposals() {
+	use crate::migrations::v1::{self, v0};
+	use frame_support::traits::{
+		GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+	};
+
+	new_test_ext().execute_with(|| {
+		let dao_id = setup_dao_with_governance::<Test>(1);
+		let bounded_dao_id: DaoIdOf<Test> = dao_id.try_into().unwrap();
+		let gone: DaoIdOf<Test> = b"GONE".to_vec().try_into().unwrap();
+		let proposal = |dao_id: &DaoIdOf<Test>, status| v0::Proposal {
+			dao_id: dao_id.clone(),
+			creator: 1,
+			birth_block: 1,
+			meta: Default::default(),
+			meta_hash: Default::default(),
+			status,
+			in_favor: 30,
+			against: 20,
+		};
+		StorageVersion::new(0).put::<DaoVotes>();
+		v0::Proposals::<Test>::insert(1, proposal(&bounded_dao_id, ProposalStatus::Running));
+		v0::Proposals::<Test>::insert(2, proposal(&bounded_dao_id, ProposalStatus::Accepted));
+		// the DAO of a running proposal is gone
+		v0::Proposals::<Test>::insert(3, proposal(&gone, ProposalStatus::Running));
+		assert_ok!(Balances::reserve(&1, 10));
+		// the creator reserves the token deposit of one proposal only
+		let asset_id = DaoCore::get_dao(&bounded_dao_id).unwrap().asset_id.unwrap();
+		assert_ok!(Assets::do_reserve(asset_id, &1, 1));
+		v0::ProposalSlots::<Test>::insert(
+			4,
+			v0::ProposalSlot { dao_id: bounded_dao_id.clone(), creator: 1 },
+		);
+
+		run_to_block::<Test>(5);
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(DaoVotes::on_chain_storage_version(), 1);
+		assert_eq!(ProposalSnapshots::<Test>::get(1), Some(1));
+		assert_eq!(ProposalSnapshots::<Test>::get(2), None);
+		assert_eq!(ProposalSnapshots::<Test>::get(3), None);
+		assert_eq!(ProposalSnapshots::<Test>::get(4), Some(2));
+
+		// the token deposits are recorded as far as they are reserved
+		assert_eq!(ProposalTokenDeposits::<Test>::get(1), Some(1));
+		assert_eq!(ProposalTokenDeposits::<Test>::get(3), None);
+		assert_eq!(ProposalTokenDeposits::<Test>::get(4), None);
+
+		// the proposals and proposal slots are indexed by DAO
+		for proposal_id in [1, 2, 4] {
+			assert!(DaoProposals::<Test>::contains_key(&bounded_dao_id, proposal_id));
+		}
+		assert!(DaoProposals::<Test>::contains_key(&gone, 3));
+
+		// the proposals are translated, without abstentions
+		let migrated = Proposals::<Test>::get(1).unwrap();
+		assert_eq!((migrated.in_favor, migrated.against, migrated.abstain), (30, 20, 0));
+		assert_eq!(migrated.status, ProposalStatus::Running);
+		assert_eq!(Proposals::<Test>::get(2).unwrap().status, ProposalStatus::Accepted);
+
+		// the proposal slot expires counting from the upgrade
+		assert_eq!(
+			ProposalSlots::<Test>::get(4),
+			Some(ProposalSlot { dao_id: bounded_dao_id, creator: 1, birth_block: 5 })
+		);
+
+		// the faulty proposal returned its deposit
+		assert_eq!(Proposals::<Test>::get(3).unwrap().status, ProposalStatus::Faulty);
+		assert_eq!(Balances::reserved_balance(1), 10);
+		System::assert_has_event(RuntimeEvent::DaoVotes(Event::ProposalFaulted {
+			proposal_id: 3,
+			reason: b"DaoDoesNotExist".to_vec(),
+		}));
+
+		// the recorded token deposit is returned on finalization
+		assert_ok!(DaoVotes::finalize_proposal(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Assets::reserved(asset_id, 1), 0);
+	})
+}
+
+#[test]
+fn instant_runoff_handles_ties() {
+	let ranking =
+		|options: Vec<OptionIndex>| -> crate::RankingOf<Test> { options.try_into().unwrap() };
+
+	// options tied for the least voting power are eliminated together
+	let rankings = [(ranking(vec![0]), 40), (ranking(vec![1, 0]), 30), (ranking(vec![2, 0]), 30)];
+	let (winner, tallies) = DaoVotes::instant_runoff(3, &rankings);
+	assert_eq!(winner, Some(0));
+	assert_eq!(tallies.into_inner(), vec![100, 0, 0]);
+
+	// there is no winner if all remaining options are tied
+	let rankings = [(ranking(vec![0]), 30), (ranking(vec![1, 2]), 30)];
+	let (winner, tallies) = DaoVotes::instant_runoff(3, &rankings);
+	assert_eq!(winner, None);
+	assert_eq!(tallies.into_inner(), vec![30, 30, 0]);
+}
@@ -5,14 +5,18 @@ use frame_support::{
 	BoundedVec, RuntimeDebug,
 };
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub struct ProposalSlot<DaoId, AccountId> {
+pub struct ProposalSlot<DaoId, AccountId, BlockId> {
 	pub dao_id: DaoId,
 	pub creator: AccountId,
+	pub birth_block: BlockId,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Proposal<DaoId, AccountId, BlockId, Balance, Metadata> {
 	pub dao_id: DaoId,
 	pub creator: AccountId,
@@ -22,9 +26,33 @@ pub struct Proposal<DaoId, AccountId, BlockId, Balance, Metadata> {
 	pub status: ProposalStatus,
 	pub in_favor: Balance,
 	pub against: Balance,
+	pub abstain: Balance,
+}
+
+/// What remains of a finalized proposal once it has been pruned.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ProposalResult<DaoId, Hash> {
+	pub dao_id: DaoId,
+	pub status: ProposalStatus,
+	/// The hash of the final tally: the ayes, nays and abstentions alongside with the tallies of
+	/// the options of a multiple-choice proposal.
+	pub tally_hash: Hash,
+}
+
+/// A vote on a proposal. The voting power of abstentions counts towards the turnout only.
+///
+/// Encoded like the `bool` votes were stored before, `Against` as `false` and `InFavor` as `true`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Vote {
+	Against,
+	InFavor,
+	Abstain,
 }
 
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum ProposalStatus {
 	#[default]
 	Running,
@@ -33,3 +61,86 @@ pub enum ProposalStatus {
 	Faulty,
 	Implemented,
 }
+
+/// What happens to the DAO token deposit of a proposal that has been marked as faulty.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum DepositSlash {
+	/// The deposit is burned, reducing the token supply.
+	Burn,
+	/// The deposit is transferred to the treasury of the DAO.
+	Treasury,
+}
+
+/// The conviction of a vote: the voting power is multiplied in exchange for locking the voting
+/// DAO tokens for a multiple of the proposal duration after the proposal has ended.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Conviction {
+	/// 2x voting power, locked for 1x the proposal duration.
+	Locked2x,
+	/// 3x voting power, locked for 2x the proposal duration.
+	Locked3x,
+	/// 4x voting power, locked for 4x the proposal duration.
+	Locked4x,
+	/// 5x voting power, locked for 8x the proposal duration.
+	Locked5x,
+	/// 6x voting power, locked for 16x the proposal duration.
+	Locked6x,
+}
+
+impl Conviction {
+	/// The factor the voting power is multiplied with.
+	pub fn multiplier(self) -> u32 {
+		match self {
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 3,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 5,
+			Conviction::Locked6x => 6,
+		}
+	}
+
+	/// The number of proposal durations the voting tokens are locked for.
+	pub fn lock_periods(self) -> u32 {
+		match self {
+			Conviction::Locked2x => 1,
+			Conviction::Locked3x => 2,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 8,
+			Conviction::Locked6x => 16,
+		}
+	}
+}
+
+/// DAO tokens locked by conviction votes.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VotingLock<Balance, BlockId> {
+	/// The amount of reserved DAO tokens.
+	pub amount: Balance,
+	/// The last block the tokens are locked at.
+	pub until: BlockId,
+}
+
+/// The index of an option of a multiple-choice proposal.
+pub type OptionIndex = u8;
+
+/// How the votes on the options of a multiple-choice proposal are tallied.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Tallying {
+	/// Every voter picks a single option, the option with the most voting power wins.
+	Plurality,
+	/// Voters rank the options. The option with the least voting power is eliminated and its
+	/// votes pass to the next ranked option, until an option has a majority (instant-runoff).
+	RankedChoice,
+}
+
+/// The options of a proposal that chooses between several options instead of being accepted or
+/// rejected.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MultipleChoice {
+	/// The number of options, which are described in the metadata of the proposal.
+	pub options: OptionIndex,
+	/// How the votes are tallied.
+	pub tallying: Tallying,
+}
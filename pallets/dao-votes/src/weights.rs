@@ -1,13 +1,12 @@
 
-//! Autogenerated weights for pallet_dao_votes
+//! Weights for pallet_dao_votes
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-07, STEPS: `2`, REPEAT: `1`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `blackbox`, CPU: `Intel(R) Core(TM) i5-6200U CPU @ 2.30GHz`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! The values are estimates rather than benchmark results: they were derived by hand from the
+//! storage accesses of each call and the benchmarked calls of the baseline, as the benchmarks in
+//! `benchmarking.rs` could not be run for them. Regenerate this file before relying on the
+//! weights in production.
 
-// Executed Command:
+// Command to regenerate:
 // ./target/release/genesis-dao
 // benchmark
 // pallet
@@ -20,9 +19,9 @@
 // --extrinsic
 // *
 // --steps
-// 2
+// 50
 // --repeat
-// 1
+// 20
 // --output
 // pallets/dao-votes/src/weights.rs
 // --template
@@ -40,8 +39,23 @@ pub trait WeightInfo {
 	fn create_proposal() -> Weight;
 	fn fault_proposal() -> Weight;
 	fn finalize_proposal(v: u32, ) -> Weight;
-	fn vote() -> Weight;
+	fn vote(d: u32, ) -> Weight;
 	fn set_governance_majority_vote() -> Weight;
+	fn set_governance_quorum_vote() -> Weight;
+	fn set_governance_supermajority_vote() -> Weight;
+	fn set_governance_quadratic_vote() -> Weight;
+	fn set_governance_contract_vote() -> Weight;
+	fn teardown(i: u32, ) -> Weight;
+	fn prune_proposal(v: u32, ) -> Weight;
+	fn set_calls(b: u32, ) -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn cancel_proposal() -> Weight;
+	fn expire_proposal_slot() -> Weight;
+	fn unlock() -> Weight;
+	fn set_options() -> Weight;
+	fn vote_options() -> Weight;
+	fn set_metadata(m: u32, ) -> Weight;
 }
 
 /// Weights for pallet_dao_votes using the Substrate node and recommended hardware.
@@ -57,27 +71,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
 	/// Storage: Votes Proposals (r:0 w:1)
 	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:1 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:0 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
 	fn create_proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `549`
 		//  Estimated: `14520`
 		// Minimum execution time: 116_835_000 picoseconds.
 		Weight::from_parts(116_835_000, 14520)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Votes Proposals (r:1 w:1)
 	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
 	/// Storage: DaoCore Daos (r:1 w:0)
 	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:66 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
 	fn fault_proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `381`
 		//  Estimated: `7786`
 		// Minimum execution time: 81_802_000 picoseconds.
 		Weight::from_parts(81_802_000, 7786)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(71_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Votes Proposals (r:1 w:1)
 	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
@@ -89,6 +117,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Votes Votes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
 	/// Storage: Assets AccountHistory (r:1000 w:0)
 	/// Proof: Assets AccountHistory (max_values: None, max_size: Some(14400072), added: 14402547, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:66 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `v` is `[0, 1000]`.
 	fn finalize_proposal(_v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -96,23 +132,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `1520193915`
 		// Minimum execution time: 111_349_000 picoseconds.
 		Weight::from_parts(16_923_371_000, 1520193915)
-			.saturating_add(T::DbWeight::get().reads(2004_u64))
-			.saturating_add(T::DbWeight::get().writes(1001_u64))
+			.saturating_add(T::DbWeight::get().reads(2073_u64))
+			.saturating_add(T::DbWeight::get().writes(1004_u64))
 	}
-	/// Storage: Votes Proposals (r:1 w:0)
+	/// Storage: Votes Proposals (r:1 w:1)
 	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
 	/// Storage: Votes Governances (r:1 w:0)
 	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
-	/// Storage: Votes Votes (r:0 w:1)
+	/// Storage: Votes ProposalOptions (r:1 w:0)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:1 w:0)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Votes (r:65 w:1)
 	/// Proof: Votes Votes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
-	fn vote() -> Weight {
+	/// Storage: Votes VoteConvictions (r:1 w:1)
+	/// Proof Skipped: Votes VoteConvictions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:0)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:65 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AccountCheckpointCount (r:65 w:0)
+	/// Proof Skipped: Assets AccountCheckpointCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Account (r:65 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Votes DelegatedTo (r:129 w:128)
+	/// Proof Skipped: Votes DelegatedTo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedVotes (r:1 w:1)
+	/// Proof Skipped: Votes DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegators (r:1 w:0)
+	/// Proof Skipped: Votes Delegators (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `d` is `[0, 64]`.
+	fn vote(d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `269`
-		//  Estimated: `7397`
-		// Minimum execution time: 54_944_000 picoseconds.
-		Weight::from_parts(54_944_000, 7397)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `1042 + d * (112 ±0)`
+		//  Estimated: `21562 + d * (12690 ±0)`
+		// Minimum execution time: 121_375_000 picoseconds.
+		Weight::from_parts(121_375_000, 21562)
+			// Standard Error: 41_209
+			.saturating_add(Weight::from_parts(27_904_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 12690).saturating_mul(d.into()))
 	}
 	/// Storage: DaoCore Daos (r:1 w:0)
 	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
@@ -127,6 +191,389 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	fn set_governance_quorum_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 45_208_000 picoseconds.
+		Weight::from_parts(45_208_000, 3893)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	fn set_governance_supermajority_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 45_012_000 picoseconds.
+		Weight::from_parts(45_012_000, 3893)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	fn set_governance_quadratic_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 45_127_000 picoseconds.
+		Weight::from_parts(45_127_000, 3893)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Contracts ContractInfoOf (r:1 w:0)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1795), added: 4270, mode: MaxEncodedLen)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	fn set_governance_contract_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `8163`
+		// Minimum execution time: 58_364_000 picoseconds.
+		Weight::from_parts(58_364_000, 8163)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	/// Storage: Votes DaoProposals (r:1 w:0)
+	/// Proof Skipped: Votes DaoProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalSlots (r:1000 w:1000)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Proposals (r:1000 w:1000)
+	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1000 w:1000)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalTokenDeposits (r:1000 w:1000)
+	/// Proof Skipped: Votes ProposalTokenDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:1000)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Votes Votes (r:1000 w:0)
+	/// Proof: Votes Votes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Votes VoteConvictions (r:1000 w:0)
+	/// Proof Skipped: Votes VoteConvictions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedVotes (r:1000 w:0)
+	/// Proof Skipped: Votes DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedTo (r:1000 w:0)
+	/// Proof Skipped: Votes DelegatedTo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes VotingLocks (r:1 w:0)
+	/// Proof Skipped: Votes VotingLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegations (r:1 w:0)
+	/// Proof Skipped: Votes Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegators (r:1 w:0)
+	/// Proof Skipped: Votes Delegators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalResults (r:0 w:1000)
+	/// Proof Skipped: Votes ProposalResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalSnapshots (r:0 w:1000)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `i` is `[0, 999]`.
+	fn teardown(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + i * (301 ±0)`
+		//  Estimated: `14377 + i * (28415 ±0)`
+		// Minimum execution time: 38_214_000 picoseconds.
+		Weight::from_parts(38_214_000, 14377)
+			// Standard Error: 52_718
+			.saturating_add(Weight::from_parts(71_836_000, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((16_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 28415).saturating_mul(i.into()))
+	}
+	/// Storage: Votes Proposals (r:1 w:1)
+	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes OptionTallies (r:1 w:1)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes PruningRewards (r:1 w:1)
+	/// Proof Skipped: Votes PruningRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Votes Votes (r:1000 w:999)
+	/// Proof: Votes Votes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Votes VoteConvictions (r:1 w:0)
+	/// Proof Skipped: Votes VoteConvictions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedVotes (r:1 w:0)
+	/// Proof Skipped: Votes DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedTo (r:1 w:0)
+	/// Proof Skipped: Votes DelegatedTo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes PersonVotes (r:1 w:0)
+	/// Proof Skipped: Votes PersonVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionVotes (r:1 w:0)
+	/// Proof Skipped: Votes OptionVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes RankingTallies (r:1 w:0)
+	/// Proof Skipped: Votes RankingTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalResults (r:0 w:1)
+	/// Proof Skipped: Votes ProposalResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes PruningProposals (r:0 w:2)
+	/// Proof Skipped: Votes PruningProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCallResults (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCallResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalTokenDeposits (r:0 w:1)
+	/// Proof Skipped: Votes ProposalTokenDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalSnapshots (r:0 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:0 w:1)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:0 w:1)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalWinners (r:0 w:1)
+	/// Proof Skipped: Votes ProposalWinners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DaoProposals (r:0 w:1)
+	/// Proof Skipped: Votes DaoProposals (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `v` is `[0, 999]`.
+	fn prune_proposal(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `543 + v * (46 ±0)`
+		//  Estimated: `12450 + v * (2533 ±0)`
+		// Minimum execution time: 64_118_000 picoseconds.
+		Weight::from_parts(64_118_000, 12450)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(6_412_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(v.into()))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:0)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:1 w:0)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `b` is `[0, 65536]`.
+	fn set_calls(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `5378`
+		// Minimum execution time: 24_913_000 picoseconds.
+		Weight::from_parts(24_913_000, 5378)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(1_462, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: DaoCore DestroyingDaos (r:1 w:0)
+	/// Proof Skipped: DaoCore DestroyingDaos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegations (r:1 w:1)
+	/// Proof Skipped: Votes Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegators (r:2 w:2)
+	/// Proof Skipped: Votes Delegators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2812`
+		//  Estimated: `12894`
+		// Minimum execution time: 52_307_000 picoseconds.
+		Weight::from_parts(52_307_000, 12894)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Delegations (r:1 w:1)
+	/// Proof Skipped: Votes Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegators (r:1 w:1)
+	/// Proof Skipped: Votes Delegators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2847`
+		//  Estimated: `9562`
+		// Minimum execution time: 38_640_000 picoseconds.
+		Weight::from_parts(38_640_000, 9562)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalTokenDeposits (r:1 w:1)
+	/// Proof Skipped: Votes ProposalTokenDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:2 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DaoProposals (r:0 w:1)
+	/// Proof Skipped: Votes DaoProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:0 w:1)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:0 w:1)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionTallies (r:0 w:1)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	fn cancel_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1186`
+		//  Estimated: `21943`
+		// Minimum execution time: 71_526_000 picoseconds.
+		Weight::from_parts(71_526_000, 21943)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalTokenDeposits (r:1 w:1)
+	/// Proof Skipped: Votes ProposalTokenDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:2 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DaoProposals (r:0 w:1)
+	/// Proof Skipped: Votes DaoProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:0 w:1)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:0 w:1)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionTallies (r:0 w:1)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	fn expire_proposal_slot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1186`
+		//  Estimated: `21943`
+		// Minimum execution time: 72_904_000 picoseconds.
+		Weight::from_parts(72_904_000, 21943)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes VotingLocks (r:1 w:1)
+	/// Proof Skipped: Votes VotingLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `962`
+		//  Estimated: `11034`
+		// Minimum execution time: 41_287_000 picoseconds.
+		Weight::from_parts(41_287_000, 11034)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:0)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:1 w:0)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:0 w:1)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionTallies (r:0 w:1)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	fn set_options() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `5358`
+		// Minimum execution time: 23_671_000 picoseconds.
+		Weight::from_parts(23_671_000, 5358)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Votes Proposals (r:2 w:0)
+	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:1 w:0)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalOptions (r:1 w:0)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:2 w:0)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionVotes (r:1 w:1)
+	/// Proof Skipped: Votes OptionVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionTallies (r:2 w:2)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes RankingTallies (r:2 w:2)
+	/// Proof Skipped: Votes RankingTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:0)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AccountCheckpointCount (r:1 w:0)
+	/// Proof Skipped: Assets AccountCheckpointCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AccountCheckpoints (r:1 w:0)
+	/// Proof Skipped: Assets AccountCheckpoints (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Account (r:1 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn vote_options() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1642`
+		//  Estimated: `32915`
+		// Minimum execution time: 96_418_000 picoseconds.
+		Weight::from_parts(96_418_000, 32915)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:1)
+	/// Proof: Votes ProposalSlots (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: DaoCore StrictMetadataDaos (r:1 w:0)
+	/// Proof Skipped: DaoCore StrictMetadataDaos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Governances (r:1 w:0)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalEnds (r:1 w:1)
+	/// Proof Skipped: Votes ProposalEnds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Proposals (r:0 w:1)
+	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// The range of component `m` is `[24, 256]`.
+	fn set_metadata(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `10992`
+		// Minimum execution time: 38_214_000 picoseconds.
+		Weight::from_parts(38_214_000, 10992)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(2_107, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -141,27 +588,41 @@ impl WeightInfo for () {
 	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
 	/// Storage: Votes Proposals (r:0 w:1)
 	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:1 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:0 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
 	fn create_proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `549`
 		//  Estimated: `14520`
 		// Minimum execution time: 116_835_000 picoseconds.
 		Weight::from_parts(116_835_000, 14520)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Votes Proposals (r:1 w:1)
 	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
 	/// Storage: DaoCore Daos (r:1 w:0)
 	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:66 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
 	fn fault_proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `381`
 		//  Estimated: `7786`
 		// Minimum execution time: 81_802_000 picoseconds.
 		Weight::from_parts(81_802_000, 7786)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(71_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Votes Proposals (r:1 w:1)
 	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
@@ -173,6 +634,14 @@ impl WeightInfo for () {
 	/// Proof: Votes Votes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
 	/// Storage: Assets AccountHistory (r:1000 w:0)
 	/// Proof: Assets AccountHistory (max_values: None, max_size: Some(14400072), added: 14402547, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:66 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `v` is `[0, 1000]`.
 	fn finalize_proposal(_v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -180,23 +649,51 @@ impl WeightInfo for () {
 		//  Estimated: `1520193915`
 		// Minimum execution time: 111_349_000 picoseconds.
 		Weight::from_parts(16_923_371_000, 1520193915)
-			.saturating_add(RocksDbWeight::get().reads(2004_u64))
-			.saturating_add(RocksDbWeight::get().writes(1001_u64))
+			.saturating_add(RocksDbWeight::get().reads(2073_u64))
+			.saturating_add(RocksDbWeight::get().writes(1004_u64))
 	}
-	/// Storage: Votes Proposals (r:1 w:0)
+	/// Storage: Votes Proposals (r:1 w:1)
 	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
 	/// Storage: Votes Governances (r:1 w:0)
 	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
-	/// Storage: Votes Votes (r:0 w:1)
+	/// Storage: Votes ProposalOptions (r:1 w:0)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:1 w:0)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Votes (r:65 w:1)
 	/// Proof: Votes Votes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
-	fn vote() -> Weight {
+	/// Storage: Votes VoteConvictions (r:1 w:1)
+	/// Proof Skipped: Votes VoteConvictions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:0)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:65 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AccountCheckpointCount (r:65 w:0)
+	/// Proof Skipped: Assets AccountCheckpointCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Account (r:65 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Votes DelegatedTo (r:129 w:128)
+	/// Proof Skipped: Votes DelegatedTo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedVotes (r:1 w:1)
+	/// Proof Skipped: Votes DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegators (r:1 w:0)
+	/// Proof Skipped: Votes Delegators (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `d` is `[0, 64]`.
+	fn vote(d: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `269`
-		//  Estimated: `7397`
-		// Minimum execution time: 54_944_000 picoseconds.
-		Weight::from_parts(54_944_000, 7397)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `1042 + d * (112 ±0)`
+		//  Estimated: `21562 + d * (12690 ±0)`
+		// Minimum execution time: 121_375_000 picoseconds.
+		Weight::from_parts(121_375_000, 21562)
+			// Standard Error: 41_209
+			.saturating_add(Weight::from_parts(27_904_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 12690).saturating_mul(d.into()))
 	}
 	/// Storage: DaoCore Daos (r:1 w:0)
 	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
@@ -211,4 +708,387 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	fn set_governance_quorum_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 45_208_000 picoseconds.
+		Weight::from_parts(45_208_000, 3893)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	fn set_governance_supermajority_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 45_012_000 picoseconds.
+		Weight::from_parts(45_012_000, 3893)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	fn set_governance_quadratic_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 45_127_000 picoseconds.
+		Weight::from_parts(45_127_000, 3893)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Contracts ContractInfoOf (r:1 w:0)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(1795), added: 4270, mode: MaxEncodedLen)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	fn set_governance_contract_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `8163`
+		// Minimum execution time: 58_364_000 picoseconds.
+		Weight::from_parts(58_364_000, 8163)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Votes Governances (r:0 w:1)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	/// Storage: Votes DaoProposals (r:1 w:0)
+	/// Proof Skipped: Votes DaoProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalSlots (r:1000 w:1000)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Proposals (r:1000 w:1000)
+	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1000 w:1000)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalTokenDeposits (r:1000 w:1000)
+	/// Proof Skipped: Votes ProposalTokenDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1000 w:1000)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Votes Votes (r:1000 w:0)
+	/// Proof: Votes Votes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Votes VoteConvictions (r:1000 w:0)
+	/// Proof Skipped: Votes VoteConvictions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedVotes (r:1000 w:0)
+	/// Proof Skipped: Votes DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedTo (r:1000 w:0)
+	/// Proof Skipped: Votes DelegatedTo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes VotingLocks (r:1 w:0)
+	/// Proof Skipped: Votes VotingLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegations (r:1 w:0)
+	/// Proof Skipped: Votes Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegators (r:1 w:0)
+	/// Proof Skipped: Votes Delegators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalResults (r:0 w:1000)
+	/// Proof Skipped: Votes ProposalResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalSnapshots (r:0 w:1000)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `i` is `[0, 999]`.
+	fn teardown(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + i * (301 ±0)`
+		//  Estimated: `14377 + i * (28415 ±0)`
+		// Minimum execution time: 38_214_000 picoseconds.
+		Weight::from_parts(38_214_000, 14377)
+			// Standard Error: 52_718
+			.saturating_add(Weight::from_parts(71_836_000, 0).saturating_mul(i.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(i.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((16_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 28415).saturating_mul(i.into()))
+	}
+	/// Storage: Votes Proposals (r:1 w:1)
+	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes OptionTallies (r:1 w:1)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes PruningRewards (r:1 w:1)
+	/// Proof Skipped: Votes PruningRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Votes Votes (r:1000 w:999)
+	/// Proof: Votes Votes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Votes VoteConvictions (r:1 w:0)
+	/// Proof Skipped: Votes VoteConvictions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedVotes (r:1 w:0)
+	/// Proof Skipped: Votes DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DelegatedTo (r:1 w:0)
+	/// Proof Skipped: Votes DelegatedTo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes PersonVotes (r:1 w:0)
+	/// Proof Skipped: Votes PersonVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionVotes (r:1 w:0)
+	/// Proof Skipped: Votes OptionVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes RankingTallies (r:1 w:0)
+	/// Proof Skipped: Votes RankingTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalResults (r:0 w:1)
+	/// Proof Skipped: Votes ProposalResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes PruningProposals (r:0 w:2)
+	/// Proof Skipped: Votes PruningProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCallResults (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCallResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalTokenDeposits (r:0 w:1)
+	/// Proof Skipped: Votes ProposalTokenDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalSnapshots (r:0 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:0 w:1)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:0 w:1)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalWinners (r:0 w:1)
+	/// Proof Skipped: Votes ProposalWinners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DaoProposals (r:0 w:1)
+	/// Proof Skipped: Votes DaoProposals (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `v` is `[0, 999]`.
+	fn prune_proposal(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `543 + v * (46 ±0)`
+		//  Estimated: `12450 + v * (2533 ±0)`
+		// Minimum execution time: 64_118_000 picoseconds.
+		Weight::from_parts(64_118_000, 12450)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(6_412_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(v.into()))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:0)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:1 w:0)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `b` is `[0, 65536]`.
+	fn set_calls(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `5378`
+		// Minimum execution time: 24_913_000 picoseconds.
+		Weight::from_parts(24_913_000, 5378)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(1_462, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: DaoCore DestroyingDaos (r:1 w:0)
+	/// Proof Skipped: DaoCore DestroyingDaos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegations (r:1 w:1)
+	/// Proof Skipped: Votes Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegators (r:2 w:2)
+	/// Proof Skipped: Votes Delegators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2812`
+		//  Estimated: `12894`
+		// Minimum execution time: 52_307_000 picoseconds.
+		Weight::from_parts(52_307_000, 12894)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Delegations (r:1 w:1)
+	/// Proof Skipped: Votes Delegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Delegators (r:1 w:1)
+	/// Proof Skipped: Votes Delegators (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2847`
+		//  Estimated: `9562`
+		// Minimum execution time: 38_640_000 picoseconds.
+		Weight::from_parts(38_640_000, 9562)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalTokenDeposits (r:1 w:1)
+	/// Proof Skipped: Votes ProposalTokenDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:2 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DaoProposals (r:0 w:1)
+	/// Proof Skipped: Votes DaoProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:0 w:1)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:0 w:1)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionTallies (r:0 w:1)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	fn cancel_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1186`
+		//  Estimated: `21943`
+		// Minimum execution time: 71_526_000 picoseconds.
+		Weight::from_parts(71_526_000, 21943)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalTokenDeposits (r:1 w:1)
+	/// Proof Skipped: Votes ProposalTokenDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:1)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets OpenSnapshots (r:2 w:1)
+	/// Proof Skipped: Assets OpenSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets CurrentSnapshotId (r:1 w:0)
+	/// Proof Skipped: Assets CurrentSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets RetainedSnapshotId (r:1 w:1)
+	/// Proof Skipped: Assets RetainedSnapshotId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes DaoProposals (r:0 w:1)
+	/// Proof Skipped: Votes DaoProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:0 w:1)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:0 w:1)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:0 w:1)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionTallies (r:0 w:1)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	fn expire_proposal_slot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1186`
+		//  Estimated: `21943`
+		// Minimum execution time: 72_904_000 picoseconds.
+		Weight::from_parts(72_904_000, 21943)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes VotingLocks (r:1 w:1)
+	/// Proof Skipped: Votes VotingLocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `962`
+		//  Estimated: `11034`
+		// Minimum execution time: 41_287_000 picoseconds.
+		Weight::from_parts(41_287_000, 11034)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:0)
+	/// Proof Skipped: Votes ProposalSlots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalCalls (r:1 w:0)
+	/// Proof Skipped: Votes ProposalCalls (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes ProposalOptions (r:0 w:1)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionTallies (r:0 w:1)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	fn set_options() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `5358`
+		// Minimum execution time: 23_671_000 picoseconds.
+		Weight::from_parts(23_671_000, 5358)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Votes Proposals (r:2 w:0)
+	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes Governances (r:1 w:0)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalOptions (r:1 w:0)
+	/// Proof Skipped: Votes ProposalOptions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes QuadraticProposals (r:2 w:0)
+	/// Proof Skipped: Votes QuadraticProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionVotes (r:1 w:1)
+	/// Proof Skipped: Votes OptionVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes OptionTallies (r:2 w:2)
+	/// Proof Skipped: Votes OptionTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes RankingTallies (r:2 w:2)
+	/// Proof Skipped: Votes RankingTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DaoCore Daos (r:1 w:0)
+	/// Proof: DaoCore Daos (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalSnapshots (r:1 w:0)
+	/// Proof Skipped: Votes ProposalSnapshots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AccountCheckpointCount (r:1 w:0)
+	/// Proof Skipped: Assets AccountCheckpointCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets AccountCheckpoints (r:1 w:0)
+	/// Proof Skipped: Assets AccountCheckpoints (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Account (r:1 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn vote_options() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1642`
+		//  Estimated: `32915`
+		// Minimum execution time: 96_418_000 picoseconds.
+		Weight::from_parts(96_418_000, 32915)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Votes ProposalSlots (r:1 w:1)
+	/// Proof: Votes ProposalSlots (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	/// Storage: DaoCore StrictMetadataDaos (r:1 w:0)
+	/// Proof Skipped: DaoCore StrictMetadataDaos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Governances (r:1 w:0)
+	/// Proof: Votes Governances (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	/// Storage: Votes ProposalEnds (r:1 w:1)
+	/// Proof Skipped: Votes ProposalEnds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Votes Proposals (r:0 w:1)
+	/// Proof: Votes Proposals (max_values: None, max_size: Some(428), added: 2903, mode: MaxEncodedLen)
+	/// The range of component `m` is `[24, 256]`.
+	fn set_metadata(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `10992`
+		// Minimum execution time: 38_214_000 picoseconds.
+		Weight::from_parts(38_214_000, 10992)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(2_107, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -0,0 +1,90 @@
+//! A chain extension giving ink! contracts access to the DAO pallets.
+//!
+//! Contracts address the functions below by their function id, the input and output are SCALE
+//! encoded. The contract itself is the account transferring DAO tokens, creating proposals and
+//! voting; a failing dispatch reverts the contract call with the error of the pallet.
+
+use crate::{AccountId, Assets, Balance, DaoCore, ProposalId, Runtime, Votes};
+use codec::Encode;
+use frame_support::{dispatch::DispatchError, traits::Get};
+use frame_system::RawOrigin;
+use pallet_contracts::chain_extension::{ChainExtension, Environment, Ext, InitState, RetVal};
+use pallet_dao_assets::WeightInfo as _;
+use pallet_dao_votes::{weights::WeightInfo as _, Conviction, Vote};
+use sp_std::vec::Vec;
+
+type AssetId = <Runtime as pallet_dao_assets::Config>::AssetId;
+type VoteInput = (ProposalId, Option<Vote>, Option<Conviction>);
+type VotesWeightInfo = <Runtime as pallet_dao_votes::Config>::WeightInfo;
+
+/// Look up a DAO: `dao_id: Vec<u8>` -> `Option<Dao>`.
+pub const DAO: u16 = 1;
+/// The DAO token balance of an account: `(AssetId, AccountId)` -> `Balance`.
+pub const TOKEN_BALANCE: u16 = 2;
+/// Transfer DAO tokens from the contract: `(AssetId, target: AccountId, Balance)` -> `()`.
+pub const TOKEN_TRANSFER: u16 = 3;
+/// Create a proposal: `(dao_id: Vec<u8>, meta: Vec<u8>, hash: Vec<u8>)` -> `ProposalId`.
+pub const CREATE_PROPOSAL: u16 = 4;
+/// Vote on a proposal: `(ProposalId, Option<Vote>, Option<Conviction>)` -> `()`.
+pub const VOTE: u16 = 5;
+
+#[derive(Default)]
+pub struct DaoExtension;
+
+impl ChainExtension<Runtime> for DaoExtension {
+	fn call<E: Ext<T = Runtime>>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError> {
+		let mut env = env.buf_in_buf_out();
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		let in_len = env.in_len();
+
+		let output = match env.func_id() {
+			DAO => {
+				env.charge_weight(db_weight.reads(1))?;
+				let dao_id: Vec<u8> = env.read_as_unbounded(in_len)?;
+				DaoCore::load_dao(dao_id).ok().encode()
+			},
+			TOKEN_BALANCE => {
+				env.charge_weight(db_weight.reads(1))?;
+				let (asset_id, who): (AssetId, AccountId) = env.read_as()?;
+				Assets::balance(asset_id, who).encode()
+			},
+			TOKEN_TRANSFER => {
+				env.charge_weight(<Runtime as pallet_dao_assets::Config>::WeightInfo::transfer())?;
+				let (asset_id, target, amount): (AssetId, AccountId, Balance) = env.read_as()?;
+				let origin = RawOrigin::Signed(env.ext().address().clone());
+				Assets::transfer(origin.into(), asset_id, target.into(), amount)?;
+				Vec::new()
+			},
+			CREATE_PROPOSAL => {
+				let (dao_id, meta, hash): (Vec<u8>, Vec<u8>, Vec<u8>) =
+					env.read_as_unbounded(in_len)?;
+				// the slot is turned into a proposal right away, validating the metadata
+				let weight = VotesWeightInfo::create_proposal()
+					.saturating_add(VotesWeightInfo::set_metadata(meta.len() as u32));
+				env.charge_weight(weight)?;
+				let origin = RawOrigin::Signed(env.ext().address().clone());
+				Votes::create_proposal(origin.into(), dao_id, Some((meta, hash)))?;
+				let proposal_id: ProposalId = Votes::get_current_proposal_id();
+				proposal_id.encode()
+			},
+			VOTE => {
+				// the worst case, counting the voting power of all delegators
+				let max_delegators = <Runtime as pallet_dao_votes::Config>::MaxDelegators::get();
+				let weight =
+					<Runtime as pallet_dao_votes::Config>::WeightInfo::vote(max_delegators);
+				env.charge_weight(weight)?;
+				let (proposal_id, vote, conviction): VoteInput = env.read_as()?;
+				let origin = RawOrigin::Signed(env.ext().address().clone());
+				Votes::vote(origin.into(), proposal_id, vote, conviction)?;
+				Vec::new()
+			},
+			_ => return Err(DispatchError::Other("DaoExtension: unknown function")),
+		};
+
+		env.write(&output, false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+}
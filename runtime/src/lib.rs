@@ -27,8 +27,8 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem,
-		Randomness, StorageInfo,
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8,
+		KeyOwnerProofSystem, Nothing, Randomness, StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -36,7 +36,7 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -51,6 +51,11 @@ pub use pallet_dao_assets;
 pub use pallet_dao_core;
 pub use pallet_dao_votes;
 
+/// The chain extension giving ink! contracts access to the DAO pallets.
+pub mod chain_extension;
+/// The ink! contracts DAOs can register as their voting strategy.
+pub mod voting_strategy;
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -79,6 +84,21 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// Identifier of a proposal.
+pub type ProposalId = u64;
+
+/// A DAO as returned by the DAO runtime API.
+pub type Dao = pallet_dao_core::DaoOf<Runtime>;
+
+/// The governance of a DAO as returned by the DAO runtime API.
+pub type Governance = pallet_dao_votes::GovernanceOf<Runtime>;
+
+/// A proposal as returned by the DAO runtime API.
+pub type Proposal = pallet_dao_votes::ProposalOf<Runtime>;
+
+/// A vote on a proposal as returned by the DAO runtime API.
+pub type Vote = pallet_dao_votes::Vote;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -291,6 +311,46 @@ impl pallet_transaction_payment::Config for Runtime {
 	type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
 }
 
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+// these values come from
+// https://github.com/paritytech/substrate-contracts-node/blob/main/runtime/src/lib.rs
+parameter_types! {
+	pub const DepositPerItem: Balance = deposit(1, 0);
+	pub const DepositPerByte: Balance = deposit(0, 1);
+	pub const DefaultDepositLimit: Balance = deposit(1024, 1024 * 1024);
+	pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
+}
+
+impl pallet_contracts::Config for Runtime {
+	type Time = Timestamp;
+	/// Predictable and influenced by block authors, contracts must not rely on it for anything
+	/// of value. There is no secure randomness source in the runtime to replace it with.
+	type Randomness = RandomnessCollectiveFlip;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	/// Contracts cannot dispatch runtime calls, they use the chain extension instead.
+	type CallFilter = Nothing;
+	type DepositPerItem = DepositPerItem;
+	type DepositPerByte = DepositPerByte;
+	type DefaultDepositLimit = DefaultDepositLimit;
+	type CallStack = [pallet_contracts::Frame<Self>; 5];
+	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
+	type ChainExtension = chain_extension::DaoExtension;
+	type Schedule = Schedule;
+	type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
+	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
+	type MaxStorageKeyLen = ConstU32<128>;
+	type UnsafeUnstableInterface = ConstBool<false>;
+	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+}
+
+/// Whether the debug output of contracts is returned by dry runs through the runtime API, which
+/// must not be enabled on production networks.
+const CONTRACTS_DEBUG_OUTPUT: bool = false;
+
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -307,6 +367,19 @@ impl pallet_utility::Config for Runtime {
 parameter_types! {
 	pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
 	pub const AssetsStringLimit: u32 = 50;
+	pub const DaoPalletId: PalletId = PalletId(*b"gdao/acc");
+	pub MaxProposalWeight: Weight = Perbill::from_percent(50) * BlockWeights::get().max_block;
+	pub const FaultyDepositSlash: pallet_dao_votes::DepositSlash =
+		pallet_dao_votes::DepositSlash::Treasury;
+	/// The algorithm the hash of all metadata needs to be computed with, as the hash carries no
+	/// multihash prefix. Metadata is only checked to have a hash of the length of a SHA3-256
+	/// digest, metadata hashed with another algorithm of that length is not rejected.
+	pub const MetadataHashAlgorithm: pallet_dao_core::metadata::HashAlgorithm =
+		pallet_dao_core::metadata::HashAlgorithm::Sha3_256;
+	/// The DAOs that changed owners by id and creator, taken from an index of the `DaoCreated`
+	/// events before the upgrade. The deposit of DAOs missing here is marked as unlocated by the
+	/// migration, unless their owner reserves it.
+	pub DaoCreators: Vec<(Vec<u8>, AccountId)> = Vec::new();
 }
 
 // DAO assets
@@ -318,10 +391,14 @@ impl pallet_dao_assets::Config for Runtime {
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
 	type Currency = Balances;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type AdminOrigin = pallet_dao_core::EnsureDaoToken<Runtime>;
 	type ApprovalDeposit = ApprovalDeposit;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type StringLimit = AssetsStringLimit;
-	type HistoryHorizon = ConstU32<{ 5 * 144000 }>; // a day is 14400 blocks of 6s
+	type MaxVestingSchedules = ConstU32<16>;
+	// one DAO token, see `TokenUnits`
+	type MinVestedTransfer = ConstU128<10_000_000_000>;
+	type MaxVestingDuration = ConstU32<{ 4 * 365 * DAYS }>;
 	type WeightInfo = pallet_dao_assets::weights::SubstrateWeight<Runtime>;
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -338,7 +415,17 @@ impl pallet_dao_core::Config for Runtime {
 	type Currency = Balances;
 	type DaoDeposit = ConstU128<{ 10 * DOT }>;
 	type TokenUnits = ConstU8<10>;
+	type MaxCouncilMembers = ConstU32<16>;
 	type AssetId = u32;
+	type PalletId = DaoPalletId;
+	type DaoOrigin = pallet_dao_core::EnsureDao<Runtime>;
+	type DaoTeardown = Votes;
+	type MetadataValidator = (
+		pallet_dao_core::metadata::UrlScheme,
+		pallet_dao_core::metadata::IpfsCid,
+		pallet_dao_core::metadata::HashLength<MetadataHashAlgorithm>,
+	);
+	type StrictMetadataValidator = pallet_dao_core::metadata::IpfsOnly;
 	type WeightInfo = pallet_dao_core::weights::SubstrateWeight<Runtime>;
 }
 
@@ -346,7 +433,23 @@ impl pallet_dao_core::Config for Runtime {
 impl pallet_dao_votes::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ProposalDeposit = ConstU128<{ 10 * DOT }>;
-	type ProposalId = u64;
+	type ProposalId = ProposalId;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type MaxCalls = ConstU32<16>;
+	type MaxProposalWeight = MaxProposalWeight;
+	type MaxDelegators = ConstU32<64>;
+	type DelegationDeposit = ConstU128<{ DOT }>;
+	type MaxOptions = ConstU32<5>;
+	type MaxProposalsPerBlock = ConstU32<64>;
+	type FaultyDepositSlash = FaultyDepositSlash;
+	type ProposalSlotExpiry = ConstU32<{ 7 * DAYS }>;
+	type ProposalRetentionPeriod = ConstU32<{ 30 * DAYS }>;
+	type PruningReward = ConstU128<{ DOT }>;
+	// no accounts are verified to belong to persons, so quadratic voting cannot be limited to
+	// persons and is open to holders splitting their balance across accounts
+	type Personhood = ();
+	type VotingStrategy = voting_strategy::ContractVotingStrategy;
 	type WeightInfo = pallet_dao_votes::weights::SubstrateWeight<Runtime>;
 }
 
@@ -367,6 +470,8 @@ construct_runtime!(
 		Sudo: pallet_sudo,
 		Utility: pallet_utility,
 		Multisig: pallet_multisig,
+		RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
+		Contracts: pallet_contracts,
 		// Include the custom logic from the DAO pallets in the runtime.
 		Assets: pallet_dao_assets,
 		DaoCore: pallet_dao_core,
@@ -404,8 +509,17 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations to run on runtime upgrade.
+type Migrations = (
+	pallet_dao_assets::migrations::v2::MigrateToV2<Runtime>,
+	pallet_dao_core::migrations::v2::MoveDaoDeposits<Runtime, DaoCreators>,
+	pallet_dao_votes::migrations::v1::MigrateToV1<Runtime>,
+	pallet_dao_assets::migrations::v2::ClearSnapshotBlocks<Runtime>,
+);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -591,6 +705,90 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_dao_rpc_runtime_api::DaoApi<Block, AccountId, Balance, Dao, Governance, Proposal, ProposalId, Vote>
+		for Runtime
+	{
+		fn get_dao(dao_id: Vec<u8>) -> Option<Dao> {
+			DaoCore::load_dao(dao_id).ok()
+		}
+		fn get_daos_by_owner(owner: AccountId) -> Vec<Dao> {
+			DaoCore::daos_by_owner(&owner)
+		}
+		fn get_governance(dao_id: Vec<u8>) -> Option<Governance> {
+			Votes::governance(dao_id)
+		}
+		fn get_proposal(proposal_id: ProposalId) -> Option<Proposal> {
+			Votes::proposal(proposal_id)
+		}
+		fn get_vote(proposal_id: ProposalId, voter: AccountId) -> Option<Vote> {
+			Votes::vote_of(proposal_id, &voter)
+		}
+		fn get_voting_power(proposal_id: ProposalId, voter: AccountId) -> Option<Balance> {
+			Votes::voting_power(proposal_id, &voter)
+		}
+	}
+
+	impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime {
+		fn call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts_primitives::ContractExecResult<Balance> {
+			let gas_limit = gas_limit.unwrap_or(BlockWeights::get().max_block);
+			Contracts::bare_call(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				CONTRACTS_DEBUG_OUTPUT,
+				pallet_contracts::Determinism::Enforced,
+			)
+		}
+
+		fn instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			code: pallet_contracts_primitives::Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> pallet_contracts_primitives::ContractInstantiateResult<AccountId, Balance> {
+			let gas_limit = gas_limit.unwrap_or(BlockWeights::get().max_block);
+			Contracts::bare_instantiate(
+				origin,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				code,
+				data,
+				salt,
+				CONTRACTS_DEBUG_OUTPUT,
+			)
+		}
+
+		fn upload_code(
+			origin: AccountId,
+			code: Vec<u8>,
+			storage_deposit_limit: Option<Balance>,
+			determinism: pallet_contracts::Determinism,
+		) -> pallet_contracts_primitives::CodeUploadResult<Hash, Balance> {
+			Contracts::bare_upload_code(origin, code, storage_deposit_limit, determinism)
+		}
+
+		fn get_storage(
+			address: AccountId,
+			key: Vec<u8>,
+		) -> pallet_contracts_primitives::GetStorageResult {
+			Contracts::get_storage(address, key)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -690,4 +888,12 @@ mod tests {
 			whitelist.contains("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7")
 		);
 	}
+
+	#[test]
+	fn voting_strategy_gas_fits_into_a_block() {
+		let max_block = BlockWeights::get().max_block;
+		let max_finalize_weight =
+			MaxProposalWeight::get().saturating_add(voting_strategy::VOTING_STRATEGY_GAS_LIMIT);
+		assert!(max_finalize_weight.all_lt(max_block));
+	}
 }
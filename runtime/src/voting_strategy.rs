@@ -0,0 +1,58 @@
+//! Lets ink! contracts decide on the proposals of DAOs that registered them as their voting
+//! strategy.
+
+use crate::{AccountId, Balance, Contracts};
+use codec::{Decode, Encode};
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+use pallet_contracts::Determinism;
+use pallet_dao_votes::{Tally, VotingStrategy};
+
+/// The selector of the `accepts(tally: Tally<Balance>) -> bool` message of a voting strategy
+/// contract, which is the default selector of an ink! message named `accepts`.
+pub const ACCEPTS_SELECTOR: [u8; 4] = [0xca, 0xcf, 0x37, 0xf7];
+
+/// The gas limit of a call into a voting strategy contract, which the dao-votes pallet reserves
+/// when finalizing proposals of DAOs with a voting strategy, in TXs as well as in `on_idle`.
+pub const VOTING_STRATEGY_GAS_LIMIT: Weight = Weight::from_parts(5_000_000_000, 256 * 1024);
+
+pub struct ContractVotingStrategy;
+
+impl VotingStrategy<AccountId, Balance> for ContractVotingStrategy {
+	fn is_contract(address: &AccountId) -> bool {
+		Contracts::code_hash(address).is_some()
+	}
+
+	fn accepts(address: &AccountId, tally: &Tally<Balance>) -> Option<bool> {
+		let mut input = ACCEPTS_SELECTOR.to_vec();
+		tally.encode_to(&mut input);
+		// the contract calls itself and cannot use any storage deposit, whatever it changes is
+		// rolled back so that it only decides on the proposal
+		let result = with_transaction(|| {
+			let result = Contracts::bare_call(
+				address.clone(),
+				address.clone(),
+				0,
+				Self::max_weight(),
+				Some(0),
+				input,
+				false,
+				Determinism::Enforced,
+			)
+			.result;
+			TransactionOutcome::Rollback(Ok::<_, sp_runtime::DispatchError>(result))
+		});
+		let output = result.ok()?.ok()?;
+		if output.did_revert() {
+			return None;
+		}
+		// ink! wraps the return value of messages into a result
+		<Result<bool, u8>>::decode(&mut &output.data[..]).ok()?.ok()
+	}
+
+	fn max_weight() -> Weight {
+		VOTING_STRATEGY_GAS_LIMIT
+	}
+}